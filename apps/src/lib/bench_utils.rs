@@ -57,7 +57,8 @@ use namada::ledger::gas::TxGasMeter;
 use namada::ledger::ibc::storage::{channel_key, connection_key};
 use namada::ledger::native_vp::ibc::get_dummy_header;
 use namada::ledger::queries::{
-    Client, EncodedResponseQuery, RequestCtx, RequestQuery, Router, RPC,
+    Client, EncodedResponseQuery, RequestCtx, RequestQuery, Router,
+    DEFAULT_MAX_QUERY_DATA_LEN, RPC,
 };
 use namada::ledger::storage_api::StorageRead;
 use namada::proto::{Code, Data, Section, Signature, Tx};
@@ -714,6 +715,7 @@ impl Client for BenchShell {
             vp_wasm_cache: self.vp_wasm_cache.read_only(),
             tx_wasm_cache: self.tx_wasm_cache.read_only(),
             storage_read_past_height_limit: None,
+            max_query_data_len: DEFAULT_MAX_QUERY_DATA_LEN,
         };
 
         if request.path == "/shell/dry_run_tx" {
@@ -2846,6 +2846,8 @@ pub mod args {
     pub const ALIAS: Arg<String> = arg("alias");
     pub const ALIAS_FORCE: ArgFlag = flag("alias-force");
     pub const ALLOW_DUPLICATE_IP: ArgFlag = flag("allow-duplicate-ip");
+    pub const ALLOW_LEGACY_SIGNATURES: ArgFlag =
+        flag("allow-legacy-signatures");
     pub const AMOUNT: Arg<token::DenominatedAmount> = arg("amount");
     pub const ARCHIVE_DIR: ArgOpt<PathBuf> = arg_opt("archive-dir");
     pub const BALANCE_OWNER: ArgOpt<WalletBalanceOwner> = arg_opt("owner");
@@ -2879,6 +2881,7 @@ pub mod args {
     pub const CHAIN_ID_OPT: ArgOpt<ChainId> = CHAIN_ID.opt();
     pub const CHAIN_ID_PREFIX: Arg<ChainIdPrefix> = arg("chain-prefix");
     pub const CHANNEL_ID: Arg<ChannelId> = arg("channel-id");
+    pub const CHECK_NET_ADDRESSES: ArgFlag = flag("check-net-addresses");
     pub const CODE_PATH: Arg<PathBuf> = arg("code-path");
     pub const CODE_PATH_OPT: ArgOpt<PathBuf> = CODE_PATH.opt();
     pub const COMMISSION_RATE: Arg<Dec> = arg("commission-rate");
@@ -6453,6 +6456,7 @@ pub mod args {
         pub consensus_timeout_commit: Timeout,
         pub dont_archive: bool,
         pub archive_dir: Option<PathBuf>,
+        pub allow_legacy_signatures: bool,
     }
 
     impl Args for InitNetwork {
@@ -6465,6 +6469,8 @@ pub mod args {
                 CONSENSUS_TIMEOUT_COMMIT.parse(matches);
             let dont_archive = DONT_ARCHIVE.parse(matches);
             let archive_dir = ARCHIVE_DIR.parse(matches);
+            let allow_legacy_signatures =
+                ALLOW_LEGACY_SIGNATURES.parse(matches);
             Self {
                 templates_path,
                 wasm_checksums_path,
@@ -6473,6 +6479,7 @@ pub mod args {
                 consensus_timeout_commit,
                 dont_archive,
                 archive_dir,
+                allow_legacy_signatures,
             }
         }
 
@@ -6507,6 +6514,11 @@ pub mod args {
                 "Specify a directory into which to store the archive. Default \
                  is the current working directory.",
             ))
+            .arg(ALLOW_LEGACY_SIGNATURES.def().help(
+                "Accept transfers and bonds signed in a legacy format, such \
+                 as the ones written without a `version`. These are \
+                 rejected by default.",
+            ))
         }
     }
 
@@ -6632,12 +6644,24 @@ pub mod args {
     pub struct ValidateGenesisTemplates {
         /// Templates dir
         pub path: PathBuf,
+        /// Reject validator net addresses that are not reachable on a public
+        /// network
+        pub check_net_addresses: bool,
+        /// Accept transfers and bonds signed in a legacy format
+        pub allow_legacy_signatures: bool,
     }
 
     impl Args for ValidateGenesisTemplates {
         fn parse(matches: &ArgMatches) -> Self {
             let path = PATH.parse(matches);
-            Self { path }
+            let check_net_addresses = CHECK_NET_ADDRESSES.parse(matches);
+            let allow_legacy_signatures =
+                ALLOW_LEGACY_SIGNATURES.parse(matches);
+            Self {
+                path,
+                check_net_addresses,
+                allow_legacy_signatures,
+            }
         }
 
         fn def(app: App) -> App {
@@ -6645,6 +6669,16 @@ pub mod args {
                 PATH.def()
                     .help("Path to the directory with the template files."),
             )
+            .arg(CHECK_NET_ADDRESSES.def().help(
+                "Reject validators' loopback and unspecified net addresses. \
+                 Use this for a public network's genesis, local testnets \
+                 commonly use such addresses.",
+            ))
+            .arg(ALLOW_LEGACY_SIGNATURES.def().help(
+                "Accept transfers and bonds signed in a legacy format, such \
+                 as the ones written without a `version`. These are \
+                 rejected by default.",
+            ))
         }
     }
 
@@ -394,14 +394,21 @@ pub fn init_network(
         consensus_timeout_commit,
         dont_archive,
         archive_dir,
+        allow_legacy_signatures,
     }: args::InitNetwork,
 ) {
     // Load and validate the templates
-    let templates = genesis::templates::load_and_validate(&templates_path)
-        .unwrap_or_else(|| {
-            eprintln!("Invalid templates, aborting.");
-            safe_exit(1)
-        });
+    let templates = genesis::templates::load_and_validate(
+        &templates_path,
+        &genesis::transactions::ValidationOptions {
+            allow_legacy_signatures,
+            ..Default::default()
+        },
+    )
+    .unwrap_or_else(|| {
+        eprintln!("Invalid templates, aborting.");
+        safe_exit(1)
+    });
 
     // In addition to standard templates validation, check that there is at
     // least one validator account.
@@ -412,10 +419,14 @@ pub fn init_network(
 
     // Also check that at least one validator account has positive voting power.
     let tm_votes_per_token = templates.parameters.pos_params.tm_votes_per_token;
-    if !templates
+    let has_validator_with_positive_voting_power = templates
         .transactions
         .has_validator_with_positive_voting_power(tm_votes_per_token)
-    {
+        .unwrap_or_else(|err| {
+            eprintln!("Invalid bonds: {err} Aborting.");
+            safe_exit(1)
+        });
+    if !has_validator_with_positive_voting_power {
         let min_stake = token::Amount::from_uint(
             if tm_votes_per_token > Dec::from(1) {
                 Uint::one()
@@ -666,6 +677,9 @@ pub fn init_genesis_validator(
         genesis::transactions::GenesisValidatorData {
             source_key,
             alias: alias::Alias::from(alias),
+            native_token_alias: alias::Alias::from(
+                genesis::transactions::DEFAULT_NATIVE_TOKEN_ALIAS,
+            ),
             commission_rate,
             max_commission_rate_change,
             net_address,
@@ -678,7 +692,14 @@ pub fn init_genesis_validator(
         },
         &mut source_wallet,
         &validator_wallet,
-    );
+    )
+    .unwrap_or_else(|err| {
+        eprintln!(
+            "Unable to sign the transfer from the source key with the source \
+             wallet. Failed with: {err}"
+        );
+        safe_exit(1)
+    });
 
     let genesis_part = toml::to_string(&transactions).unwrap();
     println!("Your public signed pre-genesis transactions TOML:");
@@ -792,10 +813,40 @@ pub fn validator_pre_genesis_dir(base_dir: &Path, alias: &str) -> PathBuf {
 /// Validate genesis templates. Exits process if invalid.
 pub fn validate_genesis_templates(
     _global_args: args::Global,
-    args::ValidateGenesisTemplates { path }: args::ValidateGenesisTemplates,
+    args::ValidateGenesisTemplates {
+        path,
+        check_net_addresses,
+        allow_legacy_signatures,
+    }: args::ValidateGenesisTemplates,
 ) {
-    if genesis::templates::load_and_validate(&path).is_none() {
-        safe_exit(1)
+    let templates = match genesis::templates::load_and_validate(
+        &path,
+        &genesis::transactions::ValidationOptions {
+            allow_legacy_signatures,
+            ..Default::default()
+        },
+    ) {
+        Some(templates) => templates,
+        None => safe_exit(1),
+    };
+    if check_net_addresses {
+        let is_valid = templates
+            .transactions
+            .validator_account
+            .iter()
+            .flatten()
+            .fold(true, |is_valid, tx| {
+                match genesis::transactions::validate_net_address(tx) {
+                    Ok(()) => is_valid,
+                    Err(err) => {
+                        eprintln!("{err}");
+                        false
+                    }
+                }
+            });
+        if !is_valid {
+            safe_exit(1)
+        }
     }
 }
 
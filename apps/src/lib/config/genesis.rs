@@ -8,7 +8,7 @@ pub mod transactions;
 use std::array::TryFromSliceError;
 use std::collections::{BTreeMap, HashMap};
 
-use borsh::{BorshDeserialize, BorshSerialize};
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use data_encoding::HEXLOWER;
 use derivative::Derivative;
 use namada::core::ledger::governance::parameters::GovernanceParameters;
@@ -196,6 +196,7 @@ pub struct Parameters {
     Serialize,
     BorshSerialize,
     BorshDeserialize,
+    BorshSchema,
     PartialOrd,
     Ord,
     PartialEq,
@@ -274,8 +275,11 @@ pub fn make_dev_genesis(
         current_path.pop();
     }
     let chain_dir = current_path.join("genesis").join("localnet");
-    let templates = templates::load_and_validate(&chain_dir)
-        .expect("Missing genesis files");
+    let templates = templates::load_and_validate(
+        &chain_dir,
+        &transactions::ValidationOptions::with_legacy_signatures(),
+    )
+    .expect("Missing genesis files");
     let mut genesis = finalize(
         templates,
         ChainIdPrefix::from_str("test").unwrap(),
@@ -398,6 +402,8 @@ pub fn make_dev_genesis(
                     tendermint_node_key: sign_pk(&consensus_keypair),
                     eth_hot_key: sign_pk(&eth_bridge_keypair),
                     eth_cold_key: sign_pk(&eth_cold_keypair),
+                    threshold: None,
+                    account_keys: vec![],
                 },
             })
         };
@@ -429,6 +435,7 @@ pub fn make_dev_genesis(
                     amount: token::Amount::native_whole(200_000),
                     denom: NATIVE_MAX_DECIMAL_PLACES.into(),
                 },
+                nonce: None,
             })
         }
         // self bond
@@ -440,6 +447,7 @@ pub fn make_dev_genesis(
                     amount: token::Amount::native_whole(100_000),
                     denom: NATIVE_MAX_DECIMAL_PLACES.into(),
                 },
+                nonce: None,
             })
         }
     }
@@ -334,6 +334,8 @@ impl Finalized {
             light_client_attack_min_slash_rate,
             cubic_slashing_window_length,
             validator_stake_threshold,
+            // Only used to validate the genesis bonds
+            min_bond: _,
         } = self.parameters.pos_params.clone();
 
         namada::proof_of_stake::parameters::PosParams {
@@ -844,7 +846,11 @@ mod test {
             .parent()
             .unwrap()
             .join("genesis/localnet");
-        let templates = templates::load_and_validate(&templates_dir).unwrap();
+        let templates = templates::load_and_validate(
+            &templates_dir,
+            &transactions::ValidationOptions::with_legacy_signatures(),
+        )
+        .unwrap();
 
         let chain_id_prefix: ChainIdPrefix =
             FromStr::from_str("test-prefix").unwrap();
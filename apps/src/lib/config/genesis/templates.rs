@@ -4,7 +4,8 @@ use std::collections::{BTreeMap, BTreeSet};
 use std::marker::PhantomData;
 use std::path::Path;
 
-use borsh::{BorshDeserialize, BorshSerialize};
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use borsh_ext::BorshSerializeExt;
 use namada::core::types::key::common;
 use namada::core::types::string_encoding::StringEncoded;
 use namada::core::types::{ethereum_structs, token};
@@ -19,9 +20,9 @@ use namada::types::token::{
 use serde::{Deserialize, Serialize};
 
 use super::toml_utils::{read_toml, write_toml};
-use super::transactions::{self, Transactions};
+use super::transactions::{self, Transactions, ValidationOptions};
 use crate::config::genesis::transactions::{
-    BondTx, SignedBondTx, SignedTransferTx, TransferTx,
+    BondAmount, BondTx, SignedBondTx, SignedTransferTx, TransferTx,
 };
 use crate::wallet::Alias;
 
@@ -75,6 +76,18 @@ pub struct UndenominatedBalances {
 }
 
 impl UndenominatedBalances {
+    /// Encode the balances in a compact binary form, which is faster to
+    /// decode than the TOML balances file.
+    pub fn to_borsh(&self) -> Vec<u8> {
+        self.serialize_to_vec()
+    }
+
+    /// Decode the balances from their binary form produced by
+    /// [`UndenominatedBalances::to_borsh`].
+    pub fn from_borsh(bytes: &[u8]) -> std::io::Result<Self> {
+        Self::try_from_slice(bytes)
+    }
+
     /// Use the denom in `TokenConfig` to correctly interpret the balances
     /// to the right denomination.
     pub fn denominate(
@@ -83,6 +96,7 @@ impl UndenominatedBalances {
     ) -> eyre::Result<DenominatedBalances> {
         let mut balances = DenominatedBalances {
             token: BTreeMap::new(),
+            aliases: BTreeMap::new(),
         };
         for (alias, bals) in self.token {
             let denom = tokens
@@ -122,6 +136,14 @@ impl UndenominatedBalances {
 )]
 pub struct DenominatedBalances {
     pub token: BTreeMap<Alias, TokenBalances>,
+    /// Balances of tokens associated with aliases of accounts, as accumulated
+    /// from the transfers of a previously validated batch of txs (see
+    /// [`transactions::TokenBalancesForValidation::apply_to`]). These are
+    /// only used as the starting balances of a validation and they are not
+    /// part of the balances file.
+    #[serde(skip)]
+    #[borsh(skip)]
+    pub aliases: BTreeMap<Alias, BTreeMap<Alias, DenominatedAmount>>,
 }
 
 /// Genesis balances for a given token
@@ -400,6 +422,9 @@ pub struct PosParams {
     /// The minimum amount of bonded tokens that a validator needs to be in
     /// either the `consensus` or `below_capacity` validator sets
     pub validator_stake_threshold: token::Amount,
+    /// The minimum amount of native tokens of a genesis bond, if any
+    #[serde(default)]
+    pub min_bond: Option<token::Amount>,
 }
 
 #[derive(
@@ -490,6 +515,7 @@ impl TokenBalances {
     Serialize,
     BorshDeserialize,
     BorshSerialize,
+    BorshSchema,
     PartialEq,
     Eq,
 )]
@@ -502,6 +528,7 @@ pub struct Unvalidated {}
     Serialize,
     BorshDeserialize,
     BorshSerialize,
+    BorshSchema,
     PartialEq,
     Eq,
 )]
@@ -514,6 +541,7 @@ pub trait TemplateValidation: Serialize {
         + std::fmt::Debug
         + BorshSerialize
         + BorshDeserialize
+        + BorshSchema
         + PartialEq
         + Eq;
     type Balances: for<'a> Deserialize<'a>
@@ -530,6 +558,16 @@ pub trait TemplateValidation: Serialize {
         + std::fmt::Debug
         + BorshSerialize
         + BorshDeserialize
+        + BorshSchema
+        + PartialEq
+        + Eq;
+    type BondAmount: for<'a> Deserialize<'a>
+        + Serialize
+        + Clone
+        + std::fmt::Debug
+        + BorshSerialize
+        + BorshDeserialize
+        + BorshSchema
         + PartialEq
         + Eq;
     type BondTx: for<'a> Deserialize<'a>
@@ -538,6 +576,7 @@ pub trait TemplateValidation: Serialize {
         + std::fmt::Debug
         + BorshSerialize
         + BorshDeserialize
+        + BorshSchema
         + PartialEq
         + Eq;
     type GasMinimums: for<'a> Deserialize<'a>
@@ -553,6 +592,7 @@ pub trait TemplateValidation: Serialize {
 impl TemplateValidation for Unvalidated {
     type Amount = token::DenominatedAmount;
     type Balances = UndenominatedBalances;
+    type BondAmount = BondAmount;
     type BondTx = SignedBondTx;
     type GasMinimums = BTreeMap<Alias, DenominatedAmount>;
     type TransferTx = SignedTransferTx;
@@ -561,6 +601,7 @@ impl TemplateValidation for Unvalidated {
 impl TemplateValidation for Validated {
     type Amount = token::DenominatedAmount;
     type Balances = DenominatedBalances;
+    type BondAmount = token::DenominatedAmount;
     type BondTx = BondTx<Validated>;
     type GasMinimums = BTreeMap<Alias, DenominatedAmount>;
     type TransferTx = TransferTx<Validated>;
@@ -638,7 +679,31 @@ impl All<Unvalidated> {
 /// Note that the validation rules for these templates won't enforce that there
 /// is at least one validator with positive voting power. This must be checked
 /// when the templates are being used to `init-network`.
-pub fn load_and_validate(templates_dir: &Path) -> Option<All<Validated>> {
+pub fn load_and_validate(
+    templates_dir: &Path,
+    options: &ValidationOptions<'_>,
+) -> Option<All<Validated>> {
+    load_and_validate_aux(templates_dir, None, options)
+}
+
+/// Load genesis templates from the given directory and validate them with the
+/// given pre-parsed balances instead of the balances file, e.g. decoded from
+/// their binary form with [`UndenominatedBalances::from_borsh`]. Returns
+/// `None` when there are some validation issues.
+pub fn load_and_validate_with_balances(
+    templates_dir: &Path,
+    balances: UndenominatedBalances,
+    options: &ValidationOptions<'_>,
+) -> Option<All<Validated>> {
+    load_and_validate_aux(templates_dir, Some(balances), options)
+}
+
+fn load_and_validate_aux(
+    templates_dir: &Path,
+    balances: Option<UndenominatedBalances>,
+    options: &ValidationOptions<'_>,
+) -> Option<All<Validated>> {
+    let is_balances_file_used = balances.is_none();
     let mut is_valid = true;
     // We don't reuse `All::read_toml_files` here to allow to validate config
     // without all files present.
@@ -657,17 +722,31 @@ pub fn load_and_validate(templates_dir: &Path) -> Option<All<Validated>> {
     };
     check_file_exists(&vps_file, "Validity predicates");
     check_file_exists(&tokens_file, "Tokens");
-    check_file_exists(&balances_file, "Balances");
+    if is_balances_file_used {
+        check_file_exists(&balances_file, "Balances");
+    }
     check_file_exists(&parameters_file, "Parameters");
     check_file_exists(&transactions_file, "Transactions");
 
     // Load and parse the files
     let vps = read_validity_predicates(&vps_file);
     let tokens = read_tokens(&tokens_file);
-    let balances = read_balances(&balances_file);
+    let balances = match balances {
+        Some(balances) => Ok(balances),
+        None => read_balances(&balances_file),
+    };
     let parameters = read_parameters(&parameters_file);
     let transactions = read_transactions(&transactions_file);
 
+    // Warn about the fields with a legacy name, which are still accepted
+    if let Ok(bytes) = std::fs::read(&transactions_file) {
+        let warnings =
+            transactions::lint_legacy_fields(&bytes).unwrap_or_default();
+        for warning in warnings {
+            eprintln!("Warning: Transactions file: {warning}");
+        }
+    }
+
     let eprintln_invalid_file = |err: &eyre::Report, name: &str| {
         eprintln!("{name} file is NOT valid. Failed to read with: {err}");
     };
@@ -753,20 +832,30 @@ pub fn load_and_validate(templates_dir: &Path) -> Option<All<Validated>> {
     }
 
     let txs = if let Some(tokens) = tokens.as_ref() {
-        if let Some(txs) = transactions.and_then(|txs| {
+        let validated = transactions.map(|txs| {
             transactions::validate(
                 txs,
                 vps.as_ref(),
                 balances.as_ref(),
                 tokens,
                 parameters.as_ref(),
+                options,
             )
-        }) {
-            println!("Transactions file is valid.");
-            Some(txs)
-        } else {
-            is_valid = false;
-            None
+        });
+        match validated {
+            Some(Ok(txs)) => {
+                println!("Transactions file is valid.");
+                Some(txs)
+            }
+            Some(Err(err)) => {
+                eprintln!("{err}");
+                is_valid = false;
+                None
+            }
+            None => {
+                is_valid = false;
+                None
+            }
         }
     } else {
         is_valid = false;
@@ -943,7 +1032,11 @@ mod tests {
             .unwrap()
             .join("genesis/localnet");
         assert!(
-            load_and_validate(&templates_dir).is_some(),
+            load_and_validate(
+                &templates_dir,
+                &ValidationOptions::with_legacy_signatures()
+            )
+            .is_some(),
             "Localnet genesis templates must be valid"
         );
     }
@@ -956,7 +1049,7 @@ mod tests {
             .unwrap()
             .join("genesis/starter");
         assert!(
-            load_and_validate(&templates_dir).is_some(),
+            load_and_validate(&templates_dir, &Default::default()).is_some(),
             "Starter genesis templates must be valid"
         );
     }
@@ -989,4 +1082,31 @@ mod tests {
                 .amount
         );
     }
+
+    /// Test that the binary form of balances round-trips to the TOML form and
+    /// that validation with it gives the same result as with the TOML file.
+    #[test]
+    fn test_balances_borsh_round_trip() {
+        let templates_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .join("genesis/localnet");
+        let balances =
+            read_balances(&templates_dir.join(BALANCES_FILE_NAME)).unwrap();
+
+        let decoded =
+            UndenominatedBalances::from_borsh(&balances.to_borsh()).unwrap();
+        assert_eq!(decoded, balances);
+        assert_eq!(
+            toml::to_string(&decoded).unwrap(),
+            toml::to_string(&balances).unwrap()
+        );
+
+        let options = ValidationOptions::with_legacy_signatures();
+        let from_toml = load_and_validate(&templates_dir, &options);
+        let from_borsh =
+            load_and_validate_with_balances(&templates_dir, decoded, &options);
+        assert!(from_toml.is_some());
+        assert_eq!(from_borsh, from_toml);
+    }
 }
@@ -1,24 +1,29 @@
 //! Genesis transactions
 
-use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::{Debug, Display, Formatter};
 use std::net::SocketAddr;
 use std::str::FromStr;
 
-use borsh::{BorshDeserialize, BorshSerialize};
+use borsh::schema::{Declaration, Definition, Fields};
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use borsh_ext::BorshSerializeExt;
+use namada::core::types::hash::Hash;
 use namada::core::types::storage;
 use namada::core::types::string_encoding::StringEncoded;
 use namada::proto::{
-    standalone_signature, verify_standalone_sig, SerializeWithBorsh,
+    standalone_signature, verify_standalone_sig, SerializeWithBorsh, Signable,
 };
+use namada::types::address::Address;
 use namada::types::dec::Dec;
-use namada::types::key::{common, RefTo, VerifySigError};
+use namada::types::key::{common, RefTo, SchemeType, VerifySigError};
 use namada::types::time::{DateTimeUtc, MIN_UTC};
 use namada::types::token;
 use namada::types::token::{DenominatedAmount, NATIVE_MAX_DECIMAL_PLACES};
+use namada::types::transaction::pos;
 use namada_sdk::wallet::pre_genesis::ValidatorWallet;
 use namada_sdk::wallet::{FindKeyError, Wallet};
+use serde::de::IgnoredAny;
 use serde::{Deserialize, Serialize};
 
 use super::templates::{
@@ -32,9 +37,15 @@ use crate::wallet::{Alias, CliWalletUtils};
 
 pub const PRE_GENESIS_TX_TIMESTAMP: DateTimeUtc = MIN_UTC;
 
+/// The alias of the native token in the default genesis templates
+pub const DEFAULT_NATIVE_TOKEN_ALIAS: &str = "NAM";
+
 pub struct GenesisValidatorData {
     pub source_key: common::SecretKey,
     pub alias: Alias,
+    /// The alias of the native token, in which the transfer from the source
+    /// is made
+    pub native_token_alias: Alias,
     pub commission_rate: Dec,
     pub max_commission_rate_change: Dec,
     pub net_address: SocketAddr,
@@ -46,13 +57,181 @@ pub struct GenesisValidatorData {
     pub discord_handle: Option<String>,
 }
 
+/// Errors from parsing, signing and validation of genesis transactions
+#[derive(thiserror::Error, Debug)]
+pub enum GenesisTxError {
+    #[error("Failed to parse the genesis transactions: {0}")]
+    Parse(#[from] toml::de::Error),
+    #[error("Failed to serialize the genesis transactions: {0}")]
+    Serialize(#[from] toml::ser::Error),
+    #[error("Unknown field in the genesis transactions: {0}")]
+    UnknownField(String),
+    #[error("Validator transactions must be signed with a validator wallet.")]
+    ValidatorTxsRequireValidatorWallet,
+    #[error("Validator self-bonds must be signed with a validator wallet.")]
+    SelfBondRequiresValidatorWallet,
+    #[error("{0}")]
+    Signing(#[from] SigningError),
+    #[error(
+        "Genesis files contained transfer of token {0}, which is not in the \
+         `tokens.toml` file"
+    )]
+    UnknownToken(Alias),
+    #[error(
+        "A transfer amount {amount} of token {token} has {decimal_places} \
+         decimal places, but the token's denomination only allows {denom}."
+    )]
+    ExcessivePrecision {
+        amount: DenominatedAmount,
+        token: Alias,
+        decimal_places: u8,
+        denom: u8,
+    },
+    #[error("Invalid amount: {0}")]
+    Amount(#[from] token::AmountParseError),
+    #[error("No available balance to bond from {0}")]
+    NoAvailableBalance(AliasOrPk),
+    #[error("{0}")]
+    AmountOverflow(String),
+    #[error(
+        "Invalid genesis transactions: {}",
+        .0.iter().map(ToString::to_string).collect::<Vec<_>>().join(" ")
+    )]
+    Validation(Vec<ValidationError>),
+}
+
+/// Errors from signing genesis transactions
+#[derive(thiserror::Error, Debug)]
+pub enum SigningError {
+    #[error(
+        "None of the validator wallets holds the account key {pk} of \
+         validator \"{alias}\"."
+    )]
+    MissingValidatorWallet { alias: Alias, pk: common::PublicKey },
+    #[error(
+        "Signing a self-bond of validator \"{0}\" requires its validator \
+         account tx."
+    )]
+    MissingValidatorAccount(Alias),
+    #[error("There is no wallet to sign the genesis transactions with.")]
+    NoWallets,
+    #[error(
+        "Key for source {pk} must be present to sign with it. Failed with \
+         {err}."
+    )]
+    MissingKey {
+        pk: common::PublicKey,
+        #[source]
+        err: FindKeyError,
+    },
+    #[error(
+        "Key for source {0} must be present in one of the wallets to sign \
+         with it."
+    )]
+    KeyNotInWallets(common::PublicKey),
+    #[error(
+        "Signing a bond failed. Cannot find \"{0}\" in the wallet or in the \
+         established accounts."
+    )]
+    UnknownBondSource(AliasOrPk),
+    #[error(
+        "Signing a bond failed. The established account \"{0}\" has no \
+         public key. Add a public to be able to sign bonds."
+    )]
+    EstablishedAccountWithoutKey(Alias),
+    #[error(
+        "Signing a bond failed. Failed to read the key for \"{source}\" from \
+         the wallet with {err}."
+    )]
+    BondSourceKey {
+        source: AliasOrPk,
+        #[source]
+        err: FindKeyError,
+    },
+    #[error(
+        "The new public key {pk} of the established account \"{alias}\" is \
+         the same as the old one."
+    )]
+    SameEstablishedAccountKey { alias: Alias, pk: common::PublicKey },
+    #[error(
+        "The secret key to sign the established account \"{alias}\" with \
+         doesn't belong to the new public key {pk}."
+    )]
+    EstablishedAccountKeyMismatch { alias: Alias, pk: common::PublicKey },
+}
+
+impl From<Vec<ValidationError>> for GenesisTxError {
+    fn from(errors: Vec<ValidationError>) -> Self {
+        Self::Validation(errors)
+    }
+}
+
+impl From<ValidationError> for GenesisTxError {
+    fn from(error: ValidationError) -> Self {
+        Self::Validation(vec![error])
+    }
+}
+
+/// Add the context of the tx's source and token to an amount that overflows
+/// when its precision is increased
+fn amount_precision_error(
+    err: token::AmountParseError,
+    alias: AliasOrPk,
+    token: Alias,
+) -> GenesisTxError {
+    match err {
+        token::AmountParseError::PrecisionOverflow => {
+            ValidationError::AmountPrecisionOverflow { alias, token }.into()
+        }
+        err => err.into(),
+    }
+}
+
 /// Panics if given `txs.validator_accounts` is not empty, because validator
 /// transactions must be signed with a validator wallet (see
-/// `init-genesis-validator` command).
+/// `init-genesis-validator` command), or if any of the txs other than
+/// transfers cannot be signed. See [`try_sign_txs`].
+///
+/// The transfers whose source key is not in the wallet are reported to stderr
+/// and left out of the signed txs.
 pub fn sign_txs(
-    txs: UnsignedTransactions,
+    mut txs: UnsignedTransactions,
     wallet: &mut Wallet<CliWalletUtils>,
 ) -> Transactions<Unvalidated> {
+    let transfer = txs.transfer.take();
+    let mut signed =
+        try_sign_txs(txs, wallet).unwrap_or_else(|err| panic!("{err}"));
+    signed.transfer = transfer.map(|txs| {
+        txs.into_iter()
+            .filter_map(|tx| {
+                let description = format!(
+                    "A transfer of token {} from {} to {}",
+                    tx.token, tx.source, tx.target
+                );
+                match sign_transfer_tx(tx, wallet) {
+                    Ok(signed) => Some(signed),
+                    Err(err) => {
+                        eprintln!(
+                            "{description} cannot be signed with the source \
+                             key from the wallet and is skipped. Failed with \
+                             {err}."
+                        );
+                        None
+                    }
+                }
+            })
+            .collect()
+    });
+    signed
+}
+
+/// Sign the genesis transactions with the keys from the given wallet.
+/// Validator transactions and validator self-bonds cannot be signed with it,
+/// because they must be signed with a validator wallet.
+pub fn try_sign_txs(
+    txs: UnsignedTransactions,
+    wallet: &mut Wallet<CliWalletUtils>,
+) -> Result<Transactions<Unvalidated>, GenesisTxError> {
     let UnsignedTransactions {
         established_account,
         validator_account,
@@ -62,55 +241,365 @@ pub fn sign_txs(
 
     // Validate input first
     if validator_account.is_some() && !validator_account.unwrap().is_empty() {
-        panic!(
-            "Validator transactions must be signed with a validator wallet."
-        );
+        return Err(GenesisTxError::ValidatorTxsRequireValidatorWallet);
     }
 
     if let Some(bonds) = bond.as_ref() {
+        // There are no validator accounts in the signed txs, so only the
+        // self-bonds with a source alias can be detected
+        let validator_accounts = BTreeMap::new();
         for bond in bonds {
-            if let AliasOrPk::Alias(source) = &bond.source {
-                if source == &bond.validator {
-                    panic!(
-                        "Validator self-bonds must be signed with a validator \
-                         wallet."
-                    )
-                }
+            if bond.is_self_bond(&validator_accounts) {
+                return Err(GenesisTxError::SelfBondRequiresValidatorWallet);
             }
         }
     }
 
     // Sign all the transactions
-    let established_account = established_account.map(|tx| {
-        tx.into_iter()
-            .map(|tx| sign_established_account_tx(tx, wallet))
-            .collect()
-    });
+    let established_account = established_account
+        .map(|tx| {
+            tx.into_iter()
+                .map(|tx| try_sign_established_account_tx(tx, wallet))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()?;
     let validator_account = None;
-    let transfer = transfer.map(|tx| {
-        tx.into_iter()
-            .map(|tx| sign_transfer_tx(tx, wallet))
-            .collect()
-    });
-    let bond = bond.map(|tx| {
-        tx.into_iter()
-            .map(|tx| sign_delegation_bond_tx(tx, wallet, &established_account))
-            .collect()
-    });
+    let transfer = transfer
+        .map(|tx| {
+            tx.into_iter()
+                .map(|tx| {
+                    let source = tx.source.raw.clone();
+                    sign_transfer_tx(tx, wallet)
+                        .map_err(|err| signing_key_error(&source, err))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()?;
+    let bond = bond
+        .map(|tx| {
+            tx.into_iter()
+                .map(|tx| {
+                    try_sign_delegation_bond_tx(
+                        tx,
+                        wallet,
+                        &established_account,
+                    )
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()?;
+
+    Ok(Transactions {
+        established_account,
+        validator_account,
+        transfer,
+        bond,
+    })
+}
+
+/// Sign the genesis transactions with the keys spread across the given
+/// wallets, e.g. in a multi-party ceremony. Each tx is routed to the wallet
+/// that holds its key: validator account txs and self-bonds to the validator
+/// wallet with the validator's account key, and all the other txs to the
+/// first of the `wallets` that can sign them. Returns an error if none of the
+/// wallets can sign a tx.
+pub fn sign_txs_multi(
+    txs: UnsignedTransactions,
+    wallets: &mut [Wallet<CliWalletUtils>],
+    validator_wallets: &[ValidatorWallet],
+) -> Result<Transactions<Unvalidated>, GenesisTxError> {
+    let UnsignedTransactions {
+        established_account,
+        validator_account,
+        transfer,
+        bond,
+    } = txs;
+
+    // The account keys of the validators, to find the self-bonds
+    let validator_accounts: BTreeMap<Alias, common::PublicKey> =
+        validator_account
+            .iter()
+            .flatten()
+            .map(|tx| (tx.alias.clone(), tx.account_key.raw.clone()))
+            .collect();
+    let find_validator_wallet = |alias: &Alias, pk: &common::PublicKey| {
+        validator_wallets
+            .iter()
+            .find(|wallet| wallet.account_key.ref_to() == *pk)
+            .ok_or_else(|| SigningError::MissingValidatorWallet {
+                alias: alias.clone(),
+                pk: pk.clone(),
+            })
+    };
+
+    // The unsigned established accounts, to sign the bonds from them
+    let established_accounts = established_account.clone();
+
+    // Sign all the transactions. The txs that don't require a validator
+    // wallet are signed one by one with the first wallet that can sign them.
+    let established_account = established_account
+        .map(|tx| {
+            tx.into_iter()
+                .map(|tx| {
+                    let txs = UnsignedTransactions {
+                        established_account: Some(vec![tx]),
+                        ..Default::default()
+                    };
+                    let signed = try_sign_txs_with_any(txs, wallets)?;
+                    Ok(single_tx(signed.established_account))
+                })
+                .collect::<Result<Vec<_>, GenesisTxError>>()
+        })
+        .transpose()?;
+    let validator_account = validator_account
+        .map(|tx| {
+            tx.into_iter()
+                .map(|tx| {
+                    let validator_wallet =
+                        find_validator_wallet(&tx.alias, &tx.account_key.raw)?;
+                    let account_keys = tx
+                        .account_keys
+                        .iter()
+                        .map(|pk| find_signing_key_in_wallets(wallets, pk))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    Ok(sign_validator_account_tx(
+                        tx,
+                        validator_wallet,
+                        &account_keys,
+                    ))
+                })
+                .collect::<Result<Vec<_>, GenesisTxError>>()
+        })
+        .transpose()?;
+    let transfer = transfer
+        .map(|tx| {
+            tx.into_iter()
+                .map(|tx| {
+                    let txs = UnsignedTransactions {
+                        transfer: Some(vec![tx]),
+                        ..Default::default()
+                    };
+                    let signed = try_sign_txs_with_any(txs, wallets)?;
+                    Ok(single_tx(signed.transfer))
+                })
+                .collect::<Result<Vec<_>, GenesisTxError>>()
+        })
+        .transpose()?;
+    let bond = bond
+        .map(|tx| {
+            tx.into_iter()
+                .map(|tx| {
+                    if tx.is_self_bond(&validator_accounts) {
+                        let validator = &tx.validator;
+                        let pk = validator_accounts.get(validator).ok_or_else(
+                            || {
+                                SigningError::MissingValidatorAccount(
+                                    validator.clone(),
+                                )
+                            },
+                        )?;
+                        let validator_wallet =
+                            find_validator_wallet(validator, pk)?;
+                        return Ok(sign_self_bond_tx(tx, validator_wallet));
+                    }
+                    // A bond from an established account is signed with the
+                    // account's key, so the account is signed with it
+                    let source_account = established_accounts
+                        .iter()
+                        .flatten()
+                        .filter(|account| {
+                            matches!(
+                                &tx.source,
+                                AliasOrPk::Alias(alias)
+                                    if alias == &account.alias
+                            )
+                        })
+                        .cloned()
+                        .collect();
+                    let txs = UnsignedTransactions {
+                        established_account: Some(source_account),
+                        bond: Some(vec![tx]),
+                        ..Default::default()
+                    };
+                    let signed = try_sign_txs_with_any(txs, wallets)?;
+                    Ok(single_tx(signed.bond))
+                })
+                .collect::<Result<Vec<_>, GenesisTxError>>()
+        })
+        .transpose()?;
 
-    Transactions {
+    Ok(Transactions {
         established_account,
         validator_account,
         transfer,
         bond,
+    })
+}
+
+/// Sign the txs with the first of the `wallets` with which [`try_sign_txs`]
+/// succeeds. Returns the error of the last wallet if none of them can sign the
+/// txs.
+fn try_sign_txs_with_any(
+    txs: UnsignedTransactions,
+    wallets: &mut [Wallet<CliWalletUtils>],
+) -> Result<Transactions<Unvalidated>, GenesisTxError> {
+    let mut last_err = None;
+    for wallet in wallets.iter_mut() {
+        match try_sign_txs(txs.clone(), wallet) {
+            Ok(signed) => return Ok(signed),
+            Err(err) => last_err = Some(err),
+        }
     }
+    Err(last_err.unwrap_or_else(|| SigningError::NoWallets.into()))
+}
+
+/// Take the single signed tx out of the txs signed from a single unsigned tx
+fn single_tx<T>(txs: Option<Vec<T>>) -> T {
+    txs.and_then(|txs| txs.into_iter().next())
+        .expect("A signed unsigned tx must yield a signed tx")
 }
 
 /// Parse [`UnsignedTransactions`] from bytes.
 pub fn parse_unsigned(
     bytes: &[u8],
-) -> Result<UnsignedTransactions, toml::de::Error> {
-    toml::from_slice(bytes)
+) -> Result<UnsignedTransactions, GenesisTxError> {
+    Ok(toml::from_slice(bytes)?)
+}
+
+/// Parse [`UnsignedTransactions`] from bytes, rejecting any unknown fields,
+/// e.g. misspelled keys, which would otherwise be silently ignored.
+pub fn parse_unsigned_strict(
+    bytes: &[u8],
+) -> Result<UnsignedTransactions, GenesisTxError> {
+    let unsigned = parse_unsigned(bytes)?;
+    // The txs are valid, so the only error left is an unknown field
+    toml::from_slice::<StrictUnsignedTransactions>(bytes)
+        .map_err(|err| GenesisTxError::UnknownField(err.to_string()))?;
+    Ok(unsigned)
+}
+
+/// The fields of [`UnsignedTransactions`] that are accepted by
+/// [`parse_unsigned_strict`]. The values are ignored, as they're parsed with
+/// [`parse_unsigned`].
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+struct StrictUnsignedTransactions {
+    established_account: Option<Vec<StrictEstablishedAccountTx>>,
+    validator_account: Option<Vec<StrictValidatorAccountTx>>,
+    transfer: Option<Vec<StrictTransferTx>>,
+    bond: Option<Vec<StrictBondTx>>,
+}
+
+/// The fields of [`EstablishedAccountTx`]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+struct StrictEstablishedAccountTx {
+    alias: IgnoredAny,
+    vp: IgnoredAny,
+    public_key: Option<IgnoredAny>,
+    storage: Option<IgnoredAny>,
+}
+
+/// The fields of [`ValidatorAccountTx`], including the legacy names of
+/// [`ValidatorAccountTxCompat`]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+struct StrictValidatorAccountTx {
+    alias: IgnoredAny,
+    vp: IgnoredAny,
+    commission_rate: IgnoredAny,
+    max_commission_rate_change: IgnoredAny,
+    email: IgnoredAny,
+    description: Option<IgnoredAny>,
+    website: Option<IgnoredAny>,
+    discord_handle: Option<IgnoredAny>,
+    net_address: IgnoredAny,
+    threshold: Option<IgnoredAny>,
+    account_key: IgnoredAny,
+    consensus_key: IgnoredAny,
+    protocol_key: IgnoredAny,
+    tendermint_node_key: IgnoredAny,
+    eth_hot_key: Option<IgnoredAny>,
+    eth_bridge_key: Option<IgnoredAny>,
+    eth_cold_key: IgnoredAny,
+    account_keys: Option<IgnoredAny>,
+}
+
+/// The fields of [`TransferTx`]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+struct StrictTransferTx {
+    token: IgnoredAny,
+    source: IgnoredAny,
+    target: IgnoredAny,
+    amount: IgnoredAny,
+    nonce: Option<IgnoredAny>,
+}
+
+/// The fields of [`BondTx`]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+struct StrictBondTx {
+    source: IgnoredAny,
+    validator: IgnoredAny,
+    amount: IgnoredAny,
+    nonce: Option<IgnoredAny>,
+}
+
+/// The legacy names of fields that are still accepted when parsing, with
+/// the current names that they're read as
+const LEGACY_FIELD_NAMES: &[(&str, &str)] =
+    &[("eth_bridge_key", "eth_hot_key")];
+
+/// Lint the genesis txs in the given bytes for fields with a legacy name
+/// (see [`LEGACY_FIELD_NAMES`]), which are still accepted when parsing, but
+/// should be renamed.
+pub fn lint_legacy_fields(
+    bytes: &[u8],
+) -> Result<Vec<LintWarning>, GenesisTxError> {
+    let value: toml::Value = toml::from_slice(bytes)?;
+    let mut warnings = vec![];
+    find_legacy_fields(&value, "", &mut warnings);
+    Ok(warnings)
+}
+
+/// Find the paths of the keys with a legacy name in the `value`
+fn find_legacy_fields(
+    value: &toml::Value,
+    path: &str,
+    warnings: &mut Vec<LintWarning>,
+) {
+    match value {
+        toml::Value::Table(table) => {
+            for (key, value) in table {
+                let path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                if let Some((_, current)) =
+                    LEGACY_FIELD_NAMES.iter().find(|(legacy, _)| key == *legacy)
+                {
+                    warnings.push(LintWarning::LegacyFieldName {
+                        path: path.clone(),
+                        current: current.to_string(),
+                    });
+                }
+                find_legacy_fields(value, &path, warnings);
+            }
+        }
+        toml::Value::Array(array) => {
+            for (ix, value) in array.iter().enumerate() {
+                find_legacy_fields(value, &format!("{path}[{ix}]"), warnings);
+            }
+        }
+        _ => {}
+    }
 }
 
 /// Create signed [`Transactions`] for a genesis validator.
@@ -118,6 +607,7 @@ pub fn init_validator(
     GenesisValidatorData {
         source_key,
         alias,
+        native_token_alias,
         commission_rate,
         max_commission_rate_change,
         net_address,
@@ -130,7 +620,7 @@ pub fn init_validator(
     }: GenesisValidatorData,
     source_wallet: &mut Wallet<CliWalletUtils>,
     validator_wallet: &ValidatorWallet,
-) -> Transactions<Unvalidated> {
+) -> Result<Transactions<Unvalidated>, FindKeyError> {
     let unsigned_validator_account_tx = UnsignedValidatorAccountTx {
         alias: alias.clone(),
         account_key: StringEncoded::new(validator_wallet.account_key.ref_to()),
@@ -161,10 +651,13 @@ pub fn init_validator(
         website,
         discord_handle,
         net_address,
+        threshold: None,
+        account_keys: vec![],
     };
     let validator_account = Some(vec![sign_validator_account_tx(
         unsigned_validator_account_tx,
         validator_wallet,
+        &[],
     )]);
 
     let transfer = if transfer_from_source_amount.amount.is_zero() {
@@ -172,12 +665,14 @@ pub fn init_validator(
     } else {
         let unsigned_transfer_tx = TransferTx {
             // Only native token can be staked
-            token: Alias::from("NAM"),
+            token: native_token_alias,
             source: StringEncoded::new(source_key.ref_to()),
             target: alias.clone(),
             amount: transfer_from_source_amount,
+            nonce: None,
         };
-        let transfer_tx = sign_transfer_tx(unsigned_transfer_tx, source_wallet);
+        let transfer_tx =
+            sign_transfer_tx(unsigned_transfer_tx, source_wallet)?;
         Some(vec![transfer_tx])
     };
 
@@ -187,34 +682,58 @@ pub fn init_validator(
         let unsigned_bond_tx = BondTx {
             source: AliasOrPk::Alias(alias.clone()),
             validator: alias,
-            amount: self_bond_amount,
+            amount: BondAmount::Fixed(self_bond_amount),
+            nonce: None,
         };
         let bond_tx = sign_self_bond_tx(unsigned_bond_tx, validator_wallet);
         Some(vec![bond_tx])
     };
 
-    Transactions {
+    Ok(Transactions {
         validator_account,
         transfer,
         bond,
         ..Default::default()
-    }
+    })
 }
 
 pub fn sign_established_account_tx(
     unsigned_tx: UnsignedEstablishedAccountTx,
     wallet: &mut Wallet<CliWalletUtils>,
 ) -> SignedEstablishedAccountTx {
-    let key = unsigned_tx.public_key.as_ref().map(|pk| {
-        let secret = wallet
-            .find_key_by_pk(pk, None)
-            .expect("Key for source must be present to sign with it.");
-        let sig = sign_tx(&unsigned_tx, &secret);
-        SignedPk {
-            pk: pk.clone(),
-            authorization: sig,
-        }
-    });
+    try_sign_established_account_tx(unsigned_tx, wallet)
+        .unwrap_or_else(|err| panic!("{err}"))
+}
+
+pub fn try_sign_established_account_tx(
+    unsigned_tx: UnsignedEstablishedAccountTx,
+    wallet: &mut Wallet<CliWalletUtils>,
+) -> Result<SignedEstablishedAccountTx, GenesisTxError> {
+    sign_established_account_tx_with(unsigned_tx, |pk| {
+        find_signing_key(wallet, pk)
+    })
+}
+
+/// Sign an established account tx with the secret key of its public key, if
+/// any, found with the given `find_key`
+fn sign_established_account_tx_with(
+    unsigned_tx: UnsignedEstablishedAccountTx,
+    mut find_key: impl FnMut(
+        &common::PublicKey,
+    ) -> Result<common::SecretKey, GenesisTxError>,
+) -> Result<SignedEstablishedAccountTx, GenesisTxError> {
+    let key = unsigned_tx
+        .public_key
+        .as_ref()
+        .map(|pk| {
+            let secret = find_key(pk)?;
+            let sig = sign_tx(&unsigned_tx, &secret);
+            Ok::<_, GenesisTxError>(SignedPk {
+                pk: pk.clone(),
+                authorization: sig,
+            })
+        })
+        .transpose()?;
     let UnsignedEstablishedAccountTx {
         alias,
         vp,
@@ -222,104 +741,182 @@ pub fn sign_established_account_tx(
         storage,
     } = unsigned_tx;
 
-    SignedEstablishedAccountTx {
+    Ok(SignedEstablishedAccountTx {
         alias,
         vp,
         public_key: key,
         storage,
-    }
+    })
 }
 
+/// Sign a validator account tx with all the keys from the validator wallet.
+/// The secret keys for additional `account_keys`, if any, must be given in
+/// `account_keys`.
 pub fn sign_validator_account_tx(
     unsigned_tx: UnsignedValidatorAccountTx,
     validator_wallet: &ValidatorWallet,
+    account_keys: &[common::SecretKey],
 ) -> SignedValidatorAccountTx {
     // Sign the tx with every validator key to authorize their usage
-    let account_key_sig = sign_tx(&unsigned_tx, &validator_wallet.account_key);
-    let consensus_key_sig =
-        sign_tx(&unsigned_tx, &validator_wallet.consensus_key);
-    let protocol_key_sig = sign_tx(
-        &unsigned_tx,
-        &validator_wallet.store.validator_keys.protocol_keypair,
-    );
-    let eth_hot_key_sig = sign_tx(&unsigned_tx, &validator_wallet.eth_hot_key);
-    let eth_cold_key_sig =
-        sign_tx(&unsigned_tx, &validator_wallet.eth_cold_key);
-    let tendermint_node_key_sig =
-        sign_tx(&unsigned_tx, &validator_wallet.tendermint_node_key);
-
-    let ValidatorAccountTx {
-        alias,
+    let account_key = sign_tx(&unsigned_tx, &validator_wallet.account_key);
+    let account_keys = unsigned_tx
+        .account_keys
+        .iter()
+        .map(|pk| {
+            let secret = account_keys
+                .iter()
+                .find(|sk| sk.ref_to() == pk.raw)
+                .unwrap_or_else(|| {
+                    panic!(
+                        "Key for validator account key {pk} must be present \
+                         to sign with it."
+                    )
+                });
+            sign_tx(&unsigned_tx, secret)
+        })
+        .collect();
+    let authorizations = ValidatorAccountAuthorizations {
         account_key,
-        consensus_key,
-        protocol_key,
-        tendermint_node_key,
-        vp,
-        commission_rate,
-        max_commission_rate_change,
-        email,
-        description,
-        website,
-        discord_handle,
-        net_address,
-        eth_hot_key,
-        eth_cold_key,
-    } = unsigned_tx;
-
-    let account_key = SignedPk {
-        pk: account_key,
-        authorization: account_key_sig,
-    };
-    let consensus_key = SignedPk {
-        pk: consensus_key,
-        authorization: consensus_key_sig,
-    };
-    let protocol_key = SignedPk {
-        pk: protocol_key,
-        authorization: protocol_key_sig,
-    };
-    let tendermint_node_key = SignedPk {
-        pk: tendermint_node_key,
-        authorization: tendermint_node_key_sig,
+        account_keys,
+        consensus_key: sign_tx(&unsigned_tx, &validator_wallet.consensus_key),
+        protocol_key: sign_tx(
+            &unsigned_tx,
+            &validator_wallet.store.validator_keys.protocol_keypair,
+        ),
+        tendermint_node_key: sign_tx(
+            &unsigned_tx,
+            &validator_wallet.tendermint_node_key,
+        ),
+        eth_hot_key: sign_tx(&unsigned_tx, &validator_wallet.eth_hot_key),
+        eth_cold_key: sign_tx(&unsigned_tx, &validator_wallet.eth_cold_key),
     };
+    SignedValidatorAccountTx::from_authorizations(unsigned_tx, authorizations)
+}
 
-    let eth_hot_key = SignedPk {
-        pk: eth_hot_key,
-        authorization: eth_hot_key_sig,
-    };
+/// The authorizations of every key of a validator account tx, i.e. the
+/// signatures of its [`UnsignedValidatorAccountTx::signing_payload`]
+#[derive(Clone, Debug)]
+pub struct ValidatorAccountAuthorizations {
+    pub account_key: StringEncoded<common::Signature>,
+    /// The signatures of the `account_keys`, in the same order
+    pub account_keys: Vec<StringEncoded<common::Signature>>,
+    pub consensus_key: StringEncoded<common::Signature>,
+    pub protocol_key: StringEncoded<common::Signature>,
+    pub tendermint_node_key: StringEncoded<common::Signature>,
+    pub eth_hot_key: StringEncoded<common::Signature>,
+    pub eth_cold_key: StringEncoded<common::Signature>,
+}
 
-    let eth_cold_key = SignedPk {
-        pk: eth_cold_key,
-        authorization: eth_cold_key_sig,
-    };
+impl UnsignedValidatorAccountTx {
+    /// The exact bytes that each of the validator keys must sign to authorize
+    /// their usage. This allows to sign them on separate offline devices and
+    /// to reassemble the tx with
+    /// [`SignedValidatorAccountTx::from_authorizations`].
+    pub fn signing_payload(&self) -> Vec<u8> {
+        <SerializeWithBorsh as Signable<Self>>::as_signable(self)
+    }
+}
 
-    SignedValidatorAccountTx {
-        alias,
-        account_key,
-        consensus_key,
-        protocol_key,
-        tendermint_node_key,
-        vp,
-        commission_rate,
-        max_commission_rate_change,
-        email,
-        description,
-        website,
-        discord_handle,
-        net_address,
-        eth_hot_key,
-        eth_cold_key,
+impl SignedValidatorAccountTx {
+    /// Reassemble a signed validator account tx from the detached
+    /// authorizations of its keys. The authorizations are not checked here,
+    /// but in the validation of the tx.
+    pub fn from_authorizations(
+        unsigned_tx: UnsignedValidatorAccountTx,
+        authorizations: ValidatorAccountAuthorizations,
+    ) -> Self {
+        let ValidatorAccountTx {
+            alias,
+            account_key,
+            consensus_key,
+            protocol_key,
+            tendermint_node_key,
+            vp,
+            commission_rate,
+            max_commission_rate_change,
+            email,
+            description,
+            website,
+            discord_handle,
+            net_address,
+            eth_hot_key,
+            eth_cold_key,
+            threshold,
+            account_keys,
+        } = unsigned_tx;
+        let sign = |pk, authorization| SignedPk { pk, authorization };
+
+        ValidatorAccountTx {
+            alias,
+            account_key: sign(account_key, authorizations.account_key),
+            consensus_key: sign(consensus_key, authorizations.consensus_key),
+            protocol_key: sign(protocol_key, authorizations.protocol_key),
+            tendermint_node_key: sign(
+                tendermint_node_key,
+                authorizations.tendermint_node_key,
+            ),
+            vp,
+            commission_rate,
+            max_commission_rate_change,
+            email,
+            description,
+            website,
+            discord_handle,
+            net_address,
+            eth_hot_key: sign(eth_hot_key, authorizations.eth_hot_key),
+            eth_cold_key: sign(eth_cold_key, authorizations.eth_cold_key),
+            threshold,
+            account_keys: account_keys
+                .into_iter()
+                .zip(authorizations.account_keys)
+                .map(|(pk, authorization)| sign(pk, authorization))
+                .collect(),
+        }
     }
 }
 
+/// Sign a transfer with the key of its source from the wallet. Returns an
+/// error if the key cannot be found.
 pub fn sign_transfer_tx(
     unsigned_tx: TransferTx<Unvalidated>,
     source_wallet: &mut Wallet<CliWalletUtils>,
-) -> SignedTransferTx {
-    let source_key = source_wallet
-        .find_key_by_pk(&unsigned_tx.source, None)
-        .expect("Key for source must be present to sign with it.");
-    unsigned_tx.sign(&source_key)
+) -> Result<SignedTransferTx, FindKeyError> {
+    let source_key = source_wallet.find_key_by_pk(&unsigned_tx.source, None)?;
+    Ok(unsigned_tx.sign(&source_key))
+}
+
+/// Find the secret key of the given source PK in the wallet
+fn find_signing_key(
+    wallet: &mut Wallet<CliWalletUtils>,
+    pk: &common::PublicKey,
+) -> Result<common::SecretKey, GenesisTxError> {
+    wallet
+        .find_key_by_pk(pk, None)
+        .map_err(|err| signing_key_error(pk, err))
+}
+
+/// The error of a missing key of the given source PK in the wallet
+fn signing_key_error(
+    pk: &common::PublicKey,
+    err: FindKeyError,
+) -> GenesisTxError {
+    SigningError::MissingKey {
+        pk: pk.clone(),
+        err,
+    }
+    .into()
+}
+
+/// Find the secret key of the given PK in the first of the wallets that holds
+/// it
+fn find_signing_key_in_wallets(
+    wallets: &mut [Wallet<CliWalletUtils>],
+    pk: &common::PublicKey,
+) -> Result<common::SecretKey, GenesisTxError> {
+    wallets
+        .iter_mut()
+        .find_map(|wallet| wallet.find_key_by_pk(pk, None).ok())
+        .ok_or_else(|| SigningError::KeyNotInWallets(pk.clone()).into())
 }
 
 pub fn sign_self_bond_tx(
@@ -334,6 +931,15 @@ pub fn sign_delegation_bond_tx(
     wallet: &mut Wallet<CliWalletUtils>,
     established_accounts: &Option<Vec<EstablishedAccountTx<SignedPk>>>,
 ) -> SignedBondTx {
+    try_sign_delegation_bond_tx(unsigned_tx, wallet, established_accounts)
+        .unwrap_or_else(|err| panic!("{err}"))
+}
+
+pub fn try_sign_delegation_bond_tx(
+    unsigned_tx: BondTx<Unvalidated>,
+    wallet: &mut Wallet<CliWalletUtils>,
+    established_accounts: &Option<Vec<EstablishedAccountTx<SignedPk>>>,
+) -> Result<SignedBondTx, GenesisTxError> {
     let alias = &unsigned_tx.source;
     // Try to look-up the source from wallet first - if it's an alias of an
     // implicit account that should give us the right key
@@ -348,62 +954,47 @@ pub fn sign_delegation_bond_tx(
         Err(FindKeyError::KeyNotFound) => {
             // If it's not in the wallet, it must be an established account
             // so we need to look-up its public key first
-            let pk = established_accounts
-                .as_ref()
-                .unwrap_or_else(|| {
-                    panic!(
-                        "Signing a bond failed. Cannot find \"{alias}\" in \
-                         the wallet and there are no established accounts."
-                    );
-                })
-                .iter()
-                .find_map(|account| match alias {
-                    AliasOrPk::Alias(alias) => {
-                        // delegation from established account
-                        if &account.alias == alias {
-                            Some(
-                                &account
-                                    .public_key
-                                    .as_ref()
-                                    .unwrap_or_else(|| {
-                                        panic!(
-                                            "Signing a bond failed. The \
-                                             established account \"{alias}\" \
-                                             has no public key. Add a public \
-                                             to be able to sign bonds."
-                                        );
-                                    })
-                                    .pk
-                                    .raw,
+            let accounts = established_accounts.as_ref().ok_or_else(|| {
+                SigningError::UnknownBondSource(alias.clone())
+            })?;
+            let pk = match alias {
+                AliasOrPk::Alias(alias) => {
+                    // delegation from established account
+                    let account = accounts
+                        .iter()
+                        .find(|account| &account.alias == alias)
+                        .ok_or_else(|| {
+                            SigningError::UnknownBondSource(
+                                unsigned_tx.source.clone(),
                             )
-                        } else {
-                            None
-                        }
-                    }
-                    AliasOrPk::PublicKey(pk) => {
-                        // delegation from an implicit account
-                        Some(&pk.raw)
-                    }
-                })
-                .unwrap_or_else(|| {
-                    panic!(
-                        "Signing a bond failed. Cannot find \"{alias}\" in \
-                         the wallet or in the established accounts."
-                    );
-                });
-            wallet.find_key_by_pk(pk, None).unwrap_or_else(|err| {
-                panic!(
-                    "Signing a bond failed. Cannot find key for established \
-                     account \"{alias}\" in the wallet. Failed with {err}."
-                );
-            })
+                        })?;
+                    let public_key =
+                        account.public_key.as_ref().ok_or_else(|| {
+                            SigningError::EstablishedAccountWithoutKey(
+                                alias.clone(),
+                            )
+                        })?;
+                    &public_key.pk.raw
+                }
+                // delegation from an implicit account
+                AliasOrPk::PublicKey(pk) => &pk.raw,
+            };
+            wallet.find_key_by_pk(pk, None).map_err(|err| {
+                SigningError::BondSourceKey {
+                    source: alias.clone(),
+                    err,
+                }
+            })?
+        }
+        Err(err) => {
+            return Err(SigningError::BondSourceKey {
+                source: alias.clone(),
+                err,
+            }
+            .into());
         }
-        Err(err) => panic!(
-            "Signing a bond failed. Failed to read the key for \"{alias}\" \
-             from wallet with {err}."
-        ),
     };
-    unsigned_tx.sign(&source_key)
+    Ok(unsigned_tx.sign(&source_key))
 }
 
 pub fn sign_tx<T: BorshSerialize>(
@@ -416,6 +1007,25 @@ pub fn sign_tx<T: BorshSerialize>(
     >(keypair, tx_data))
 }
 
+/// The kind of an account initialized by the genesis txs
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccountKind {
+    /// An account initialized by a validator account tx
+    Validator,
+    /// An account initialized by an established account tx
+    Established,
+}
+
+/// Get the borsh schema definitions of the genesis txs, both as they're
+/// signed in the txs files and once they're validated, e.g. to generate their
+/// encoding for clients.
+pub fn transactions_borsh_schema() -> BTreeMap<Declaration, Definition> {
+    let mut definitions = BTreeMap::new();
+    Transactions::<Unvalidated>::add_definitions_recursively(&mut definitions);
+    Transactions::<Validated>::add_definitions_recursively(&mut definitions);
+    definitions
+}
+
 #[derive(
     Clone,
     Debug,
@@ -423,6 +1033,7 @@ pub fn sign_tx<T: BorshSerialize>(
     Serialize,
     BorshDeserialize,
     BorshSerialize,
+    BorshSchema,
     PartialEq,
     Eq,
 )]
@@ -477,20 +1088,462 @@ impl<T: TemplateValidation> Transactions<T> {
             })
             .or(other.bond);
     }
-}
 
-impl<T: TemplateValidation> Default for Transactions<T> {
-    fn default() -> Self {
-        Self {
-            established_account: None,
-            validator_account: None,
-            transfer: None,
-            bond: None,
-        }
-    }
-}
+    /// Get the names of all the VPs used by the established and validator
+    /// accounts
+    pub fn required_vps(&self) -> BTreeSet<String> {
+        let established_vps = self
+            .established_account
+            .iter()
+            .flatten()
+            .map(|tx| tx.vp.clone());
+        let validator_vps = self
+            .validator_account
+            .iter()
+            .flatten()
+            .map(|tx| tx.vp.clone());
+        established_vps.chain(validator_vps).collect()
+    }
+
+    /// Find the kind of the account with the given alias, e.g. to tell apart
+    /// transfers to validators from transfers to established accounts.
+    /// Returns `None` when no account is initialized with the alias.
+    pub fn target_kind(&self, alias: &Alias) -> Option<AccountKind> {
+        if self
+            .validator_account
+            .iter()
+            .flatten()
+            .any(|tx| &tx.alias == alias)
+        {
+            Some(AccountKind::Validator)
+        } else if self
+            .established_account
+            .iter()
+            .flatten()
+            .any(|tx| &tx.alias == alias)
+        {
+            Some(AccountKind::Established)
+        } else {
+            None
+        }
+    }
+
+    /// Estimate the cost of applying the txs at genesis from their counts.
+    /// This is only an advisory metric for operators, it's not used for
+    /// consensus.
+    pub fn estimate_apply_cost(&self) -> GenesisCost {
+        GenesisCost {
+            established_accounts: self
+                .established_account
+                .as_ref()
+                .map_or(0, Vec::len),
+            validator_accounts: self
+                .validator_account
+                .as_ref()
+                .map_or(0, Vec::len),
+            transfers: self.transfer.as_ref().map_or(0, Vec::len),
+            bonds: self.bond.as_ref().map_or(0, Vec::len),
+        }
+    }
+}
+
+/// A rough estimate of the cost of applying genesis txs, given by their
+/// counts. Genesis txs don't pay fees, but still take time to apply.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GenesisCost {
+    /// The number of established accounts created
+    pub established_accounts: usize,
+    /// The number of validator accounts created
+    pub validator_accounts: usize,
+    /// The number of transfers
+    pub transfers: usize,
+    /// The number of bonds
+    pub bonds: usize,
+}
+
+impl GenesisCost {
+    /// The number of all the accounts created
+    pub fn accounts(&self) -> usize {
+        self.established_accounts + self.validator_accounts
+    }
+
+    /// The number of all the txs
+    pub fn txs(&self) -> usize {
+        self.accounts() + self.transfers + self.bonds
+    }
+}
+
+/// Push a tx into an optional list of txs, initializing it if it's `None`
+fn push_tx<Tx>(txs: &mut Option<Vec<Tx>>, tx: Tx) {
+    txs.get_or_insert_with(Vec::new).push(tx)
+}
+
+/// A builder of [`Transactions`] that adds the txs one by one
+pub struct TransactionsBuilder<T: TemplateValidation> {
+    txs: Transactions<T>,
+}
+
+impl<T: TemplateValidation> TransactionsBuilder<T> {
+    /// Start with no txs
+    pub fn new() -> Self {
+        Self {
+            txs: Transactions::default(),
+        }
+    }
+
+    /// Add an established account tx
+    pub fn add_established(mut self, tx: SignedEstablishedAccountTx) -> Self {
+        push_tx(&mut self.txs.established_account, tx);
+        self
+    }
+
+    /// Add a validator account tx
+    pub fn add_validator(mut self, tx: SignedValidatorAccountTx) -> Self {
+        push_tx(&mut self.txs.validator_account, tx);
+        self
+    }
+
+    /// Add a transfer tx
+    pub fn add_transfer(mut self, tx: T::TransferTx) -> Self {
+        push_tx(&mut self.txs.transfer, tx);
+        self
+    }
+
+    /// Add a bond tx
+    pub fn add_bond(mut self, tx: T::BondTx) -> Self {
+        push_tx(&mut self.txs.bond, tx);
+        self
+    }
+
+    /// Get the added txs. The kinds of txs that weren't added are `None`.
+    pub fn build(self) -> Transactions<T> {
+        self.txs
+    }
+}
+
+impl<T: TemplateValidation> Default for TransactionsBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: TemplateValidation> Default for Transactions<T> {
+    fn default() -> Self {
+        Self {
+            established_account: None,
+            validator_account: None,
+            transfer: None,
+            bond: None,
+        }
+    }
+}
+
+impl Transactions<Unvalidated> {
+    /// Net the transfers that share the same token, source and target into a
+    /// single transfer of their summed amount, placed at the position of the
+    /// first of them. Returns an error if a summed amount overflows.
+    ///
+    /// The signatures of the txs are dropped, because the netted transfers
+    /// are not covered by them, so all the returned txs have to be re-signed.
+    pub fn net_transfers(
+        &self,
+    ) -> Result<UnsignedTransactions, GenesisTxError> {
+        let mut transfers: Vec<TransferTx<Unvalidated>> = vec![];
+        let mut netted: BTreeMap<
+            (Alias, StringEncoded<common::PublicKey>, Alias),
+            usize,
+        > = BTreeMap::new();
+        for tx in self.transfer.iter().flatten() {
+            let tx = TransferTx::<Unvalidated>::from(tx);
+            let key = (tx.token.clone(), tx.source.clone(), tx.target.clone());
+            match netted.get(&key) {
+                Some(ix) => {
+                    let netted_tx = &mut transfers[*ix];
+                    netted_tx.amount = sum_amounts(netted_tx.amount, tx.amount)
+                        .ok_or_else(|| {
+                            GenesisTxError::AmountOverflow(format!(
+                                "Netting transfers of token {} from {} to {} \
+                                 overflows",
+                                tx.token, tx.source, tx.target
+                            ))
+                        })?;
+                }
+                None => {
+                    netted.insert(key, transfers.len());
+                    transfers.push(tx);
+                }
+            }
+        }
+        Ok(UnsignedTransactions {
+            established_account: self.established_account.as_ref().map(|txs| {
+                txs.iter().map(UnsignedEstablishedAccountTx::from).collect()
+            }),
+            validator_account: self.validator_account.as_ref().map(|txs| {
+                txs.iter().map(UnsignedValidatorAccountTx::from).collect()
+            }),
+            transfer: self.transfer.as_ref().map(|_| transfers),
+            bond: self
+                .bond
+                .as_ref()
+                .map(|txs| txs.iter().map(BondTx::from).collect()),
+        })
+    }
+
+    /// Remove the transfer and bond txs whose signed data duplicates that of
+    /// a preceding tx, e.g. after merging the same txs twice. Txs that only
+    /// differ in their nonce are not duplicates. Returns the number of the
+    /// removed txs.
+    pub fn dedup(&mut self) -> usize {
+        let mut removed = 0;
+        if let Some(txs) = self.transfer.as_mut() {
+            let len = txs.len();
+            let mut seen = BTreeSet::new();
+            txs.retain(|tx| {
+                seen.insert(tx.data.data_to_sign(SIGNED_FORMAT_VERSION))
+            });
+            removed += len - txs.len();
+        }
+        if let Some(txs) = self.bond.as_mut() {
+            let len = txs.len();
+            let mut seen = BTreeSet::new();
+            txs.retain(|tx| {
+                seen.insert(tx.data.data_to_sign(SIGNED_FORMAT_VERSION))
+            });
+            removed += len - txs.len();
+        }
+        removed
+    }
+
+    /// Lint the txs for references to accounts that are not created by any
+    /// of the txs and for established accounts without a public key. Unlike
+    /// [`validate`], this doesn't check the signatures or balances, so it can
+    /// be used for quick feedback before the full validation.
+    pub fn lint(&self) -> Vec<LintWarning> {
+        let mut warnings = vec![];
+        let established_aliases: BTreeSet<&Alias> = self
+            .established_account
+            .iter()
+            .flatten()
+            .map(|tx| &tx.alias)
+            .collect();
+        let validator_aliases: BTreeSet<&Alias> = self
+            .validator_account
+            .iter()
+            .flatten()
+            .map(|tx| &tx.alias)
+            .collect();
+
+        for tx in self.established_account.iter().flatten() {
+            if tx.public_key.is_none() {
+                warnings.push(LintWarning::EstablishedAccountWithoutPk(
+                    tx.alias.clone(),
+                ));
+            }
+        }
+        for tx in self.transfer.iter().flatten() {
+            let target = &tx.data.target;
+            if !established_aliases.contains(target)
+                && !validator_aliases.contains(target)
+            {
+                warnings.push(LintWarning::OrphanTransferTarget {
+                    token: tx.data.token.clone(),
+                    target: target.clone(),
+                });
+            }
+        }
+        for tx in self.bond.iter().flatten() {
+            if !validator_aliases.contains(&tx.data.validator) {
+                warnings.push(LintWarning::UnknownBondValidator {
+                    source: tx.data.source.clone(),
+                    validator: tx.data.validator.clone(),
+                });
+            }
+        }
+        warnings
+    }
+
+    /// Drop the signatures and the authorizations of all the txs, e.g. to
+    /// edit the txs and sign them again.
+    pub fn strip_signatures(self) -> UnsignedTransactions {
+        let Transactions {
+            established_account,
+            validator_account,
+            transfer,
+            bond,
+        } = self;
+        UnsignedTransactions {
+            established_account: established_account.map(|txs| {
+                txs.iter().map(UnsignedEstablishedAccountTx::from).collect()
+            }),
+            validator_account: validator_account.map(|txs| {
+                txs.iter().map(UnsignedValidatorAccountTx::from).collect()
+            }),
+            transfer: transfer
+                .map(|txs| txs.into_iter().map(|tx| tx.data).collect()),
+            bond: bond.map(|txs| txs.into_iter().map(|tx| tx.data).collect()),
+        }
+    }
+
+    /// Get the canonical hash of the txs, which doesn't depend on the order
+    /// of the txs of each kind, e.g. in which the txs from different files
+    /// were merged. A missing list of txs hashes the same as an empty one.
+    pub fn canonical_hash(&self) -> Hash {
+        fn sorted_bytes<Tx: BorshSerialize>(
+            txs: &Option<Vec<Tx>>,
+        ) -> Vec<Vec<u8>> {
+            let mut bytes: Vec<Vec<u8>> = txs
+                .iter()
+                .flatten()
+                .map(|tx| tx.serialize_to_vec())
+                .collect();
+            bytes.sort();
+            bytes
+        }
+        let canonical = (
+            sorted_bytes(&self.established_account),
+            sorted_bytes(&self.validator_account),
+            sorted_bytes(&self.transfer),
+            sorted_bytes(&self.bond),
+        );
+        Hash::sha256(canonical.serialize_to_vec())
+    }
+
+    /// Check that the canonical hash of the txs matches the `expected` one,
+    /// e.g. one that's been published for the genesis files.
+    pub fn verify_hash(&self, expected: &Hash) -> bool {
+        &self.canonical_hash() == expected
+    }
+
+    /// Get the aliases of all the tokens moved by the transfers, e.g. to
+    /// check that they're all declared in the tokens file. Bonds always
+    /// stake the native token, which is only included when it's transferred.
+    pub fn referenced_tokens(&self) -> BTreeSet<Alias> {
+        self.transfer
+            .iter()
+            .flatten()
+            .map(|tx| tx.data.token.clone())
+            .collect()
+    }
+
+    /// Split the txs by validator, e.g. to review the contribution of each
+    /// validator separately. Each validator's subset contains its validator
+    /// account tx and the transfers and bonds that target the validator. All
+    /// the other txs, including the established account txs, are returned
+    /// separately as the second element.
+    pub fn partition_by_validator(
+        &self,
+    ) -> (
+        BTreeMap<Alias, Transactions<Unvalidated>>,
+        Transactions<Unvalidated>,
+    ) {
+        let mut partition: BTreeMap<Alias, Transactions<Unvalidated>> =
+            BTreeMap::new();
+        let mut other = Transactions::<Unvalidated>::default();
+        let validator_aliases: BTreeSet<&Alias> = self
+            .validator_account
+            .iter()
+            .flatten()
+            .map(|tx| &tx.alias)
+            .collect();
+
+        for tx in self.validator_account.iter().flatten() {
+            push_tx(
+                &mut partition
+                    .entry(tx.alias.clone())
+                    .or_default()
+                    .validator_account,
+                tx.clone(),
+            );
+        }
+        for tx in self.transfer.iter().flatten() {
+            let subset = match validator_aliases.get(&tx.data.target) {
+                Some(validator) => {
+                    partition.entry((*validator).clone()).or_default()
+                }
+                None => &mut other,
+            };
+            push_tx(&mut subset.transfer, tx.clone());
+        }
+        for tx in self.bond.iter().flatten() {
+            let subset = match validator_aliases.get(&tx.data.validator) {
+                Some(validator) => {
+                    partition.entry((*validator).clone()).or_default()
+                }
+                None => &mut other,
+            };
+            push_tx(&mut subset.bond, tx.clone());
+        }
+        for tx in self.established_account.iter().flatten() {
+            push_tx(&mut other.established_account, tx.clone());
+        }
+        (partition, other)
+    }
+}
+
+/// A warning from linting genesis transactions
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum LintWarning {
+    #[error(
+        "A transfer of token \"{token}\" targets an alias \"{target}\" \
+         with no matching established or validator account."
+    )]
+    OrphanTransferTarget { token: Alias, target: Alias },
+    #[error(
+        "A bond from {source} targets an alias \"{validator}\" with no \
+         matching validator account."
+    )]
+    UnknownBondValidator { source: AliasOrPk, validator: Alias },
+    #[error("The established account \"{0}\" has no public key.")]
+    EstablishedAccountWithoutPk(Alias),
+    #[error(
+        "The field \"{path}\" uses a legacy name, which is read as \
+         \"{current}\". Please rename the field."
+    )]
+    LegacyFieldName { path: String, current: String },
+}
+
+/// Sum two amounts at the higher precision of the two. Returns `None` on
+/// overflow.
+fn sum_amounts(
+    a: DenominatedAmount,
+    b: DenominatedAmount,
+) -> Option<DenominatedAmount> {
+    let denom = std::cmp::max(a.denom, b.denom);
+    let a = a.increase_precision(denom).ok()?;
+    let b = b.increase_precision(denom).ok()?;
+    Some(DenominatedAmount {
+        amount: a.amount.checked_add(b.amount)?,
+        denom,
+    })
+}
 
 impl Transactions<Validated> {
+    /// Iterate the established account txs, if any
+    pub fn iter_established(
+        &self,
+    ) -> impl Iterator<Item = &SignedEstablishedAccountTx> {
+        self.established_account.iter().flatten()
+    }
+
+    /// Iterate the validator account txs, if any
+    pub fn iter_validators(
+        &self,
+    ) -> impl Iterator<Item = &SignedValidatorAccountTx> {
+        self.validator_account.iter().flatten()
+    }
+
+    /// Iterate the transfer txs, if any
+    pub fn iter_transfers(
+        &self,
+    ) -> impl Iterator<Item = &TransferTx<Validated>> {
+        self.transfer.iter().flatten()
+    }
+
+    /// Iterate the bond txs, if any
+    pub fn iter_bonds(&self) -> impl Iterator<Item = &BondTx<Validated>> {
+        self.bond.iter().flatten()
+    }
+
     /// Check that there is at least one validator.
     pub fn has_at_least_one_validator(&self) -> bool {
         self.validator_account
@@ -502,34 +1555,206 @@ impl Transactions<Validated> {
     /// Check if there is at least one validator with positive Tendermint voting
     /// power. The voting power is converted from `token::Amount` of the
     /// validator's stake using the `tm_votes_per_token` PoS parameter.
+    ///
+    /// Returns an error if the sum of the bonds to any validator overflows.
     pub fn has_validator_with_positive_voting_power(
         &self,
         votes_per_token: Dec,
-    ) -> bool {
-        self.bond
-            .as_ref()
-            .map(|txs| {
-                let mut stakes: BTreeMap<&Alias, token::Amount> =
-                    BTreeMap::new();
-                for tx in txs {
-                    let entry = stakes.entry(&tx.validator).or_default();
-                    *entry += tx.amount.amount;
-                }
+    ) -> Result<bool, GenesisTxError> {
+        if self.bond.is_none() {
+            return Ok(false);
+        }
+        let stakes = self.bonded_stakes()?;
 
-                stakes.into_iter().any(|(_validator, stake)| {
-                    let tendermint_voting_power =
-                        namada::ledger::pos::into_tm_voting_power(
-                            votes_per_token,
-                            stake,
-                        );
-                    if tendermint_voting_power > 0 {
-                        return true;
-                    }
-                    false
-                })
+        Ok(stakes.into_iter().any(|(_validator, stake)| {
+            let tendermint_voting_power =
+                namada::ledger::pos::into_tm_voting_power(
+                    votes_per_token,
+                    stake,
+                );
+            tendermint_voting_power > 0
+        }))
+    }
+
+    /// Get the Tendermint voting power of every validator from the sum of
+    /// the bonds to it, using the `tm_votes_per_token` PoS parameter. When a
+    /// `cap` is given, a validator's voting power is limited to it, as it
+    /// would be on a chain with capped voting power. Validators without any
+    /// bonds have no voting power.
+    ///
+    /// Returns an error if the sum of the bonds to any validator overflows.
+    pub fn capped_voting_powers(
+        &self,
+        votes_per_token: Dec,
+        cap: Option<u64>,
+    ) -> Result<BTreeMap<Alias, u64>, GenesisTxError> {
+        let mut stakes: BTreeMap<Alias, token::Amount> = self
+            .validator_account
+            .iter()
+            .flatten()
+            .map(|tx| (tx.alias.clone(), token::Amount::zero()))
+            .collect();
+        stakes.extend(self.bonded_stakes()?);
+
+        Ok(stakes
+            .into_iter()
+            .map(|(validator, stake)| {
+                let voting_power =
+                    u64::try_from(namada::ledger::pos::into_tm_voting_power(
+                        votes_per_token,
+                        stake,
+                    ))
+                    .unwrap_or_default();
+                let voting_power = match cap {
+                    Some(cap) => std::cmp::min(voting_power, cap),
+                    None => voting_power,
+                };
+                (validator, voting_power)
             })
-            .unwrap_or_default()
+            .collect())
+    }
+
+    /// Get every validator's total bonded stake and Tendermint voting power,
+    /// as in [`Self::capped_voting_powers`] without a cap, ordered by the
+    /// stake descending and then by the alias, e.g. to render the genesis
+    /// validator set.
+    ///
+    /// Returns an error if the sum of the bonds to any validator overflows.
+    pub fn validators_by_stake(
+        &self,
+        votes_per_token: Dec,
+    ) -> Result<Vec<(Alias, token::Amount, u64)>, GenesisTxError> {
+        let stakes = self.bonded_stakes()?;
+        let mut validators: Vec<_> = self
+            .capped_voting_powers(votes_per_token, None)?
+            .into_iter()
+            .map(|(validator, voting_power)| {
+                let stake = stakes.get(&validator).copied().unwrap_or_default();
+                (validator, stake, voting_power)
+            })
+            .collect();
+        validators.sort_by(|(alias_a, stake_a, _), (alias_b, stake_b, _)| {
+            stake_b.cmp(stake_a).then_with(|| alias_a.cmp(alias_b))
+        });
+        Ok(validators)
+    }
+
+    /// Find the bonds to validators whose total bonded stake converts to zero
+    /// Tendermint voting power, using the `tm_votes_per_token` PoS parameter.
+    /// Such bonds are dust that doesn't contribute to consensus.
+    ///
+    /// Returns an error if the sum of the bonds to any validator overflows.
+    pub fn bonds_with_zero_power_validators(
+        &self,
+        votes_per_token: Dec,
+    ) -> Result<Vec<&BondTx<Validated>>, GenesisTxError> {
+        let zero_power_validators: BTreeSet<Alias> = self
+            .bonded_stakes()?
+            .into_iter()
+            .filter_map(|(validator, stake)| {
+                (namada::ledger::pos::into_tm_voting_power(
+                    votes_per_token,
+                    stake,
+                ) == 0)
+                    .then_some(validator)
+            })
+            .collect();
+        Ok(self
+            .iter_bonds()
+            .filter(|tx| zero_power_validators.contains(&tx.validator))
+            .collect())
+    }
+
+    /// Sum up the bonds to each validator. Returns an error if the sum of the
+    /// bonds to any validator overflows.
+    fn bonded_stakes(
+        &self,
+    ) -> Result<BTreeMap<Alias, token::Amount>, GenesisTxError> {
+        aggregate_bond_stakes(self.bond.as_deref().unwrap_or_default())
+    }
+
+    /// Find the aliases of validators that have no self-bond, i.e. that are
+    /// only bonded to by delegations, if at all.
+    pub fn validators_without_self_bond(&self) -> Vec<Alias> {
+        let validator_accounts: BTreeMap<Alias, common::PublicKey> = self
+            .validator_account
+            .iter()
+            .flatten()
+            .map(|tx| (tx.alias.clone(), tx.account_key.pk.raw.clone()))
+            .collect();
+        let self_bonded: BTreeSet<&Alias> = self
+            .bond
+            .iter()
+            .flatten()
+            .filter(|tx| tx.is_self_bond(&validator_accounts))
+            .map(|tx| &tx.validator)
+            .collect();
+        validator_accounts
+            .into_keys()
+            .filter(|alias| !self_bonded.contains(alias))
+            .collect()
+    }
+
+    /// Validate the txs again, e.g. with parameters, balances or tokens that
+    /// have changed since they were validated, the same as in [`validate`].
+    /// Validated transfers and bonds don't keep their signatures, so these are
+    /// not verified again and only the rest of their data is re-checked. The
+    /// `balance_proofs` of the `options` are not checked again.
+    pub fn revalidate(
+        self,
+        vps: Option<&ValidityPredicates>,
+        balances: Option<&DenominatedBalances>,
+        tokens: &Tokens,
+        parameters: Option<&Parameters<Validated>>,
+        options: &ValidationOptions<'_>,
+    ) -> Result<Transactions<Validated>, GenesisTxError> {
+        let Transactions {
+            established_account,
+            validator_account,
+            transfer,
+            bond,
+        } = self;
+        let accounts = Transactions::<Unvalidated> {
+            established_account,
+            validator_account,
+            transfer: None,
+            bond: None,
+        };
+        let is_valid =
+            validate_signature_schemes(&accounts, options.allowed_schemes);
+        let mut validator =
+            GenesisValidator::new(parameters, tokens, vps, balances)
+                .with_options(ValidationOptions {
+                    balance_proofs: None,
+                    ..options.clone()
+                });
+        validator.add_aux(
+            accounts.established_account,
+            accounts.validator_account,
+            TransfersAndBonds::Validated { transfer, bond },
+            is_valid,
+        )?;
+        Ok(validator.into_transactions())
+    }
+}
+
+/// Sum up the given bonds to each validator. Returns a
+/// [`GenesisTxError::AmountOverflow`] if the sum of the bonds to any
+/// validator overflows.
+pub fn aggregate_bond_stakes(
+    bonds: &[BondTx<Validated>],
+) -> Result<BTreeMap<Alias, token::Amount>, GenesisTxError> {
+    let mut stakes: BTreeMap<Alias, token::Amount> = BTreeMap::new();
+    for tx in bonds {
+        let entry = stakes.entry(tx.validator.clone()).or_default();
+        *entry = entry.checked_add(tx.amount.amount).ok_or_else(|| {
+            GenesisTxError::AmountOverflow(format!(
+                "The total amount bonded to validator \"{}\" overflows.",
+                tx.validator
+            ))
+        })?;
     }
+    Ok(stakes)
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
@@ -555,6 +1780,7 @@ pub type SignedValidatorAccountTx = ValidatorAccountTx<SignedPk>;
     PartialEq,
     Eq,
 )]
+#[serde(try_from = "ValidatorAccountTxCompat<PK>")]
 pub struct ValidatorAccountTx<PK> {
     pub alias: Alias,
     pub vp: String,
@@ -570,6 +1796,10 @@ pub struct ValidatorAccountTx<PK> {
     pub discord_handle: Option<String>,
     /// P2P IP:port
     pub net_address: SocketAddr,
+    /// Minimum number of valid `account_key` and `account_keys`
+    /// authorizations. When `None`, all of them must be valid.
+    #[serde(default)]
+    pub threshold: Option<u8>,
     /// PKs have to come last in TOML to avoid `ValueAfterTable` error
     pub account_key: PK,
     pub consensus_key: PK,
@@ -577,80 +1807,358 @@ pub struct ValidatorAccountTx<PK> {
     pub tendermint_node_key: PK,
     pub eth_hot_key: PK,
     pub eth_cold_key: PK,
+    /// Additional account keys, counted together with the `account_key`
+    /// towards the `threshold`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub account_keys: Vec<PK>,
 }
 
-pub type UnsignedEstablishedAccountTx =
-    EstablishedAccountTx<StringEncoded<common::PublicKey>>;
-
-pub type SignedEstablishedAccountTx = EstablishedAccountTx<SignedPk>;
-
-#[derive(
-    Clone,
-    Debug,
-    Deserialize,
-    Serialize,
-    BorshSerialize,
-    BorshDeserialize,
-    PartialEq,
-    Eq,
-)]
-pub struct EstablishedAccountTx<PK> {
-    pub alias: Alias,
-    pub vp: String,
-    /// PKs have to come last in TOML to avoid `ValueAfterTable` error
-    pub public_key: Option<PK>,
+/// The deserialized form of [`ValidatorAccountTx`], which also accepts the
+/// files written before the Ethereum bridge key was split into the hot and
+/// cold keys, in which the hot key was named `eth_bridge_key`.
+#[derive(Deserialize)]
+struct ValidatorAccountTxCompat<PK> {
+    alias: Alias,
+    vp: String,
+    commission_rate: Dec,
+    max_commission_rate_change: Dec,
+    email: String,
+    description: Option<String>,
+    website: Option<String>,
+    discord_handle: Option<String>,
+    net_address: SocketAddr,
+    #[serde(default)]
+    threshold: Option<u8>,
+    account_key: PK,
+    consensus_key: PK,
+    protocol_key: PK,
+    tendermint_node_key: PK,
+    eth_hot_key: Option<PK>,
+    /// The legacy name of the `eth_hot_key`
+    eth_bridge_key: Option<PK>,
+    eth_cold_key: PK,
     #[serde(default)]
-    /// Initial storage key values
-    pub storage: HashMap<storage::Key, HexString>,
+    account_keys: Vec<PK>,
 }
 
-pub type SignedTransferTx = Signed<TransferTx<Unvalidated>>;
-
-impl SignedTransferTx {
-    /// Verify the signature of `TransferTx`. This should not depend
-    /// on whether the contained amount is denominated or not.
-    ///
-    /// Since we denominate amounts as part of validation, we can
-    /// only verify signatures on [`SignedTransferTx`]
-    /// types.
-    pub fn verify_sig(&self) -> Result<(), VerifySigError> {
-        let Self { data, signature } = self;
-        verify_standalone_sig::<_, SerializeWithBorsh>(
-            &data.data_to_sign(),
-            &data.source.raw,
-            signature,
-        )
-    }
-}
+impl<PK> TryFrom<ValidatorAccountTxCompat<PK>> for ValidatorAccountTx<PK> {
+    type Error = String;
 
-#[derive(
-    Clone,
-    Debug,
-    Deserialize,
-    Serialize,
-    BorshSerialize,
-    BorshDeserialize,
-    PartialEq,
-    Eq,
-)]
-pub struct TransferTx<T: TemplateValidation> {
-    pub token: Alias,
-    pub source: StringEncoded<common::PublicKey>,
-    pub target: Alias,
-    pub amount: T::Amount,
-}
+    fn try_from(tx: ValidatorAccountTxCompat<PK>) -> Result<Self, String> {
+        let ValidatorAccountTxCompat {
+            alias,
+            vp,
+            commission_rate,
+            max_commission_rate_change,
+            email,
+            description,
+            website,
+            discord_handle,
+            net_address,
+            threshold,
+            account_key,
+            consensus_key,
+            protocol_key,
+            tendermint_node_key,
+            eth_hot_key,
+            eth_bridge_key,
+            eth_cold_key,
+            account_keys,
+        } = tx;
+        let eth_hot_key = match (eth_hot_key, eth_bridge_key) {
+            (Some(key), None) => key,
+            // Reported by `lint_legacy_fields`
+            (None, Some(key)) => key,
+            (Some(_), Some(_)) => {
+                return Err(format!(
+                    "The validator account \"{alias}\" must not contain both \
+                     the `eth_hot_key` and its legacy name `eth_bridge_key`"
+                ));
+            }
+            (None, None) => {
+                return Err("missing field `eth_hot_key`".to_string());
+            }
+        };
+        Ok(Self {
+            alias,
+            vp,
+            commission_rate,
+            max_commission_rate_change,
+            email,
+            description,
+            website,
+            discord_handle,
+            net_address,
+            threshold,
+            account_key,
+            consensus_key,
+            protocol_key,
+            tendermint_node_key,
+            eth_hot_key,
+            eth_cold_key,
+            account_keys,
+        })
+    }
+}
+
+// Implemented by hand, because `SocketAddr` doesn't implement `BorshSchema`
+impl<PK: BorshSchema> BorshSchema for ValidatorAccountTx<PK> {
+    fn add_definitions_recursively(
+        definitions: &mut BTreeMap<Declaration, Definition>,
+    ) {
+        let fields = Fields::NamedFields(vec![
+            ("alias".into(), Alias::declaration()),
+            ("vp".into(), String::declaration()),
+            ("commission_rate".into(), Dec::declaration()),
+            ("max_commission_rate_change".into(), Dec::declaration()),
+            ("email".into(), String::declaration()),
+            ("description".into(), Option::<String>::declaration()),
+            ("website".into(), Option::<String>::declaration()),
+            ("discord_handle".into(), Option::<String>::declaration()),
+            ("net_address".into(), socket_addr_declaration()),
+            ("threshold".into(), Option::<u8>::declaration()),
+            ("account_key".into(), PK::declaration()),
+            ("consensus_key".into(), PK::declaration()),
+            ("protocol_key".into(), PK::declaration()),
+            ("tendermint_node_key".into(), PK::declaration()),
+            ("eth_hot_key".into(), PK::declaration()),
+            ("eth_cold_key".into(), PK::declaration()),
+            ("account_keys".into(), Vec::<PK>::declaration()),
+        ]);
+        let definition = Definition::Struct { fields };
+        borsh::schema::add_definition(
+            Self::declaration(),
+            definition,
+            definitions,
+        );
+        Alias::add_definitions_recursively(definitions);
+        String::add_definitions_recursively(definitions);
+        Dec::add_definitions_recursively(definitions);
+        Option::<String>::add_definitions_recursively(definitions);
+        add_socket_addr_definitions(definitions);
+        Option::<u8>::add_definitions_recursively(definitions);
+        PK::add_definitions_recursively(definitions);
+        Vec::<PK>::add_definitions_recursively(definitions);
+    }
+
+    fn declaration() -> Declaration {
+        format!("ValidatorAccountTx<{}>", PK::declaration())
+    }
+}
+
+fn socket_addr_declaration() -> Declaration {
+    "SocketAddr".into()
+}
+
+/// Add the schema of a borsh encoded `SocketAddr`, which is an enum of an IPv4
+/// or an IPv6 address' octets followed by the port.
+fn add_socket_addr_definitions(
+    definitions: &mut BTreeMap<Declaration, Definition>,
+) {
+    let v4: Declaration = "SocketAddrV4".into();
+    let v6: Declaration = "SocketAddrV6".into();
+    let definition = Definition::Enum {
+        variants: vec![("V4".into(), v4.clone()), ("V6".into(), v6.clone())],
+    };
+    borsh::schema::add_definition(
+        socket_addr_declaration(),
+        definition,
+        definitions,
+    );
+    let v4_definition = Definition::Tuple {
+        elements: vec![<[u8; 4]>::declaration(), u16::declaration()],
+    };
+    borsh::schema::add_definition(v4, v4_definition, definitions);
+    let v6_definition = Definition::Tuple {
+        elements: vec![<[u8; 16]>::declaration(), u16::declaration()],
+    };
+    borsh::schema::add_definition(v6, v6_definition, definitions);
+    <[u8; 4]>::add_definitions_recursively(definitions);
+    <[u8; 16]>::add_definitions_recursively(definitions);
+}
+
+pub type UnsignedEstablishedAccountTx =
+    EstablishedAccountTx<StringEncoded<common::PublicKey>>;
+
+pub type SignedEstablishedAccountTx = EstablishedAccountTx<SignedPk>;
+
+#[derive(
+    Clone,
+    Debug,
+    Deserialize,
+    Serialize,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshSchema,
+    PartialEq,
+    Eq,
+)]
+pub struct EstablishedAccountTx<PK> {
+    pub alias: Alias,
+    pub vp: String,
+    /// PKs have to come last in TOML to avoid `ValueAfterTable` error
+    pub public_key: Option<PK>,
+    /// Initial storage key values, ordered by the keys to serialize them
+    /// deterministically
+    #[serde(default, with = "storage_initializer")]
+    pub storage: BTreeMap<storage::Key, HexString>,
+}
+
+impl SignedEstablishedAccountTx {
+    /// Replace the public key of the account with `new_pk` and sign the tx
+    /// again with its secret key `new_key`. The new key must differ from the
+    /// old one.
+    pub fn rekey(
+        self,
+        new_pk: common::PublicKey,
+        new_key: &common::SecretKey,
+    ) -> Result<SignedEstablishedAccountTx, GenesisTxError> {
+        if matches!(&self.public_key, Some(old) if old.pk.raw == new_pk) {
+            return Err(SigningError::SameEstablishedAccountKey {
+                alias: self.alias,
+                pk: new_pk,
+            }
+            .into());
+        }
+        if new_key.ref_to() != new_pk {
+            return Err(SigningError::EstablishedAccountKeyMismatch {
+                alias: self.alias,
+                pk: new_pk,
+            }
+            .into());
+        }
+        let mut unsigned_tx = UnsignedEstablishedAccountTx::from(&self);
+        unsigned_tx.public_key = Some(StringEncoded::new(new_pk));
+        sign_established_account_tx_with(unsigned_tx, |_pk| Ok(new_key.clone()))
+    }
+}
+
+/// (De)serialization of an established account's initial storage with the
+/// keys written as strings. A key that appears more than once, including
+/// the keys that are written differently but parse to the same storage key,
+/// is rejected rather than silently overwriting the earlier value.
+mod storage_initializer {
+    use std::collections::BTreeMap;
+    use std::fmt::Formatter;
+
+    use namada::core::types::storage;
+    use serde::de::{Error, MapAccess, Visitor};
+    use serde::{Deserializer, Serialize, Serializer};
+
+    use crate::config::genesis::HexString;
+
+    pub fn serialize<S>(
+        storage: &BTreeMap<storage::Key, HexString>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        storage
+            .iter()
+            .map(|(key, val)| (key.to_string(), val))
+            .collect::<BTreeMap<_, _>>()
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(
+        deserializer: D,
+    ) -> Result<BTreeMap<storage::Key, HexString>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct StorageVisitor;
+
+        impl<'de> Visitor<'de> for StorageVisitor {
+            type Value = BTreeMap<storage::Key, HexString>;
+
+            fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                f.write_str("a map of storage keys to hex-encoded values")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut storage = BTreeMap::new();
+                while let Some((raw_key, val)) =
+                    map.next_entry::<String, HexString>()?
+                {
+                    let key = storage::Key::parse(&raw_key).map_err(|err| {
+                        A::Error::custom(format!(
+                            "Invalid storage key {raw_key}: {err}"
+                        ))
+                    })?;
+                    if storage.contains_key(&key) {
+                        return Err(A::Error::custom(format!(
+                            "Duplicate storage key {key}"
+                        )));
+                    }
+                    storage.insert(key, val);
+                }
+                Ok(storage)
+            }
+        }
+
+        deserializer.deserialize_map(StorageVisitor)
+    }
+}
+
+pub type SignedTransferTx = Signed<TransferTx<Unvalidated>>;
+
+impl SignedTransferTx {
+    /// Verify the signature of `TransferTx`. This should not depend
+    /// on whether the contained amount is denominated or not.
+    ///
+    /// Since we denominate amounts as part of validation, we can
+    /// only verify signatures on [`SignedTransferTx`]
+    /// types.
+    pub fn verify_sig(&self) -> Result<(), VerifySigError> {
+        self.check_version()?;
+        let Self {
+            data, signature, ..
+        } = self;
+        verify_standalone_sig::<_, SerializeWithBorsh>(
+            &data.data_to_sign(self.version),
+            &data.source.raw,
+            signature,
+        )
+    }
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Deserialize,
+    Serialize,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshSchema,
+    PartialEq,
+    Eq,
+)]
+pub struct TransferTx<T: TemplateValidation> {
+    pub token: Alias,
+    pub source: StringEncoded<common::PublicKey>,
+    pub target: Alias,
+    pub amount: T::Amount,
+    /// An optional nonce to tell apart otherwise identical transfers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<u64>,
+}
 
 impl TransferTx<Unvalidated> {
     /// Add the correct denomination to the contained amount
     pub fn denominate(
         self,
         tokens: &Tokens,
-    ) -> eyre::Result<TransferTx<Validated>> {
+    ) -> Result<TransferTx<Validated>, GenesisTxError> {
         let TransferTx {
             token,
             source,
             target,
             amount,
+            nonce,
         } = self;
         let denom =
             if let Some(super::templates::TokenConfig { denom, .. }) =
@@ -663,19 +2171,34 @@ impl TransferTx<Unvalidated> {
                      not in the `tokens.toml` file",
                     token
                 );
-                return Err(eyre::eyre!(
-                    "Genesis files contained transfer of token {}, which is \
-                     not in the `tokens.toml` file",
-                    token
-                ));
+                return Err(GenesisTxError::UnknownToken(token));
             };
+        // Reject amounts that are more precise than the token allows,
+        // e.g. a fractional amount of a token with `0` denomination
+        if amount.denom > denom {
+            eprintln!(
+                "A transfer amount {amount} of token {token} has {} decimal \
+                 places, but the token's denomination only allows {}.",
+                amount.denom.0, denom.0,
+            );
+            return Err(GenesisTxError::ExcessivePrecision {
+                amount,
+                token,
+                decimal_places: amount.denom.0,
+                denom: denom.0,
+            });
+        }
         let amount = amount.increase_precision(denom).map_err(|e| {
             eprintln!(
-                "A bond amount in the transactions.toml file was incorrectly \
-                 formatted:\n{}",
+                "A transfer amount in the transactions.toml file was \
+                 incorrectly formatted:\n{}",
                 e
             );
-            e
+            amount_precision_error(
+                e,
+                AliasOrPk::PublicKey(source.clone()),
+                token.clone(),
+            )
         })?;
 
         Ok(TransferTx {
@@ -683,18 +2206,28 @@ impl TransferTx<Unvalidated> {
             source,
             target,
             amount,
+            nonce,
         })
     }
 
-    /// The signable data. This does not include the phantom data.
-    fn data_to_sign(&self) -> Vec<u8> {
-        [
+    /// The signable data in the given format version. This does not include
+    /// the phantom data. Before [`SIGNED_FORMAT_VERSION`], the nonce is only
+    /// appended when specified.
+    fn data_to_sign(&self, version: u8) -> Vec<u8> {
+        let mut data = [
+            domain_tag(version, TRANSFER_DOMAIN_TAG).to_vec(),
             self.token.serialize_to_vec(),
             self.source.serialize_to_vec(),
             self.target.serialize_to_vec(),
             self.amount.serialize_to_vec(),
         ]
-        .concat()
+        .concat();
+        if version >= SIGNED_FORMAT_VERSION {
+            data.extend(self.nonce.serialize_to_vec());
+        } else if let Some(nonce) = self.nonce {
+            data.extend(nonce.serialize_to_vec());
+        }
+        data
     }
 
     /// Sign the transfer.
@@ -704,17 +2237,44 @@ impl TransferTx<Unvalidated> {
     /// types. Thus we only allow signing of [`TransferTx<Unvalidated>`]
     /// types.
     pub fn sign(self, key: &common::SecretKey) -> SignedTransferTx {
+        self.sign_with_version(key, SIGNED_FORMAT_VERSION)
+    }
+
+    /// Sign the transfer in the given format version
+    fn sign_with_version(
+        self,
+        key: &common::SecretKey,
+        version: u8,
+    ) -> SignedTransferTx {
         let sig = standalone_signature::<_, SerializeWithBorsh>(
             key,
-            &self.data_to_sign(),
+            &self.data_to_sign(version),
         );
         SignedTransferTx {
+            version,
             data: self,
             signature: StringEncoded { raw: sig },
         }
     }
 }
 
+impl<T: TemplateValidation> TransferTx<T> {
+    /// The implicit address derived from the source public key, which holds
+    /// the source's balance after genesis.
+    pub fn source_address(&self) -> Address {
+        Address::from(&self.source.raw)
+    }
+}
+
+impl TransferTx<Validated> {
+    /// The transferred amount with the minimal precision that holds it
+    /// losslessly, as it would be written by a user. This is the inverse of
+    /// [`TransferTx::denominate`].
+    pub fn display_amount(&self) -> DenominatedAmount {
+        self.amount.canonical()
+    }
+}
+
 pub type SignedBondTx = Signed<BondTx<Unvalidated>>;
 
 impl SignedBondTx {
@@ -728,9 +2288,12 @@ impl SignedBondTx {
         &self,
         pk: &common::PublicKey,
     ) -> Result<(), VerifySigError> {
-        let Self { data, signature } = self;
+        self.check_version()?;
+        let Self {
+            data, signature, ..
+        } = self;
         verify_standalone_sig::<_, SerializeWithBorsh>(
-            &data.data_to_sign(),
+            &data.data_to_sign(self.version),
             pk,
             signature,
         )
@@ -744,48 +2307,120 @@ impl SignedBondTx {
     Serialize,
     BorshSerialize,
     BorshDeserialize,
+    BorshSchema,
     PartialEq,
     Eq,
 )]
 pub struct BondTx<T: TemplateValidation> {
     pub source: AliasOrPk,
     pub validator: Alias,
-    pub amount: T::Amount,
+    pub amount: T::BondAmount,
+    /// An optional nonce to tell apart otherwise identical bonds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<u64>,
+}
+
+impl<T: TemplateValidation> BondTx<T> {
+    /// Check if this is a self-bond of the validator. A source alias is a
+    /// self-bond when it's the validator's alias and a source PK is a
+    /// self-bond when it's the account key of the validator, found in the
+    /// given `validator_accounts` that map validator aliases to their account
+    /// keys.
+    pub fn is_self_bond(
+        &self,
+        validator_accounts: &BTreeMap<Alias, common::PublicKey>,
+    ) -> bool {
+        match &self.source {
+            AliasOrPk::Alias(alias) => *alias == self.validator,
+            AliasOrPk::PublicKey(pk) => validator_accounts
+                .get(&self.validator)
+                .map(|account_key| *account_key == pk.raw)
+                .unwrap_or_default(),
+        }
+    }
 }
 
 impl BondTx<Unvalidated> {
-    /// Add the correct denomination to the contained amount
-    pub fn denominate(self) -> eyre::Result<BondTx<Validated>> {
+    /// Add the native token denomination to the contained amount. A
+    /// [`BondAmount::AllAvailable`] amount can only be resolved against the
+    /// source's balance in validation, so it's an error here.
+    pub fn denominate(self) -> Result<BondTx<Validated>, GenesisTxError> {
+        self.denominate_to(None)
+    }
+
+    /// Add the native token denomination to the contained amount. A
+    /// [`BondAmount::AllAvailable`] amount is resolved to the given
+    /// `available` balance of the source.
+    fn denominate_to(
+        self,
+        available: Option<DenominatedAmount>,
+    ) -> Result<BondTx<Validated>, GenesisTxError> {
         let BondTx {
             source,
             validator,
             amount,
+            nonce,
         } = self;
-        let amount = amount
-            .increase_precision(NATIVE_MAX_DECIMAL_PLACES.into())
-            .map_err(|e| {
-                eprintln!(
-                    "A bond amount in the transactions.toml file was \
-                     incorrectly formatted:\n{}",
-                    e
-                );
-                e
-            })?;
+        let amount = match amount {
+            BondAmount::Fixed(amount) => amount
+                .increase_precision(NATIVE_MAX_DECIMAL_PLACES.into())
+                .map_err(|e| {
+                    eprintln!(
+                        "A bond amount in the transactions.toml file was \
+                         incorrectly formatted:\n{}",
+                        e
+                    );
+                    amount_precision_error(
+                        e,
+                        source.clone(),
+                        Alias::from(DEFAULT_NATIVE_TOKEN_ALIAS),
+                    )
+                })?,
+            BondAmount::AllAvailable => match available {
+                Some(available) => available,
+                None => {
+                    eprintln!(
+                        "A bond of all the available balance from {source} \
+                         has no balance to bond."
+                    );
+                    return Err(GenesisTxError::NoAvailableBalance(source));
+                }
+            },
+        };
         Ok(BondTx {
             source,
             validator,
             amount,
+            nonce,
         })
     }
 
-    /// The signable data. This does not include the phantom data.
-    fn data_to_sign(&self) -> Vec<u8> {
-        [
+    /// The signable data in the given format version. This does not include
+    /// the phantom data. Since [`SIGNED_FORMAT_VERSION`], the amount and the
+    /// optional nonce are encoded with their borsh tags. Before it, the nonce
+    /// is only appended when specified.
+    fn data_to_sign(&self, version: u8) -> Vec<u8> {
+        if version >= SIGNED_FORMAT_VERSION {
+            return [
+                BOND_DOMAIN_TAG.to_vec(),
+                self.source.serialize_to_vec(),
+                self.validator.serialize_to_vec(),
+                self.amount.serialize_to_vec(),
+                self.nonce.serialize_to_vec(),
+            ]
+            .concat();
+        }
+        let mut data = [
+            domain_tag(version, BOND_DOMAIN_TAG).to_vec(),
             self.source.serialize_to_vec(),
             self.validator.serialize_to_vec(),
-            self.amount.serialize_to_vec(),
+            self.amount.data_to_sign(),
         ]
-        .concat()
+        .concat();
+        if let Some(nonce) = self.nonce {
+            data.extend(nonce.serialize_to_vec());
+        }
+        data
     }
 
     /// Sign the transfer.
@@ -795,18 +2430,134 @@ impl BondTx<Unvalidated> {
     /// types. Thus we only allow signing of [`BondTx<Unvalidated>`]
     /// types.
     pub fn sign(self, key: &common::SecretKey) -> SignedBondTx {
+        self.sign_with_version(key, SIGNED_FORMAT_VERSION)
+    }
+
+    /// Sign the bond in the given format version
+    fn sign_with_version(
+        self,
+        key: &common::SecretKey,
+        version: u8,
+    ) -> SignedBondTx {
         let sig = standalone_signature::<_, SerializeWithBorsh>(
             key,
-            &self.data_to_sign(),
+            &self.data_to_sign(version),
         );
         SignedBondTx {
+            version,
             data: self,
             signature: StringEncoded { raw: sig },
         }
     }
 }
 
-#[derive(Clone, Debug, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+impl BondTx<Validated> {
+    /// The bonded amount with the minimal precision that holds it
+    /// losslessly, as it would be written by a user. This is the inverse of
+    /// [`BondTx::denominate`].
+    pub fn display_amount(&self) -> DenominatedAmount {
+        self.amount.canonical()
+    }
+
+    /// Convert into a runtime bond tx with the given resolved addresses of
+    /// the source and the validator, as the bond is applied at genesis.
+    pub fn to_core_bond(
+        &self,
+        source_addr: Address,
+        validator_addr: Address,
+    ) -> pos::Bond {
+        pos::Bond {
+            validator: validator_addr,
+            amount: self.amount.amount,
+            source: Some(source_addr),
+        }
+    }
+}
+
+/// The amount of a bond that is either fixed or all the balance that's
+/// available to the source at the point the bond is validated, after the
+/// preceding bonds and transfers have been applied.
+#[derive(
+    Clone, Debug, BorshSerialize, BorshDeserialize, BorshSchema, PartialEq, Eq,
+)]
+pub enum BondAmount {
+    /// `amount = "value"` in toml
+    Fixed(DenominatedAmount),
+    /// `amount = "all"` in toml
+    AllAvailable,
+}
+
+impl BondAmount {
+    /// The keyword of [`BondAmount::AllAvailable`]
+    pub const ALL_AVAILABLE: &'static str = "all";
+
+    /// The signable data before [`SIGNED_FORMAT_VERSION`]. A fixed amount is
+    /// signed the same as a plain amount, so that the signatures of existing
+    /// bonds are not affected.
+    fn data_to_sign(&self) -> Vec<u8> {
+        match self {
+            BondAmount::Fixed(amount) => amount.serialize_to_vec(),
+            BondAmount::AllAvailable => self.serialize_to_vec(),
+        }
+    }
+}
+
+impl From<DenominatedAmount> for BondAmount {
+    fn from(amount: DenominatedAmount) -> Self {
+        BondAmount::Fixed(amount)
+    }
+}
+
+impl FromStr for BondAmount {
+    type Err = token::AmountParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == Self::ALL_AVAILABLE {
+            Ok(BondAmount::AllAvailable)
+        } else {
+            DenominatedAmount::from_str(s).map(BondAmount::Fixed)
+        }
+    }
+}
+
+impl Display for BondAmount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BondAmount::Fixed(amount) => write!(f, "{}", amount),
+            BondAmount::AllAvailable => f.write_str(Self::ALL_AVAILABLE),
+        }
+    }
+}
+
+impl Serialize for BondAmount {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            BondAmount::Fixed(amount) => {
+                Serialize::serialize(amount, serializer)
+            }
+            BondAmount::AllAvailable => {
+                Serialize::serialize(Self::ALL_AVAILABLE, serializer)
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for BondAmount {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let amount: String = Deserialize::deserialize(deserializer)?;
+        BondAmount::from_str(&amount).map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(
+    Clone, Debug, BorshSerialize, BorshDeserialize, BorshSchema, PartialEq, Eq,
+)]
 pub enum AliasOrPk {
     /// `alias = "value"` in toml (encoded via `AliasSerHelper`)
     Alias(Alias),
@@ -846,18 +2597,7 @@ impl<'de> Deserialize<'de> for AliasOrPk {
             where
                 E: serde::de::Error,
             {
-                // Try to deserialize a PK first
-                let maybe_pk =
-                    StringEncoded::<common::PublicKey>::from_str(value);
-                match maybe_pk {
-                    Ok(pk) => Ok(AliasOrPk::PublicKey(pk)),
-                    Err(_) => {
-                        // If that doesn't work, use it as an alias
-                        let alias = Alias::from_str(value)
-                            .map_err(serde::de::Error::custom)?;
-                        Ok(AliasOrPk::Alias(alias))
-                    }
-                }
+                AliasOrPk::from_str(value).map_err(serde::de::Error::custom)
             }
         }
 
@@ -865,31 +2605,127 @@ impl<'de> Deserialize<'de> for AliasOrPk {
     }
 }
 
-impl Display for AliasOrPk {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            AliasOrPk::Alias(alias) => write!(f, "{}", alias),
-            AliasOrPk::PublicKey(pk) => write!(f, "{}", pk),
-        }
+impl AliasOrPk {
+    /// Get the TOML value of this alias or PK, as it's written by the
+    /// serializer. Deserializing the value gives back an equal [`AliasOrPk`],
+    /// except for an alias that's also a valid encoding of a PK, which is
+    /// always read as the PK, the same as in [`AliasOrPk::from_str`].
+    pub fn to_toml_value(&self) -> toml::Value {
+        toml::Value::String(self.to_string())
     }
 }
 
-#[derive(
-    Clone,
-    Debug,
-    Deserialize,
+/// Errors from parsing an [`AliasOrPk`]
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum ParseAliasOrPkError {
+    #[error("An alias or a public key must not be empty.")]
+    Empty,
+}
+
+impl FromStr for AliasOrPk {
+    type Err = ParseAliasOrPkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseAliasOrPkError::Empty);
+        }
+        // Try to parse a PK first
+        match StringEncoded::<common::PublicKey>::from_str(s) {
+            Ok(pk) => Ok(AliasOrPk::PublicKey(pk)),
+            // If that doesn't work, use it as an alias
+            Err(_) => Ok(AliasOrPk::Alias(Alias::from(s))),
+        }
+    }
+}
+
+impl Display for AliasOrPk {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AliasOrPk::Alias(alias) => write!(f, "{}", alias),
+            AliasOrPk::PublicKey(pk) => write!(f, "{}", pk),
+        }
+    }
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Deserialize,
     Serialize,
     BorshSerialize,
     BorshDeserialize,
+    BorshSchema,
     PartialEq,
     Eq,
 )]
 pub struct Signed<T> {
+    /// The version of the signed data format. Files written before the
+    /// version was introduced don't contain it and are read as
+    /// [`SIGNED_FORMAT_VERSION_LEGACY`].
+    #[serde(default = "legacy_signed_format_version")]
+    pub version: u8,
     #[serde(flatten)]
     pub data: T,
     pub signature: StringEncoded<common::Signature>,
 }
 
+/// The version of the [`Signed`] data format that is produced by signing.
+/// On top of [`SIGNED_FORMAT_VERSION_TAGGED`], the optional fields of the
+/// signable data are encoded with their presence, so that the data of
+/// different txs can't have the same bytes.
+pub const SIGNED_FORMAT_VERSION: u8 = 3;
+
+/// The version of the [`Signed`] data format in which the signable data is
+/// prefixed with a domain separation tag of the kind of the tx, so that a
+/// signature of one kind of tx can't be reused for another.
+pub const SIGNED_FORMAT_VERSION_TAGGED: u8 = 2;
+
+/// The version of the [`Signed`] data format without a domain separation tag.
+/// Txs in this version can still be verified.
+pub const SIGNED_FORMAT_VERSION_UNTAGGED: u8 = 1;
+
+/// The version of [`Signed`] data that was written without a version. The
+/// signable layout is the same as in [`SIGNED_FORMAT_VERSION_UNTAGGED`].
+pub const SIGNED_FORMAT_VERSION_LEGACY: u8 = 0;
+
+/// The domain separation tag of the signable data of transfers
+const TRANSFER_DOMAIN_TAG: &[u8] = b"namada-genesis-transfer";
+
+/// The domain separation tag of the signable data of bonds
+const BOND_DOMAIN_TAG: &[u8] = b"namada-genesis-bond";
+
+/// The domain separation tag to prefix the signable data with in the given
+/// format version. The versions before [`SIGNED_FORMAT_VERSION_TAGGED`] are
+/// untagged.
+fn domain_tag(version: u8, tag: &'static [u8]) -> &'static [u8] {
+    if version < SIGNED_FORMAT_VERSION_TAGGED {
+        &[]
+    } else {
+        tag
+    }
+}
+
+fn legacy_signed_format_version() -> u8 {
+    SIGNED_FORMAT_VERSION_LEGACY
+}
+
+impl<T> Signed<T> {
+    /// Check that the signed data has a format version that we know how to
+    /// verify.
+    fn check_version(&self) -> Result<(), VerifySigError> {
+        match self.version {
+            SIGNED_FORMAT_VERSION_LEGACY
+            | SIGNED_FORMAT_VERSION_UNTAGGED
+            | SIGNED_FORMAT_VERSION_TAGGED
+            | SIGNED_FORMAT_VERSION => Ok(()),
+            version => Err(VerifySigError::SigVerifyError(format!(
+                "Unsupported signed data format version {version}, the \
+                 latest supported version is {SIGNED_FORMAT_VERSION}"
+            ))),
+        }
+    }
+}
+
 #[derive(
     Clone,
     Debug,
@@ -897,6 +2733,7 @@ pub struct Signed<T> {
     Serialize,
     BorshSerialize,
     BorshDeserialize,
+    BorshSchema,
     PartialEq,
     Eq,
 )]
@@ -905,144 +2742,528 @@ pub struct SignedPk {
     pub authorization: StringEncoded<common::Signature>,
 }
 
+/// Errors from validation of genesis transactions
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    #[error("Unable to validate bonds without a valid parameters file.")]
+    MissingParametersForBonds,
+    #[error("Some genesis transactions are invalid.")]
+    InvalidTxs,
+    #[error(
+        "Unable to validate transfers and bonds without a valid balances file."
+    )]
+    BalancesRequired,
+    #[error(
+        "An amount of token {token} from {alias} overflows when increasing \
+         its precision to the token's denomination."
+    )]
+    AmountPrecisionOverflow { alias: AliasOrPk, token: Alias },
+    #[error(
+        "There are {count} genesis validator accounts, exceeding the maximum \
+         of {max}."
+    )]
+    TooManyValidators { count: usize, max: usize },
+    #[error(
+        "The alias \"{0}\" is used by both an established account and a \
+         validator account."
+    )]
+    EstablishedAndValidatorAlias(Alias),
+    #[error(
+        "The source \"{alias}\" of a bond tx has no established or validator \
+         account."
+    )]
+    UnknownBondSource { alias: Alias },
+    #[error(
+        "A transfer tx moves token \"{token}\", which is not in the \
+         `tokens.toml` file."
+    )]
+    UnknownToken { token: Alias },
+    #[error("The `established_account` tx with alias \"{alias}\" is invalid.")]
+    InvalidEstablishedAccount { alias: Alias },
+    #[error("The `validator_account` tx with alias \"{alias}\" is invalid.")]
+    InvalidValidatorAccount { alias: Alias },
+    #[error(
+        "A transfer tx of token \"{token}\" from {source} to \"{target}\" is \
+         invalid."
+    )]
+    InvalidTransfer {
+        token: Alias,
+        source: StringEncoded<common::PublicKey>,
+        target: Alias,
+    },
+    #[error(
+        "The `validator_account` tx of \"{alias}\" has a net address \
+         {net_address} that is not reachable on a public network."
+    )]
+    UnreachableNetAddress {
+        alias: Alias,
+        net_address: SocketAddr,
+    },
+}
+
+/// The signature schemes that are allowed in genesis txs by default
+pub const ALL_SIGNATURE_SCHEMES: &[SchemeType] =
+    &[SchemeType::Ed25519, SchemeType::Secp256k1];
+
+/// The optional requirements and limits of the validation of genesis txs. The
+/// default allows the [`ALL_SIGNATURE_SCHEMES`] and has no limits.
+#[derive(Clone, Debug)]
+pub struct ValidationOptions<'a> {
+    /// When given, the balances of the PK sources of transfers and bonds must
+    /// be proven by them
+    pub balance_proofs: Option<&'a BalanceProofs>,
+    /// The txs must be signed with one of these schemes
+    pub allowed_schemes: &'a [SchemeType],
+    /// When given, there must be at most as many validator account txs
+    pub max_validators: Option<usize>,
+    /// When given, no single transfer may exceed it. The limit is compared
+    /// with the transfers' denominated amounts, so it applies to the whole
+    /// units of any token, whatever its denomination.
+    pub max_transfer: Option<DenominatedAmount>,
+    /// Accept transfers and bonds signed in a format version before
+    /// [`SIGNED_FORMAT_VERSION`]. These are rejected unless opted into.
+    pub allow_legacy_signatures: bool,
+}
+
+impl Default for ValidationOptions<'_> {
+    fn default() -> Self {
+        Self {
+            balance_proofs: None,
+            allowed_schemes: ALL_SIGNATURE_SCHEMES,
+            max_validators: None,
+            max_transfer: None,
+            allow_legacy_signatures: false,
+        }
+    }
+}
+
+impl ValidationOptions<'_> {
+    /// The default options that also accept the signed formats before
+    /// [`SIGNED_FORMAT_VERSION`], e.g. for the `genesis/localnet` templates
+    /// that were signed before the format was versioned.
+    pub fn with_legacy_signatures() -> Self {
+        Self {
+            allow_legacy_signatures: true,
+            ..Default::default()
+        }
+    }
+}
+
+/// Validate the genesis transactions, collecting the validation errors into a
+/// [`GenesisTxError::Validation`]. The details of errors reported as
+/// [`ValidationError::InvalidTxs`] are printed to stderr. The txs are also
+/// checked against the given `options`.
 pub fn validate(
     transactions: Transactions<Unvalidated>,
     vps: Option<&ValidityPredicates>,
     balances: Option<&DenominatedBalances>,
     tokens: &Tokens,
     parameters: Option<&Parameters<Validated>>,
-) -> Option<Transactions<Validated>> {
-    let mut is_valid = true;
+    options: &ValidationOptions<'_>,
+) -> Result<Transactions<Validated>, GenesisTxError> {
+    let mut validator =
+        GenesisValidator::new(parameters, tokens, vps, balances)
+            .with_options(options.clone());
+    validator.add(transactions)?;
+    Ok(validator.into_transactions())
+}
 
-    let mut all_used_aliases: BTreeSet<Alias> = BTreeSet::default();
-    let mut established_accounts: BTreeMap<Alias, Option<common::PublicKey>> =
-        BTreeMap::default();
-    let mut validator_accounts: BTreeMap<Alias, common::PublicKey> =
-        BTreeMap::default();
+/// A validator of genesis txs that arrive incrementally, e.g. from the
+/// participants of a genesis ceremony. Every added set of txs is validated
+/// against the state accumulated from the previously added ones, so the
+/// whole merged set doesn't have to be validated again on every addition.
+/// Validating all the txs at once with [`validate`] is the same as adding
+/// them to a new validator at once.
+pub struct GenesisValidator<'a> {
+    parameters: Option<&'a Parameters<Validated>>,
+    tokens: &'a Tokens,
+    vps: Option<&'a ValidityPredicates>,
+    balances: Option<&'a DenominatedBalances>,
+    options: ValidationOptions<'a>,
+    state: IncrementalValidationState,
+    /// The valid txs added so far. These are kept out of the `state` so that
+    /// they don't have to be copied on every addition.
+    txs: Transactions<Validated>,
+}
 
-    let Transactions {
-        ref established_account,
-        ref validator_account,
-        ref transfer,
-        bond,
-    } = transactions;
+/// The state accumulated from the txs added to a [`GenesisValidator`]
+#[derive(Clone, Default)]
+struct IncrementalValidationState {
+    all_used_aliases: BTreeSet<Alias>,
+    established_accounts: BTreeMap<Alias, Option<common::PublicKey>>,
+    validator_accounts: BTreeMap<Alias, common::PublicKey>,
+    token_balances: BTreeMap<Alias, TokenBalancesForValidation>,
+}
+
+/// The transfers and bonds added to a [`GenesisValidator`]
+enum TransfersAndBonds {
+    /// Signed txs, whose signatures are verified
+    Signed {
+        transfer: Option<Vec<SignedTransferTx>>,
+        bond: Option<Vec<SignedBondTx>>,
+    },
+    /// Txs that have been validated before. These don't keep their
+    /// signatures, so only their data is validated again.
+    Validated {
+        transfer: Option<Vec<TransferTx<Validated>>>,
+        bond: Option<Vec<BondTx<Validated>>>,
+    },
+}
+
+/// A bond added to a [`GenesisValidator`], see [`TransfersAndBonds`]
+enum AddedBond {
+    Signed(SignedBondTx),
+    Validated(BondTx<Unvalidated>),
+}
+
+impl AddedBond {
+    /// The data of the bond tx
+    fn data(&self) -> &BondTx<Unvalidated> {
+        match self {
+            Self::Signed(tx) => &tx.data,
+            Self::Validated(tx) => tx,
+        }
+    }
+}
+
+impl<'a> GenesisValidator<'a> {
+    /// Start with no txs, the given `balances`, if any, and the default
+    /// [`ValidationOptions`]
+    pub fn new(
+        parameters: Option<&'a Parameters<Validated>>,
+        tokens: &'a Tokens,
+        vps: Option<&'a ValidityPredicates>,
+        balances: Option<&'a DenominatedBalances>,
+    ) -> Self {
+        Self {
+            parameters,
+            tokens,
+            vps,
+            balances,
+            options: ValidationOptions::default(),
+            state: IncrementalValidationState {
+                token_balances: token_balances_for_validation(balances),
+                ..Default::default()
+            },
+            txs: Transactions::default(),
+        }
+    }
+
+    /// Check the added txs against the given `options`
+    pub fn with_options(mut self, options: ValidationOptions<'a>) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Validate the given txs against the previously added ones. The details
+    /// of errors reported as [`ValidationError::InvalidTxs`] are printed to
+    /// stderr. Only valid txs are added, otherwise the state is left
+    /// unchanged.
+    pub fn add(
+        &mut self,
+        txs: Transactions<Unvalidated>,
+    ) -> Result<(), GenesisTxError> {
+        let mut is_valid =
+            validate_signature_schemes(&txs, self.options.allowed_schemes);
+        if !self.options.allow_legacy_signatures
+            && !validate_signed_format_versions(&txs)
+        {
+            is_valid = false;
+        }
+        let Transactions {
+            established_account,
+            validator_account,
+            transfer,
+            bond,
+        } = txs;
+        self.add_aux(
+            established_account,
+            validator_account,
+            TransfersAndBonds::Signed { transfer, bond },
+            is_valid,
+        )
+        .map_err(GenesisTxError::Validation)
+    }
+
+    /// Validate the given txs against the previously added ones, as in
+    /// [`GenesisValidator::add`]. The signatures of the transfers and bonds
+    /// are only verified if they're signed. The `is_valid` flag carries the
+    /// result of any checks done by the caller.
+    fn add_aux(
+        &mut self,
+        established_account: Option<Vec<SignedEstablishedAccountTx>>,
+        validator_account: Option<Vec<SignedValidatorAccountTx>>,
+        transfers_and_bonds: TransfersAndBonds,
+        mut is_valid: bool,
+    ) -> Result<(), Vec<ValidationError>> {
+        let ValidationOptions {
+            balance_proofs,
+            max_validators,
+            max_transfer,
+            ..
+        } = self.options.clone();
+        let mut errors: Vec<ValidationError> = vec![];
+        // Only the valid txs are folded into the state, so they're applied to
+        // a copy of it first
+        let mut state = self.state.clone();
 
-    if let Some(txs) = established_account {
-        for tx in txs {
-            if !validate_established_account(
+        if let Some(max) = max_validators {
+            let count = [&self.txs.validator_account, &validator_account]
+                .into_iter()
+                .flatten()
+                .map(Vec::len)
+                .sum::<usize>();
+            if count > max {
+                errors.push(ValidationError::TooManyValidators { count, max });
+            }
+        }
+
+        // Verify the signatures that don't depend on other txs up-front
+        let (sig_errors, transfer, bond) = match transfers_and_bonds {
+            TransfersAndBonds::Signed { transfer, bond } => (
+                verify_signatures(
+                    validator_account.as_deref().unwrap_or_default(),
+                    transfer.as_deref().unwrap_or_default(),
+                ),
+                transfer.map(|txs| txs.iter().map(Into::into).collect()),
+                bond.map(|txs| {
+                    txs.into_iter().map(AddedBond::Signed).collect()
+                }),
+            ),
+            TransfersAndBonds::Validated { transfer, bond } => (
+                verify_signatures(
+                    validator_account.as_deref().unwrap_or_default(),
+                    &[],
+                ),
+                transfer.map(|txs| txs.into_iter().map(Into::into).collect()),
+                bond.map(|txs| {
+                    txs.into_iter()
+                        .map(|tx| AddedBond::Validated(tx.into()))
+                        .collect()
+                }),
+            ),
+        };
+
+        for tx in established_account.iter().flatten() {
+            if validate_established_account(
                 tx,
-                vps,
-                &mut all_used_aliases,
-                &mut established_accounts,
-            ) {
+                self.vps,
+                &mut state.all_used_aliases,
+                &mut state.established_accounts,
+            )
+            .is_err()
+            {
                 is_valid = false;
             }
         }
-    }
 
-    if let Some(txs) = validator_account {
-        for tx in txs {
-            if !validate_validator_account(
+        for (ix, tx) in validator_account.iter().flatten().enumerate() {
+            if !validate_validator_account_data(
                 tx,
-                vps,
-                &mut all_used_aliases,
-                &mut validator_accounts,
+                self.vps,
+                &mut state.all_used_aliases,
+                &mut state.validator_accounts,
             ) {
                 is_valid = false;
             }
+            if !report_sig_errors(sig_errors.validator_account(ix)) {
+                is_valid = false;
+            }
         }
-    }
 
-    // Make a mutable copy of the balances for tracking changes applied from txs
-    let mut token_balances: BTreeMap<Alias, TokenBalancesForValidation> =
-        balances
-            .map(|balances| {
-                balances
-                    .token
-                    .iter()
-                    .map(|(token, token_balances)| {
-                        (
-                            token.clone(),
-                            TokenBalancesForValidation {
-                                // Add an accumulator for tokens transferred to
-                                // aliases
-                                aliases: BTreeMap::new(),
-                                pks: token_balances.clone(),
-                            },
-                        )
-                    })
-                    .collect()
-            })
-            .unwrap_or_default();
+        // Report the aliases shared across the established and validator
+        // accounts with their own error, as they're easily mistaken for
+        // duplicates within either of the two
+        for alias in state.validator_accounts.keys() {
+            if state.established_accounts.contains_key(alias) {
+                errors.push(ValidationError::EstablishedAndValidatorAlias(
+                    alias.clone(),
+                ));
+            }
+        }
 
-    let validated_txs = if let Some(txs) = transfer {
-        let validated_txs: Vec<_> = txs
-            .iter()
-            .filter_map(|tx| {
-                validate_transfer(
-                    tx,
-                    &mut token_balances,
-                    &all_used_aliases,
-                    tokens,
-                )
-            })
-            .collect();
-        if validated_txs.len() != txs.len() {
-            is_valid = false;
-            None
-        } else {
-            Some(validated_txs)
+        // Without balances, every transfer and bond would fail on a missing
+        // balance, so they're not validated and only a single error is
+        // reported. Bonds without parameters are reported with their own
+        // error.
+        let has_transfers = matches!(&transfer, Some(txs) if !txs.is_empty());
+        let has_bonds = matches!(&bond, Some(txs) if !txs.is_empty());
+        let balances_required = self.balances.is_none()
+            && (has_transfers || (self.parameters.is_some() && has_bonds));
+        if balances_required {
+            errors.push(ValidationError::BalancesRequired);
         }
-    } else {
-        None
-    };
 
-    let validated_bonds = if let Some(txs) = bond {
-        if !txs.is_empty() {
-            match parameters {
-                Some(parameters) => {
-                    let bond_number = txs.len();
-                    let validated_bonds: Vec<_> = txs
-                        .into_iter()
-                        .filter_map(|tx| {
-                            validate_bond(
-                                tx,
-                                &mut token_balances,
-                                &established_accounts,
-                                &validator_accounts,
-                                parameters,
-                            )
-                        })
-                        .collect();
-                    if validated_bonds.len() != bond_number {
+        let validated_transfers = match transfer {
+            Some(txs) if !balances_required => {
+                let mut validated_txs = vec![];
+                for (ix, tx) in txs.into_iter().enumerate() {
+                    let is_sig_valid =
+                        report_sig_errors(sig_errors.transfer(ix));
+                    if let Some(balance_proofs) = balance_proofs {
+                        if !validate_transfer_balance_proof(
+                            &tx,
+                            self.balances,
+                            balance_proofs,
+                        ) {
+                            is_valid = false;
+                            continue;
+                        }
+                    }
+                    if !validate_transfer_token(
+                        &tx.token,
+                        self.tokens,
+                        &mut errors,
+                    ) {
                         is_valid = false;
-                        None
-                    } else {
-                        Some(validated_bonds)
+                        continue;
+                    }
+                    match validate_transfer_data(
+                        tx,
+                        &mut state.token_balances,
+                        &state.all_used_aliases,
+                        self.tokens,
+                    )
+                    .filter(|tx| validate_transfer_max(tx, max_transfer))
+                    {
+                        Some(tx) if is_sig_valid => validated_txs.push(tx),
+                        _ => is_valid = false,
                     }
                 }
-                None => {
-                    eprintln!(
-                        "Unable to validate bonds without a valid parameters \
-                         file."
-                    );
-                    is_valid = false;
-                    None
+                Some(validated_txs)
+            }
+            _ => None,
+        };
+
+        let mut validated_bonds = vec![];
+        match self.parameters {
+            Some(_) if balances_required => {}
+            Some(parameters) => {
+                for tx in bond.into_iter().flatten() {
+                    // Report an undeclared source with its own error instead
+                    // of an opaque signature failure
+                    if let Some(alias) = find_unknown_bond_source(
+                        tx.data(),
+                        &state.established_accounts,
+                        &state.validator_accounts,
+                    ) {
+                        errors
+                            .push(ValidationError::UnknownBondSource { alias });
+                        is_valid = false;
+                        continue;
+                    }
+                    if let Some(balance_proofs) = balance_proofs {
+                        if !validate_bond_balance_proof(
+                            tx.data(),
+                            self.balances,
+                            balance_proofs,
+                            &parameters.parameters.native_token,
+                        ) {
+                            is_valid = false;
+                            continue;
+                        }
+                    }
+                    let validated_bond = match tx {
+                        AddedBond::Signed(tx) => validate_bond(
+                            tx,
+                            &mut state.token_balances,
+                            &state.established_accounts,
+                            &state.validator_accounts,
+                            parameters,
+                        ),
+                        AddedBond::Validated(tx) => validate_bond_data(
+                            tx,
+                            &mut state.token_balances,
+                            &state.validator_accounts,
+                            parameters,
+                        ),
+                    };
+                    match validated_bond {
+                        Some(tx) => validated_bonds.push(tx),
+                        None => is_valid = false,
+                    }
                 }
             }
-        } else {
-            None
+            None if has_bonds => {
+                errors.push(ValidationError::MissingParametersForBonds);
+            }
+            None => {}
         }
-    } else {
-        None
-    };
 
-    is_valid.then_some(Transactions {
-        established_account: transactions.established_account,
-        validator_account: transactions.validator_account,
-        transfer: validated_txs,
-        bond: validated_bonds,
-    })
+        if !is_valid {
+            errors.push(ValidationError::InvalidTxs);
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        self.txs.merge(Transactions {
+            established_account,
+            validator_account,
+            transfer: validated_transfers,
+            bond: has_bonds.then_some(validated_bonds),
+        });
+        self.state = state;
+        Ok(())
+    }
+
+    /// The valid txs added so far
+    pub fn transactions(&self) -> &Transactions<Validated> {
+        &self.txs
+    }
+
+    /// Take the valid txs added so far
+    pub fn into_transactions(self) -> Transactions<Validated> {
+        self.txs
+    }
+}
+
+/// Make a copy of the balances for tracking changes applied from txs
+fn token_balances_for_validation(
+    balances: Option<&DenominatedBalances>,
+) -> BTreeMap<Alias, TokenBalancesForValidation> {
+    balances
+        .map(|balances| {
+            balances
+                .token
+                .iter()
+                .map(|(token, token_balances)| {
+                    (
+                        token.clone(),
+                        TokenBalancesForValidation {
+                            token: token.clone(),
+                            // Add an accumulator for tokens transferred to
+                            // aliases, starting from the balances
+                            // accumulated by a previous validation, if any
+                            aliases: balances
+                                .aliases
+                                .get(token)
+                                .cloned()
+                                .unwrap_or_default(),
+                            pks: token_balances.clone(),
+                        },
+                    )
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Find the source alias of a bond tx, if it has neither an established nor a
+/// validator account
+fn find_unknown_bond_source(
+    tx: &BondTx<Unvalidated>,
+    established_accounts: &BTreeMap<Alias, Option<common::PublicKey>>,
+    validator_accounts: &BTreeMap<Alias, common::PublicKey>,
+) -> Option<Alias> {
+    match &tx.source {
+        AliasOrPk::Alias(alias)
+            if !established_accounts.contains_key(alias)
+                && !validator_accounts.contains_key(alias) =>
+        {
+            Some(alias.clone())
+        }
+        _ => None,
+    }
 }
 
 fn validate_bond(
@@ -1053,9 +3274,15 @@ fn validate_bond(
     parameters: &Parameters<Validated>,
 ) -> Option<BondTx<Validated>> {
     // Check signature
-    let mut is_valid = {
+    let is_sig_valid = {
         let source = &tx.data.source;
+        let is_self_bond = tx.data.is_self_bond(validator_accounts);
         if let Some(source_pk) = match source {
+            // A self-bond must be signed with the validator's account key,
+            // the same as in `sign_self_bond_tx`, so no other key is accepted
+            AliasOrPk::Alias(alias) if is_self_bond => {
+                validator_accounts.get(alias).cloned()
+            }
             AliasOrPk::Alias(alias) => {
                 // Try to find the source's PK in either established_accounts or
                 // validator_accounts
@@ -1068,7 +3295,16 @@ fn validate_bond(
             AliasOrPk::PublicKey(pk) => Some(pk.raw.clone()),
         } {
             if tx.verify_sig(&source_pk).is_err() {
-                eprintln!("Invalid bond tx signature.",);
+                if is_self_bond {
+                    eprintln!(
+                        "Invalid self-bond tx signature. A self-bond to the \
+                         validator \"{}\" must be signed with the \
+                         validator's account key.",
+                        tx.data.validator
+                    );
+                } else {
+                    eprintln!("Invalid bond tx signature.",);
+                }
                 false
             } else {
                 true
@@ -1082,8 +3318,31 @@ fn validate_bond(
         }
     };
 
-    // Make sure the native token amount is denominated correctly
-    let validated_bond = tx.data.denominate().ok()?;
+    validate_bond_data(tx.data, balances, validator_accounts, parameters)
+        .filter(|_| is_sig_valid)
+}
+
+/// Validate a bond tx, except for its signature, and update the token balances
+/// if it's valid
+fn validate_bond_data(
+    tx: BondTx<Unvalidated>,
+    balances: &mut BTreeMap<Alias, TokenBalancesForValidation>,
+    validator_accounts: &BTreeMap<Alias, common::PublicKey>,
+    parameters: &Parameters<Validated>,
+) -> Option<BondTx<Validated>> {
+    let mut is_valid = true;
+    let native_token = &parameters.parameters.native_token;
+
+    // Make sure the native token amount is denominated correctly, resolving
+    // a bond of all the available balance to the source's remaining balance
+    let available = balances.get(native_token).and_then(|balances| {
+        let balance = match &tx.source {
+            AliasOrPk::Alias(source) => balances.aliases.get(source),
+            AliasOrPk::PublicKey(source) => balances.pks.0.get(source),
+        };
+        balance.cloned()
+    });
+    let validated_bond = tx.denominate_to(available).ok()?;
     let BondTx {
         source,
         validator,
@@ -1091,7 +3350,10 @@ fn validate_bond(
         ..
     } = &validated_bond;
 
-    // Check that the validator exists
+    // Check that the validator is valid and exists
+    if !validate_alias(validator, "bond") {
+        is_valid = false;
+    }
     if !validator_accounts.contains_key(validator) {
         eprintln!(
             "Invalid bond tx. The target validator \"{validator}\" account \
@@ -1100,8 +3362,23 @@ fn validate_bond(
         is_valid = false;
     }
 
+    // Check that the bond is not below the minimum
+    if let Some(min_bond) = parameters.pos_params.min_bond {
+        if amount.amount < min_bond {
+            eprintln!(
+                "Invalid bond tx. The bond of {amount} from {source} to the \
+                 validator \"{validator}\" is below the minimum bond amount \
+                 {}.",
+                DenominatedAmount {
+                    amount: min_bond,
+                    denom: NATIVE_MAX_DECIMAL_PLACES.into(),
+                },
+            );
+            is_valid = false;
+        }
+    }
+
     // Check and update token balance of the source
-    let native_token = &parameters.parameters.native_token;
     match balances.get_mut(native_token) {
         Some(balances) => {
             let balance = match source {
@@ -1155,33 +3432,240 @@ fn validate_bond(
     is_valid.then_some(validated_bond)
 }
 
+/// Check that the balance of the bond's source, if it's a PK, is proven by the
+/// given balance proofs. The balance of the source is checked later in
+/// `validate_bond`, so a missing balance is not an error here.
+fn validate_bond_balance_proof(
+    tx: &BondTx<Unvalidated>,
+    balances: Option<&DenominatedBalances>,
+    balance_proofs: &BalanceProofs,
+    native_token: &Alias,
+) -> bool {
+    match &tx.source {
+        AliasOrPk::PublicKey(pk) => validate_balance_proof(
+            native_token,
+            pk,
+            balances,
+            balance_proofs,
+            "bond",
+        ),
+        AliasOrPk::Alias(_) => true,
+    }
+}
+
+/// Check that the balance of the transfer's source is proven by the given
+/// balance proofs. The balance of the source is checked later in
+/// `validate_transfer_data`, so a missing balance is not an error here.
+fn validate_transfer_balance_proof(
+    tx: &TransferTx<Unvalidated>,
+    balances: Option<&DenominatedBalances>,
+    balance_proofs: &BalanceProofs,
+) -> bool {
+    validate_balance_proof(
+        &tx.token,
+        &tx.source,
+        balances,
+        balance_proofs,
+        "transfer",
+    )
+}
+
+/// Check that the balance of the `token` owned by the `pk` in the `balances`,
+/// if any, is proven by the given balance proofs
+fn validate_balance_proof(
+    token: &Alias,
+    pk: &StringEncoded<common::PublicKey>,
+    balances: Option<&DenominatedBalances>,
+    balance_proofs: &BalanceProofs,
+    tx_kind: &str,
+) -> bool {
+    let balance = balances
+        .and_then(|balances| balances.token.get(token))
+        .and_then(|balances| balances.0.get(pk));
+    match balance {
+        Some(balance) if !balance_proofs.verify(token, pk, balance.amount) => {
+            eprintln!(
+                "Invalid {tx_kind} tx. The balance {balance} of token \
+                 \"{token}\" of the source {pk} is not proven."
+            );
+            false
+        }
+        _ => true,
+    }
+}
+
+/// Merkle proofs of the balances owned by PKs, e.g. for balances imported from
+/// an external snapshot.
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    Deserialize,
+    Serialize,
+    BorshSerialize,
+    BorshDeserialize,
+    PartialEq,
+    Eq,
+)]
+pub struct BalanceProofs {
+    /// The root of the Merkle tree of the balances
+    pub root: Hash,
+    /// Proofs of balances of each token, by their owner's PK
+    pub proofs: BTreeMap<
+        Alias,
+        BTreeMap<StringEncoded<common::PublicKey>, BalanceProof>,
+    >,
+}
+
+impl BalanceProofs {
+    /// Check that the given balance of the token owned by the PK is proven
+    pub fn verify(
+        &self,
+        token: &Alias,
+        pk: &StringEncoded<common::PublicKey>,
+        amount: token::Amount,
+    ) -> bool {
+        self.proofs
+            .get(token)
+            .and_then(|proofs| proofs.get(pk))
+            .map(|proof| proof.root(token, pk, amount) == self.root)
+            .unwrap_or_default()
+    }
+}
+
+/// A Merkle proof of a balance of a token owned by a PK
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    Deserialize,
+    Serialize,
+    BorshSerialize,
+    BorshDeserialize,
+    PartialEq,
+    Eq,
+)]
+pub struct BalanceProof {
+    /// The sibling hashes on the path from the balance leaf to the root. The
+    /// flag is `true` when the sibling is on the left.
+    pub path: Vec<(bool, Hash)>,
+}
+
+impl BalanceProof {
+    /// The prefix of the hashed data of a leaf, which tells it apart from
+    /// the data of a node
+    pub const LEAF_PREFIX: u8 = 0;
+    /// The prefix of the hashed data of a node, which tells it apart from
+    /// the data of a leaf
+    pub const NODE_PREFIX: u8 = 1;
+
+    /// The hash of a Merkle tree leaf of a balance
+    pub fn leaf_hash(
+        token: &Alias,
+        pk: &StringEncoded<common::PublicKey>,
+        amount: token::Amount,
+    ) -> Hash {
+        Hash::sha256(
+            [
+                vec![Self::LEAF_PREFIX],
+                token.serialize_to_vec(),
+                pk.serialize_to_vec(),
+                amount.serialize_to_vec(),
+            ]
+            .concat(),
+        )
+    }
+
+    /// The hash of a Merkle tree node from its children
+    pub fn node_hash(left: &Hash, right: &Hash) -> Hash {
+        Hash::sha256(
+            [&[Self::NODE_PREFIX][..], &left.0[..], &right.0[..]].concat(),
+        )
+    }
+
+    /// Compute the Merkle tree root for the given balance from this proof
+    pub fn root(
+        &self,
+        token: &Alias,
+        pk: &StringEncoded<common::PublicKey>,
+        amount: token::Amount,
+    ) -> Hash {
+        self.path.iter().fold(
+            Self::leaf_hash(token, pk, amount),
+            |hash, (is_left, sibling)| {
+                if *is_left {
+                    Self::node_hash(sibling, &hash)
+                } else {
+                    Self::node_hash(&hash, sibling)
+                }
+            },
+        )
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct TokenBalancesForValidation {
+    /// The alias of the token of these balances
+    pub token: Alias,
     /// Accumulator for tokens transferred to aliases
     pub aliases: BTreeMap<Alias, token::DenominatedAmount>,
     /// Token balances from the balances file, associated with PKs
     pub pks: TokenBalances,
 }
 
+impl TokenBalancesForValidation {
+    /// Apply the balances of the token left after validating a batch of txs
+    /// to the `balances`, so that they can be used as the starting balances
+    /// to validate another batch. The balances of PKs are replaced with the
+    /// remaining ones and the balances accumulated by aliases are
+    /// materialized in [`DenominatedBalances::aliases`].
+    pub fn apply_to(&self, balances: &mut DenominatedBalances) {
+        balances.token.insert(self.token.clone(), self.pks.clone());
+        if self.aliases.is_empty() {
+            balances.aliases.remove(&self.token);
+        } else {
+            balances
+                .aliases
+                .insert(self.token.clone(), self.aliases.clone());
+        }
+    }
+}
+
+/// Validate an established account tx and add it to the used aliases and
+/// established accounts. The details of an invalid tx are printed to stderr.
 pub fn validate_established_account(
     tx: &SignedEstablishedAccountTx,
     vps: Option<&ValidityPredicates>,
     all_used_aliases: &mut BTreeSet<Alias>,
     established_accounts: &mut BTreeMap<Alias, Option<common::PublicKey>>,
-) -> bool {
+) -> Result<(), GenesisTxError> {
     let mut is_valid = true;
 
-    established_accounts.insert(
-        tx.alias.clone(),
-        tx.public_key.as_ref().map(|signed| signed.pk.raw.clone()),
-    );
+    let is_established_duplicate = established_accounts
+        .insert(
+            tx.alias.clone(),
+            tx.public_key.as_ref().map(|signed| signed.pk.raw.clone()),
+        )
+        .is_some();
 
-    // Check that alias is unique
+    // Check that alias is valid and unique
+    if !validate_alias(&tx.alias, "established_account") {
+        is_valid = false;
+    }
     if all_used_aliases.contains(&tx.alias) {
-        eprintln!(
-            "A duplicate alias \"{}\" found in a `established_account` tx.",
-            tx.alias
-        );
+        if is_established_duplicate {
+            eprintln!(
+                "A duplicate alias \"{}\" found in a `established_account` \
+                 tx.",
+                tx.alias
+            );
+        } else {
+            eprintln!(
+                "The alias \"{}\" of an `established_account` tx is already \
+                 used by a `validator_account` tx.",
+                tx.alias
+            );
+        }
         is_valid = false;
     } else {
         all_used_aliases.insert(tx.alias.clone());
@@ -1207,7 +3691,14 @@ pub fn validate_established_account(
         }
     }
 
-    is_valid
+    if is_valid {
+        Ok(())
+    } else {
+        Err(ValidationError::InvalidEstablishedAccount {
+            alias: tx.alias.clone(),
+        }
+        .into())
+    }
 }
 
 fn validate_established_account_sig(
@@ -1218,22 +3709,60 @@ fn validate_established_account_sig(
     validate_signature(&unsigned, &pk.raw, &authorization.raw)
 }
 
+/// Validate a validator account tx and add it to the used aliases and
+/// validator accounts. The details of an invalid tx are printed to stderr.
 pub fn validate_validator_account(
     tx: &ValidatorAccountTx<SignedPk>,
     vps: Option<&ValidityPredicates>,
     all_used_aliases: &mut BTreeSet<Alias>,
     validator_accounts: &mut BTreeMap<Alias, common::PublicKey>,
+) -> Result<(), GenesisTxError> {
+    let is_valid = validate_validator_account_data(
+        tx,
+        vps,
+        all_used_aliases,
+        validator_accounts,
+    );
+    if report_sig_errors(&validator_account_sig_errors(tx)) && is_valid {
+        Ok(())
+    } else {
+        Err(ValidationError::InvalidValidatorAccount {
+            alias: tx.alias.clone(),
+        }
+        .into())
+    }
+}
+
+/// Validate a validator account tx, except for its keys authorizations
+fn validate_validator_account_data(
+    tx: &ValidatorAccountTx<SignedPk>,
+    vps: Option<&ValidityPredicates>,
+    all_used_aliases: &mut BTreeSet<Alias>,
+    validator_accounts: &mut BTreeMap<Alias, common::PublicKey>,
 ) -> bool {
     let mut is_valid = true;
 
-    validator_accounts.insert(tx.alias.clone(), tx.account_key.pk.raw.clone());
+    let is_validator_duplicate = validator_accounts
+        .insert(tx.alias.clone(), tx.account_key.pk.raw.clone())
+        .is_some();
 
-    // Check that alias is unique
+    // Check that alias is valid and unique
+    if !validate_alias(&tx.alias, "validator_account") {
+        is_valid = false;
+    }
     if all_used_aliases.contains(&tx.alias) {
-        eprintln!(
-            "A duplicate alias \"{}\" found in a `validator_account` tx.",
-            tx.alias
-        );
+        if is_validator_duplicate {
+            eprintln!(
+                "A duplicate alias \"{}\" found in a `validator_account` tx.",
+                tx.alias
+            );
+        } else {
+            eprintln!(
+                "The alias \"{}\" of a `validator_account` tx is already \
+                 used by an `established_account` tx.",
+                tx.alias
+            );
+        }
         is_valid = false;
     } else {
         all_used_aliases.insert(tx.alias.clone());
@@ -1252,102 +3781,171 @@ pub fn validate_validator_account(
         is_valid = false;
     }
 
-    // Check keys authorizations
+    is_valid
+}
+
+/// Check that the net address of a validator account is usable on a public
+/// network, i.e. that it's neither a loopback nor an unspecified address.
+/// This is not part of [`validate`] as local testnets use such addresses.
+pub fn validate_net_address<PK>(
+    tx: &ValidatorAccountTx<PK>,
+) -> Result<(), GenesisTxError> {
+    let ip = tx.net_address.ip();
+    if ip.is_loopback() || ip.is_unspecified() {
+        return Err(ValidationError::UnreachableNetAddress {
+            alias: tx.alias.clone(),
+            net_address: tx.net_address,
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Check the keys authorizations of a validator account tx. Returns the
+/// errors to report, which are empty when all the authorizations are valid.
+fn validator_account_sig_errors(tx: &SignedValidatorAccountTx) -> Vec<String> {
     let unsigned = UnsignedValidatorAccountTx::from(tx);
-    if !validate_signature(
-        &unsigned,
-        &tx.account_key.pk.raw,
-        &tx.account_key.authorization.raw,
-    ) {
-        eprintln!(
-            "Invalid `account_key` authorization for `validator_account` tx \
-             with alias \"{}\".",
-            tx.alias
-        );
-        is_valid = false;
+    let mut errors = validator_account_keys_errors(&unsigned, tx);
+    for (key_name, SignedPk { pk, authorization }) in [
+        ("consensus_key", &tx.consensus_key),
+        ("protocol_key", &tx.protocol_key),
+        ("tendermint_node_key", &tx.tendermint_node_key),
+        ("eth_hot_key", &tx.eth_hot_key),
+        ("eth_cold_key", &tx.eth_cold_key),
+    ] {
+        if let Some(error) =
+            signature_error(&unsigned, &pk.raw, &authorization.raw)
+        {
+            errors.push(error);
+            errors.push(format!(
+                "Invalid `{key_name}` authorization for `validator_account` \
+                 tx with alias \"{}\".",
+                tx.alias
+            ));
+        }
     }
-    if !validate_signature(
-        &unsigned,
-        &tx.consensus_key.pk.raw,
-        &tx.consensus_key.authorization.raw,
-    ) {
-        eprintln!(
-            "Invalid `consensus_key` authorization for `validator_account` tx \
-             with alias \"{}\".",
+    errors
+}
+
+/// The maximum number of the `account_key` and `account_keys` of a validator
+/// account, which are stored at `u8` indices and whose number must fit the
+/// `u8` threshold
+pub const MAX_VALIDATOR_ACCOUNT_KEYS: usize = u8::MAX as usize;
+
+/// Check that the `account_key` and `account_keys` are distinct and not too
+/// many, and that at least `threshold` of their authorizations are valid.
+/// When the threshold is not set, all of them must be valid.
+fn validator_account_keys_errors(
+    unsigned: &UnsignedValidatorAccountTx,
+    tx: &SignedValidatorAccountTx,
+) -> Vec<String> {
+    let num_keys = 1 + tx.account_keys.len();
+    if num_keys > MAX_VALIDATOR_ACCOUNT_KEYS {
+        return vec![format!(
+            "The `validator_account` tx with alias \"{}\" has {num_keys} \
+             account keys, but at most {MAX_VALIDATOR_ACCOUNT_KEYS} are \
+             allowed.",
             tx.alias
-        );
-        is_valid = false;
+        )];
     }
-    if !validate_signature(
-        &unsigned,
-        &tx.protocol_key.pk.raw,
-        &tx.protocol_key.authorization.raw,
-    ) {
-        eprintln!(
-            "Invalid `protocol_key` authorization for `validator_account` tx \
-             with alias \"{}\".",
-            tx.alias
-        );
-        is_valid = false;
+    let mut unique_keys = BTreeSet::new();
+    for SignedPk { pk, .. } in
+        std::iter::once(&tx.account_key).chain(tx.account_keys.iter())
+    {
+        if !unique_keys.insert(&pk.raw) {
+            return vec![format!(
+                "The account key {pk} of the `validator_account` tx with \
+                 alias \"{}\" is repeated. Each key can only be used once.",
+                tx.alias
+            )];
+        }
     }
-    if !validate_signature(
-        &unsigned,
-        &tx.tendermint_node_key.pk.raw,
-        &tx.tendermint_node_key.authorization.raw,
-    ) {
-        eprintln!(
-            "Invalid `tendermint_node_key` authorization for \
-             `validator_account` tx with alias \"{}\".",
+    let threshold = tx.threshold.map(usize::from).unwrap_or(num_keys);
+    if threshold == 0 || threshold > num_keys {
+        return vec![format!(
+            "Invalid `threshold` {threshold} for `validator_account` tx with \
+             alias \"{}\". It must be between 1 and the number of account \
+             keys {num_keys}.",
             tx.alias
-        );
-        is_valid = false;
+        )];
     }
-
-    if !validate_signature(
-        &unsigned,
-        &tx.eth_hot_key.pk.raw,
-        &tx.eth_hot_key.authorization.raw,
-    ) {
-        eprintln!(
-            "Invalid `eth_hot_key` authorization for `validator_account` tx \
-             with alias \"{}\".",
+    let mut errors: Vec<String> = std::iter::once(&tx.account_key)
+        .chain(tx.account_keys.iter())
+        .filter_map(|SignedPk { pk, authorization }| {
+            signature_error(unsigned, &pk.raw, &authorization.raw)
+        })
+        .collect();
+    let num_valid = num_keys - errors.len();
+    if num_valid < threshold {
+        errors.push(format!(
+            "Invalid `account_key` authorizations for `validator_account` tx \
+             with alias \"{}\". Only {num_valid} of the required \
+             {threshold} authorizations are valid.",
             tx.alias
-        );
-        is_valid = false;
+        ));
+        errors
+    } else {
+        vec![]
     }
+}
 
-    if !validate_signature(
-        &unsigned,
-        &tx.eth_cold_key.pk.raw,
-        &tx.eth_cold_key.authorization.raw,
-    ) {
-        eprintln!(
-            "Invalid `eth_cold_key` authorization for `validator_account` tx \
-             with alias \"{}\".",
-            tx.alias
-        );
-        is_valid = false;
+/// Updates the token balances with all the valid transfers applied. The
+/// details of an invalid tx are printed to stderr.
+pub fn validate_transfer(
+    tx: &SignedTransferTx,
+    balances: &mut BTreeMap<Alias, TokenBalancesForValidation>,
+    all_used_aliases: &BTreeSet<Alias>,
+    tokens: &Tokens,
+) -> Result<TransferTx<Validated>, GenesisTxError> {
+    let is_sig_valid = report_sig_errors(&transfer_sig_errors(tx));
+    validate_transfer_data(tx.into(), balances, all_used_aliases, tokens)
+        .filter(|_| is_sig_valid)
+        .ok_or_else(|| {
+            ValidationError::InvalidTransfer {
+                token: tx.data.token.clone(),
+                source: tx.data.source.clone(),
+                target: tx.data.target.clone(),
+            }
+            .into()
+        })
+}
+
+/// Check the signature of a transfer tx. Returns the errors to report, which
+/// are empty when the signature is valid.
+fn transfer_sig_errors(tx: &SignedTransferTx) -> Vec<String> {
+    match tx.verify_sig() {
+        Ok(()) => vec![],
+        Err(err) => vec![format!("Invalid transfer tx signature: {err}.")],
     }
+}
 
-    is_valid
+/// Check that the token of a transfer tx is in the tokens file, otherwise
+/// report it with [`ValidationError::UnknownToken`]
+fn validate_transfer_token(
+    token: &Alias,
+    tokens: &Tokens,
+    errors: &mut Vec<ValidationError>,
+) -> bool {
+    let is_known = tokens.token.contains_key(token);
+    if !is_known {
+        errors.push(ValidationError::UnknownToken {
+            token: token.clone(),
+        });
+    }
+    is_known
 }
 
-/// Updates the token balances with all the valid transfers applied
-pub fn validate_transfer(
-    tx: &SignedTransferTx,
+/// Validate a transfer tx, except for its signature, and update the token
+/// balances if it's valid
+fn validate_transfer_data(
+    tx: TransferTx<Unvalidated>,
     balances: &mut BTreeMap<Alias, TokenBalancesForValidation>,
     all_used_aliases: &BTreeSet<Alias>,
     tokens: &Tokens,
 ) -> Option<TransferTx<Validated>> {
     let mut is_valid = true;
-    // Check signature
-    if tx.verify_sig().is_err() {
-        eprintln!("Invalid transfer tx signature.",);
-        is_valid = false;
-    }
 
-    let unsigned: TransferTx<Unvalidated> = tx.into();
-    let validated = unsigned.denominate(tokens).ok()?;
+    let validated = tx.denominate(tokens).ok()?;
     let TransferTx {
         token,
         source,
@@ -1356,7 +3954,10 @@ pub fn validate_transfer(
         ..
     } = &validated;
 
-    // Check that the target exists
+    // Check that the target is valid and exists
+    if !validate_alias(target, "transfer") {
+        is_valid = false;
+    }
     if !all_used_aliases.contains(target) {
         eprintln!(
             "Invalid transfer tx. The target alias \"{target}\" no matching \
@@ -1370,7 +3971,21 @@ pub fn validate_transfer(
     match balances.get_mut(token) {
         Some(balances) => match balances.pks.0.get_mut(source) {
             Some(balance) => {
-                if balance.amount < amount.amount {
+                // The source and target balances must share the token's
+                // denomination with the amount
+                let mismatched_denom = Some(balance.denom)
+                    .into_iter()
+                    .chain(balances.aliases.get(target).map(|b| b.denom))
+                    .find(|denom| *denom != amount.denom);
+                if let Some(denom) = mismatched_denom {
+                    eprintln!(
+                        "Invalid transfer tx. The amount {amount} of token \
+                         \"{token}\" has denomination {}, which doesn't match \
+                         the denomination {} of its balances.",
+                        amount.denom.0, denom.0,
+                    );
+                    is_valid = false;
+                } else if balance.amount < amount.amount {
                     eprintln!(
                         "Invalid transfer tx. Source {source} doesn't have \
                          enough balance of token \"{token}\" to transfer {}. \
@@ -1416,22 +4031,250 @@ pub fn validate_transfer(
     is_valid.then_some(validated)
 }
 
+/// Check that a transfer's amount doesn't exceed the maximum, if any
+fn validate_transfer_max(
+    tx: &TransferTx<Validated>,
+    max_transfer: Option<DenominatedAmount>,
+) -> bool {
+    match max_transfer {
+        Some(max) if tx.amount > max => {
+            eprintln!(
+                "Invalid transfer tx. The transfer of {} of token \"{}\" from \
+                 {} to \"{}\" exceeds the maximum transfer amount {}.",
+                tx.amount, tx.token, tx.source, tx.target, max,
+            );
+            false
+        }
+        _ => true,
+    }
+}
+
+/// Check that an alias referenced by a tx of the given kind is valid
+fn validate_alias(alias: &Alias, tx_kind: &str) -> bool {
+    match alias.validate() {
+        Ok(()) => true,
+        Err(err) => {
+            eprintln!("Invalid alias in a `{tx_kind}` tx: {err}.");
+            false
+        }
+    }
+}
+
 fn validate_signature<T: BorshSerialize + Debug>(
     tx_data: &T,
     pk: &common::PublicKey,
     sig: &common::Signature,
 ) -> bool {
-    match verify_standalone_sig::<T, SerializeWithBorsh>(tx_data, pk, sig) {
-        Ok(()) => true,
-        Err(err) => {
-            eprintln!(
-                "Invalid tx signature in tx {tx_data:?}, failed with: {err}."
-            );
+    match signature_error(tx_data, pk, sig) {
+        None => true,
+        Some(error) => {
+            eprintln!("{error}");
             false
         }
     }
 }
 
+/// The scheme of a signature
+fn signature_scheme(sig: &common::Signature) -> SchemeType {
+    match sig {
+        common::Signature::Ed25519(_) => SchemeType::Ed25519,
+        common::Signature::Secp256k1(_) => SchemeType::Secp256k1,
+    }
+}
+
+/// Check that the txs are signed with the allowed schemes only. The
+/// authorizations of validator's consensus, protocol, node and Ethereum keys
+/// are not checked, because their schemes are determined by the keys' roles.
+/// Any disallowed signatures are printed to stderr.
+fn validate_signature_schemes(
+    txs: &Transactions<Unvalidated>,
+    allowed_schemes: &[SchemeType],
+) -> bool {
+    let mut is_valid = true;
+    let mut validate_scheme =
+        |tx_kind: &str, signer: &dyn Display, sig: &common::Signature| {
+            let scheme = signature_scheme(sig);
+            if !allowed_schemes.contains(&scheme) {
+                eprintln!(
+                    "A `{tx_kind}` tx from {signer} is signed with a \
+                     {scheme:?} signature, but only {allowed_schemes:?} \
+                     signatures are allowed."
+                );
+                is_valid = false;
+            }
+        };
+    for tx in txs.established_account.iter().flatten() {
+        if let Some(pk) = tx.public_key.as_ref() {
+            validate_scheme(
+                "established_account",
+                &tx.alias,
+                &pk.authorization.raw,
+            );
+        }
+    }
+    for tx in txs.validator_account.iter().flatten() {
+        for key in std::iter::once(&tx.account_key).chain(&tx.account_keys) {
+            validate_scheme(
+                "validator_account",
+                &tx.alias,
+                &key.authorization.raw,
+            );
+        }
+    }
+    for tx in txs.transfer.iter().flatten() {
+        validate_scheme("transfer", &tx.data.source, &tx.signature.raw);
+    }
+    for tx in txs.bond.iter().flatten() {
+        validate_scheme("bond", &tx.data.source, &tx.signature.raw);
+    }
+    is_valid
+}
+
+/// Check that the transfers and bonds are signed in the current
+/// [`SIGNED_FORMAT_VERSION`]. The older formats are only accepted with
+/// [`ValidationOptions::allow_legacy_signatures`].
+fn validate_signed_format_versions(txs: &Transactions<Unvalidated>) -> bool {
+    let mut is_valid = true;
+    let mut validate_version =
+        |tx_kind: &str, signer: &dyn Display, version: u8| {
+            if version < SIGNED_FORMAT_VERSION {
+                eprintln!(
+                    "A `{tx_kind}` tx from {signer} is signed in the legacy \
+                     format version {version}. Sign it again to use the \
+                     current version {SIGNED_FORMAT_VERSION}."
+                );
+                is_valid = false;
+            }
+        };
+    for tx in txs.transfer.iter().flatten() {
+        validate_version("transfer", &tx.data.source, tx.version);
+    }
+    for tx in txs.bond.iter().flatten() {
+        validate_version("bond", &tx.data.source, tx.version);
+    }
+    is_valid
+}
+
+/// Verify a signature, returning the error to report if it's invalid
+fn signature_error<T: BorshSerialize + Debug>(
+    tx_data: &T,
+    pk: &common::PublicKey,
+    sig: &common::Signature,
+) -> Option<String> {
+    verify_standalone_sig::<T, SerializeWithBorsh>(tx_data, pk, sig)
+        .err()
+        .map(|err| {
+            format!(
+                "Invalid tx signature in tx {tx_data:?}, failed with: {err}."
+            )
+        })
+}
+
+/// Print the given signature errors. Returns `true` if there are none.
+fn report_sig_errors(errors: &[String]) -> bool {
+    for error in errors {
+        eprintln!("{error}");
+    }
+    errors.is_empty()
+}
+
+/// The errors from verifying the signatures of genesis txs, keyed by the
+/// index of the tx in its section. Only the invalid txs have an entry.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct SignatureErrors {
+    validator_account: BTreeMap<usize, Vec<String>>,
+    transfer: BTreeMap<usize, Vec<String>>,
+}
+
+impl SignatureErrors {
+    /// The signature errors of the validator account tx at the given index
+    fn validator_account(&self, ix: usize) -> &[String] {
+        self.validator_account
+            .get(&ix)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// The signature errors of the transfer tx at the given index
+    fn transfer(&self, ix: usize) -> &[String] {
+        self.transfer
+            .get(&ix)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+}
+
+/// Verify the signatures of the validator account and transfer txs, which
+/// don't depend on the other txs. With the `parallel-genesis-validation`
+/// feature, the signatures are verified concurrently.
+fn verify_signatures(
+    validator_account: &[SignedValidatorAccountTx],
+    transfer: &[SignedTransferTx],
+) -> SignatureErrors {
+    #[cfg(feature = "parallel-genesis-validation")]
+    {
+        verify_signatures_parallel(validator_account, transfer)
+    }
+    #[cfg(not(feature = "parallel-genesis-validation"))]
+    {
+        verify_signatures_serial(validator_account, transfer)
+    }
+}
+
+#[cfg(any(test, not(feature = "parallel-genesis-validation")))]
+fn verify_signatures_serial(
+    validator_account: &[SignedValidatorAccountTx],
+    transfer: &[SignedTransferTx],
+) -> SignatureErrors {
+    SignatureErrors {
+        validator_account: validator_account
+            .iter()
+            .map(validator_account_sig_errors)
+            .enumerate()
+            .filter(|(_ix, errors)| !errors.is_empty())
+            .collect(),
+        transfer: transfer
+            .iter()
+            .map(transfer_sig_errors)
+            .enumerate()
+            .filter(|(_ix, errors)| !errors.is_empty())
+            .collect(),
+    }
+}
+
+#[cfg(any(test, feature = "parallel-genesis-validation"))]
+fn verify_signatures_parallel(
+    validator_account: &[SignedValidatorAccountTx],
+    transfer: &[SignedTransferTx],
+) -> SignatureErrors {
+    use rayon::prelude::*;
+
+    let (validator_account, transfer): (Vec<_>, Vec<_>) = rayon::join(
+        || {
+            validator_account
+                .par_iter()
+                .map(validator_account_sig_errors)
+                .enumerate()
+                .filter(|(_ix, errors)| !errors.is_empty())
+                .collect()
+        },
+        || {
+            transfer
+                .par_iter()
+                .map(transfer_sig_errors)
+                .enumerate()
+                .filter(|(_ix, errors)| !errors.is_empty())
+                .collect()
+        },
+    );
+    // Sort the errors by the txs' indices to keep the error output
+    // deterministic
+    SignatureErrors {
+        validator_account: validator_account.into_iter().collect(),
+        transfer: transfer.into_iter().collect(),
+    }
+}
+
 impl From<&SignedEstablishedAccountTx> for UnsignedEstablishedAccountTx {
     fn from(tx: &SignedEstablishedAccountTx) -> Self {
         let SignedEstablishedAccountTx {
@@ -1467,6 +4310,8 @@ impl From<&SignedValidatorAccountTx> for UnsignedValidatorAccountTx {
             tendermint_node_key,
             eth_hot_key,
             eth_cold_key,
+            threshold,
+            account_keys,
         } = tx;
 
         Self {
@@ -1485,20 +4330,3621 @@ impl From<&SignedValidatorAccountTx> for UnsignedValidatorAccountTx {
             tendermint_node_key: tendermint_node_key.pk.clone(),
             eth_hot_key: eth_hot_key.pk.clone(),
             eth_cold_key: eth_cold_key.pk.clone(),
+            threshold: *threshold,
+            account_keys: account_keys
+                .iter()
+                .map(|signed| signed.pk.clone())
+                .collect(),
         }
     }
 }
 
 impl From<&SignedTransferTx> for TransferTx<Unvalidated> {
     fn from(tx: &SignedTransferTx) -> Self {
-        let SignedTransferTx { data, signature: _ } = tx;
+        let SignedTransferTx { data, .. } = tx;
         data.clone()
     }
 }
 
 impl From<&SignedBondTx> for BondTx<Unvalidated> {
     fn from(tx: &SignedBondTx) -> Self {
-        let SignedBondTx { data, signature: _ } = tx;
+        let SignedBondTx { data, .. } = tx;
         data.clone()
     }
 }
+
+impl From<TransferTx<Validated>> for TransferTx<Unvalidated> {
+    fn from(tx: TransferTx<Validated>) -> Self {
+        let TransferTx {
+            token,
+            source,
+            target,
+            amount,
+            nonce,
+        } = tx;
+        Self {
+            token,
+            source,
+            target,
+            amount,
+            nonce,
+        }
+    }
+}
+
+impl From<BondTx<Validated>> for BondTx<Unvalidated> {
+    fn from(tx: BondTx<Validated>) -> Self {
+        let BondTx {
+            source,
+            validator,
+            amount,
+            nonce,
+        } = tx;
+        Self {
+            source,
+            validator,
+            amount: BondAmount::Fixed(amount),
+            nonce,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use namada::core::types::key::testing::{
+        common_sk_from_simple_seed, gen_keypair, keypair_3, keypair_4,
+    };
+    use namada::ledger::storage::Sha256Hasher;
+    use namada::types::key::{secp256k1, SigScheme};
+    use namada_sdk::wallet::pre_genesis::ValidatorStore;
+    use namada_sdk::wallet::{StoredKeypair, ValidatorKeys};
+
+    use super::*;
+    use crate::config::genesis::templates;
+    use crate::config::genesis::templates::{TokenConfig, WasmVpConfig};
+
+    /// Make a validator wallet with keys derived from the given seed
+    fn validator_wallet(seed: u64) -> ValidatorWallet {
+        let account_key = common_sk_from_simple_seed(seed);
+        let consensus_key = common_sk_from_simple_seed(seed + 1);
+        let tendermint_node_key = common_sk_from_simple_seed(seed + 2);
+        let protocol_keypair = common_sk_from_simple_seed(seed + 3);
+        let eth_cold_key = keypair_3();
+        let eth_hot_key = keypair_4();
+        let store = ValidatorStore {
+            account_key: StoredKeypair::new(account_key.clone(), None).0,
+            consensus_key: StoredKeypair::new(consensus_key.clone(), None).0,
+            eth_cold_key: StoredKeypair::new(eth_cold_key.clone(), None).0,
+            tendermint_node_key: StoredKeypair::new(
+                tendermint_node_key.clone(),
+                None,
+            )
+            .0,
+            validator_keys: ValidatorKeys {
+                protocol_keypair,
+                eth_bridge_keypair: eth_hot_key.clone(),
+            },
+        };
+        ValidatorWallet {
+            store,
+            account_key,
+            consensus_key,
+            eth_cold_key,
+            eth_hot_key,
+            tendermint_node_key,
+        }
+    }
+
+    /// Make an unsigned validator account tx using the keys from the given
+    /// validator wallet
+    fn unsigned_validator_account_tx(
+        alias: &str,
+        validator_wallet: &ValidatorWallet,
+    ) -> UnsignedValidatorAccountTx {
+        ValidatorAccountTx {
+            alias: Alias::from(alias),
+            vp: "vp_validator".to_string(),
+            commission_rate: Dec::new(5, 2).unwrap(),
+            max_commission_rate_change: Dec::new(1, 2).unwrap(),
+            email: "null@null.net".to_string(),
+            description: None,
+            website: None,
+            discord_handle: None,
+            net_address: SocketAddr::from(([127, 0, 0, 1], 26656)),
+            threshold: None,
+            account_key: StringEncoded::new(
+                validator_wallet.account_key.ref_to(),
+            ),
+            consensus_key: StringEncoded::new(
+                validator_wallet.consensus_key.ref_to(),
+            ),
+            protocol_key: StringEncoded::new(
+                validator_wallet
+                    .store
+                    .validator_keys
+                    .protocol_keypair
+                    .ref_to(),
+            ),
+            tendermint_node_key: StringEncoded::new(
+                validator_wallet.tendermint_node_key.ref_to(),
+            ),
+            eth_hot_key: StringEncoded::new(
+                validator_wallet.eth_hot_key.ref_to(),
+            ),
+            eth_cold_key: StringEncoded::new(
+                validator_wallet.eth_cold_key.ref_to(),
+            ),
+            account_keys: vec![],
+        }
+    }
+
+    /// Tokens config with the given aliases and denominations
+    fn tokens(tokens: &[(&str, u8)]) -> Tokens {
+        Tokens {
+            token: tokens
+                .iter()
+                .map(|(alias, denom)| {
+                    (
+                        Alias::from(alias),
+                        TokenConfig {
+                            denom: (*denom).into(),
+                            parameters: token::Parameters {
+                                max_reward_rate: Dec::zero(),
+                                kd_gain_nom: Dec::zero(),
+                                kp_gain_nom: Dec::zero(),
+                                locked_ratio_target: Dec::zero(),
+                            },
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Make an unsigned transfer tx
+    fn transfer_tx(
+        token: &str,
+        source: &common::SecretKey,
+        target: &str,
+        amount: &str,
+    ) -> TransferTx<Unvalidated> {
+        TransferTx {
+            token: Alias::from(token),
+            source: StringEncoded::new(source.ref_to()),
+            target: Alias::from(target),
+            amount: DenominatedAmount::from_str(amount).unwrap(),
+            nonce: None,
+        }
+    }
+
+    /// A bond of the given amount from the source to the validator, without a
+    /// nonce
+    fn bond_tx<T: TemplateValidation>(
+        source: AliasOrPk,
+        validator: &str,
+        amount: T::BondAmount,
+    ) -> BondTx<T> {
+        BondTx {
+            source,
+            validator: Alias::from(validator),
+            amount,
+            nonce: None,
+        }
+    }
+
+    /// Load the validated `genesis/localnet` genesis templates
+    fn localnet_templates() -> templates::All<Validated> {
+        let templates_dir =
+            std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .parent()
+                .unwrap()
+                .join("genesis/localnet");
+        templates::load_and_validate(
+            &templates_dir,
+            &ValidationOptions::with_legacy_signatures(),
+        )
+        .expect("Localnet genesis templates must be valid")
+    }
+
+    /// Validity predicates config containing the given VPs
+    fn vps(names: &[&str]) -> ValidityPredicates {
+        ValidityPredicates {
+            wasm: names
+                .iter()
+                .map(|name| {
+                    (
+                        name.to_string(),
+                        WasmVpConfig {
+                            filename: format!("{name}.wasm"),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// The common setup of the validation tests
+    struct ValidationFixture {
+        /// The validated `genesis/localnet` templates
+        templates: templates::All<Validated>,
+        /// The native token of the templates
+        native_token: Alias,
+        /// A signed account tx of a validator with the alias "validator"
+        validator_tx: SignedValidatorAccountTx,
+        /// The secret key of the `source`
+        source_sk: common::SecretKey,
+        /// A PK with the `balance` of the native token in the `balances`
+        source: StringEncoded<common::PublicKey>,
+        /// The balance of the `source`
+        balance: DenominatedAmount,
+        /// Balances with only the `balance` of the `source`
+        balances: DenominatedBalances,
+        /// The VPs with the "vp_validator"
+        vps: ValidityPredicates,
+    }
+
+    /// Set up the common state of the validation tests
+    /// The validation errors of a failed validation
+    fn validation_errors(err: GenesisTxError) -> Vec<ValidationError> {
+        match err {
+            GenesisTxError::Validation(errors) => errors,
+            err => panic!("Unexpected error {err}"),
+        }
+    }
+
+    fn validation_fixture() -> ValidationFixture {
+        let templates = localnet_templates();
+        let native_token = templates.parameters.parameters.native_token.clone();
+        let validator_wallet = validator_wallet(0);
+        let validator_tx = sign_validator_account_tx(
+            unsigned_validator_account_tx("validator", &validator_wallet),
+            &validator_wallet,
+            &[],
+        );
+        let source_sk = common_sk_from_simple_seed(100);
+        let source = StringEncoded::new(source_sk.ref_to());
+        let balance =
+            DenominatedAmount::native(token::Amount::native_whole(100));
+        let balances = DenominatedBalances {
+            token: BTreeMap::from([(
+                native_token.clone(),
+                TokenBalances(BTreeMap::from([(source.clone(), balance)])),
+            )]),
+            aliases: BTreeMap::new(),
+        };
+        ValidationFixture {
+            templates,
+            native_token,
+            validator_tx,
+            source_sk,
+            source,
+            balance,
+            balances,
+            vps: vps(&["vp_validator"]),
+        }
+    }
+
+    /// Test that a validator account with a 2-of-3 account keys threshold is
+    /// valid with 2 or 3 valid authorizations and invalid with only 1.
+    #[test]
+    fn test_validator_account_keys_threshold() {
+        let validator_wallet = validator_wallet(0);
+        let extra_keys =
+            [common_sk_from_simple_seed(10), common_sk_from_simple_seed(11)];
+        let mut unsigned =
+            unsigned_validator_account_tx("validator", &validator_wallet);
+        unsigned.threshold = Some(2);
+        unsigned.account_keys = extra_keys
+            .iter()
+            .map(|sk| StringEncoded::new(sk.ref_to()))
+            .collect();
+        let signed = sign_validator_account_tx(
+            unsigned,
+            &validator_wallet,
+            &extra_keys,
+        );
+        let vps = vps(&["vp_validator"]);
+        let is_valid = |tx: &SignedValidatorAccountTx| {
+            validate_validator_account(
+                tx,
+                Some(&vps),
+                &mut BTreeSet::new(),
+                &mut BTreeMap::new(),
+            )
+            .is_ok()
+        };
+
+        // All 3 authorizations are valid
+        assert!(is_valid(&signed));
+
+        // An authorization from an unrelated key is invalid
+        let invalid_authorization = sign_tx(&"garbage", &extra_keys[0]);
+
+        // 2 of 3 authorizations are valid
+        let mut tx = signed.clone();
+        tx.account_keys[0].authorization = invalid_authorization.clone();
+        assert!(is_valid(&tx));
+
+        // Only 1 of 3 authorizations is valid
+        tx.account_keys[1].authorization = invalid_authorization;
+        assert!(!is_valid(&tx));
+
+        // Without a threshold, all the authorizations are required
+        let mut unsigned =
+            unsigned_validator_account_tx("validator", &validator_wallet);
+        unsigned.account_keys = extra_keys
+            .iter()
+            .map(|sk| StringEncoded::new(sk.ref_to()))
+            .collect();
+        let mut tx = sign_validator_account_tx(
+            unsigned,
+            &validator_wallet,
+            &extra_keys,
+        );
+        assert!(is_valid(&tx));
+        tx.account_keys[0].authorization = sign_tx(&"garbage", &extra_keys[0]);
+        assert!(!is_valid(&tx));
+
+        // A repeated key can't be counted twice towards the threshold
+        let mut unsigned =
+            unsigned_validator_account_tx("validator", &validator_wallet);
+        unsigned.threshold = Some(2);
+        unsigned.account_keys =
+            vec![StringEncoded::new(extra_keys[0].ref_to()); 2];
+        let tx =
+            sign_validator_account_tx(unsigned, &validator_wallet, &extra_keys);
+        assert!(!is_valid(&tx));
+        let mut unsigned =
+            unsigned_validator_account_tx("validator", &validator_wallet);
+        unsigned.account_keys =
+            vec![StringEncoded::new(validator_wallet.account_key.ref_to())];
+        let tx = sign_validator_account_tx(
+            unsigned,
+            &validator_wallet,
+            &[validator_wallet.account_key.clone()],
+        );
+        assert!(!is_valid(&tx));
+
+        // The number of keys must fit their `u8` indices and threshold
+        let many_keys: Vec<_> = (0..MAX_VALIDATOR_ACCOUNT_KEYS as u64)
+            .map(|seed| common_sk_from_simple_seed(100 + seed))
+            .collect();
+        let mut unsigned =
+            unsigned_validator_account_tx("validator", &validator_wallet);
+        unsigned.threshold = Some(1);
+        unsigned.account_keys = many_keys
+            .iter()
+            .map(|sk| StringEncoded::new(sk.ref_to()))
+            .collect();
+        let tx =
+            sign_validator_account_tx(unsigned, &validator_wallet, &many_keys);
+        assert!(!is_valid(&tx));
+    }
+
+    /// Test denominating transfers of a token with `0` denomination.
+    #[test]
+    fn test_denominate_zero_denom_token() {
+        let tokens = tokens(&[("zero", 0)]);
+        let source = common_sk_from_simple_seed(0);
+
+        // Integer amounts are accepted
+        let validated = transfer_tx("zero", &source, "target", "10")
+            .denominate(&tokens)
+            .unwrap();
+        assert_eq!(validated.amount.denom.0, 0);
+        assert_eq!(validated.amount.amount, token::Amount::from_u64(10));
+
+        // Fractional amounts are rejected
+        let err = transfer_tx("zero", &source, "target", "10.5")
+            .denominate(&tokens)
+            .unwrap_err();
+        assert!(err.to_string().contains("decimal places"));
+    }
+
+    /// Test that the displayed amounts of validated bonds and transfers
+    /// round-trip to the original input.
+    #[test]
+    fn test_display_amount_round_trip() {
+        let tokens = tokens(&[("nam", NATIVE_MAX_DECIMAL_PLACES)]);
+        let source = common_sk_from_simple_seed(0);
+
+        for input in ["0", "1", "10", "1.5", "0.000001", "123456.789"] {
+            let amount = DenominatedAmount::from_str(input).unwrap();
+
+            let bond = BondTx::<Unvalidated> {
+                source: AliasOrPk::Alias(Alias::from("source")),
+                validator: Alias::from("validator"),
+                amount: amount.into(),
+                nonce: None,
+            }
+            .denominate()
+            .unwrap();
+            assert_eq!(bond.amount.denom.0, NATIVE_MAX_DECIMAL_PLACES);
+            assert_eq!(bond.display_amount(), amount);
+            assert_eq!(bond.display_amount().to_string(), input);
+
+            let transfer = transfer_tx("nam", &source, "target", input)
+                .denominate(&tokens)
+                .unwrap();
+            assert_eq!(transfer.amount.denom.0, NATIVE_MAX_DECIMAL_PLACES);
+            assert_eq!(transfer.display_amount(), amount);
+            assert_eq!(transfer.display_amount().to_string(), input);
+        }
+    }
+
+    /// Test that the transfer from the source of a genesis validator is made
+    /// in the given native token.
+    #[test]
+    fn test_init_validator_native_token_alias() {
+        let tmp = tempfile::tempdir().unwrap();
+        let mut source_wallet = CliWalletUtils::new(tmp.path().to_path_buf());
+        let source_key = common_sk_from_simple_seed(0);
+        source_wallet
+            .insert_keypair(
+                "source".to_string(),
+                true,
+                source_key.clone(),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        let validator_wallet = validator_wallet(1);
+
+        let txs = init_validator(
+            GenesisValidatorData {
+                source_key,
+                alias: Alias::from("validator"),
+                native_token_alias: Alias::from("XAN"),
+                commission_rate: Dec::new(5, 2).unwrap(),
+                max_commission_rate_change: Dec::new(1, 2).unwrap(),
+                net_address: SocketAddr::from(([127, 0, 0, 1], 26656)),
+                transfer_from_source_amount: DenominatedAmount::from_str("10")
+                    .unwrap(),
+                self_bond_amount: DenominatedAmount::from_str("1").unwrap(),
+                email: "null@null.net".to_string(),
+                description: None,
+                website: None,
+                discord_handle: None,
+            },
+            &mut source_wallet,
+            &validator_wallet,
+        )
+        .unwrap();
+        let transfer = &txs.transfer.unwrap()[0].data;
+        assert_eq!(transfer.token, Alias::from("XAN"));
+        assert_eq!(transfer.target, Alias::from("validator"));
+    }
+
+    /// Test that a validated bond converts into a runtime bond tx with the
+    /// given addresses and the denominated amount.
+    #[test]
+    fn test_bond_to_core_bond() {
+        use namada::types::address::testing::{
+            established_address_1, established_address_2,
+        };
+
+        let bond = BondTx::<Unvalidated> {
+            source: AliasOrPk::Alias(Alias::from("source")),
+            validator: Alias::from("validator"),
+            amount: DenominatedAmount::from_str("12.5").unwrap().into(),
+            nonce: None,
+        }
+        .denominate()
+        .unwrap();
+        let core_bond =
+            bond.to_core_bond(established_address_1(), established_address_2());
+        assert_eq!(
+            core_bond,
+            pos::Bond {
+                validator: established_address_2(),
+                amount: token::Amount::from(12_500_000),
+                source: Some(established_address_1()),
+            }
+        );
+    }
+
+    /// Test that bonds of all the available balance are resolved to the
+    /// source's remaining balance, so that a second such bond from the same
+    /// source has nothing left to bond.
+    #[test]
+    fn test_validate_all_available_bonds() {
+        let templates = localnet_templates();
+        let source_sk = common_sk_from_simple_seed(0);
+        let source = StringEncoded::new(source_sk.ref_to());
+        let validator_accounts = BTreeMap::from([
+            (
+                Alias::from("validator-1"),
+                common_sk_from_simple_seed(1).ref_to(),
+            ),
+            (
+                Alias::from("validator-2"),
+                common_sk_from_simple_seed(2).ref_to(),
+            ),
+        ]);
+        let native_token = &templates.parameters.parameters.native_token;
+        let balance = |amount: &str| {
+            DenominatedAmount::from_str(amount)
+                .unwrap()
+                .increase_precision(NATIVE_MAX_DECIMAL_PLACES.into())
+                .unwrap()
+        };
+        let mut balances = BTreeMap::from([(
+            native_token.clone(),
+            TokenBalancesForValidation {
+                token: native_token.clone(),
+                aliases: BTreeMap::new(),
+                pks: TokenBalances(BTreeMap::from([(
+                    source.clone(),
+                    balance("100"),
+                )])),
+            },
+        )]);
+        let bond = |validator: &str, amount: &str| {
+            bond_tx::<Unvalidated>(
+                AliasOrPk::PublicKey(source.clone()),
+                validator,
+                BondAmount::from_str(amount).unwrap(),
+            )
+            .sign(&source_sk)
+        };
+        let mut validate = |tx: SignedBondTx| {
+            validate_bond(
+                tx,
+                &mut balances,
+                &BTreeMap::new(),
+                &validator_accounts,
+                &templates.parameters,
+            )
+        };
+
+        // The "all" keyword round-trips through TOML
+        let tx = bond("validator-1", "all");
+        assert_eq!(tx.data.amount, BondAmount::AllAvailable);
+        let toml = toml::to_string(&tx).unwrap();
+        assert!(toml.contains("amount = \"all\""), "{toml}");
+        assert_eq!(toml::from_str::<SignedBondTx>(&toml).unwrap(), tx);
+
+        // A fixed bond is deducted first, then the rest is all bonded
+        assert!(validate(bond("validator-1", "10")).is_some());
+        let validated = validate(tx).unwrap();
+        assert_eq!(validated.amount, balance("90"));
+        assert_eq!(validated.display_amount().to_string(), "90");
+
+        // There's nothing left for another bond of all the available balance
+        assert!(validate(bond("validator-2", "all")).is_none());
+        assert!(balances[native_token].pks.0.get(&source).is_none());
+
+        // An unresolved bond of all the available balance can't be
+        // denominated without the balance
+        assert!(bond("validator-2", "all").data.denominate().is_err());
+    }
+
+    /// Test the detection of self-bonds with a source alias or PK.
+    #[test]
+    fn test_bond_is_self_bond() {
+        let validator_sk = common_sk_from_simple_seed(0);
+        let other_sk = common_sk_from_simple_seed(1);
+        let validator_accounts =
+            BTreeMap::from([(Alias::from("validator"), validator_sk.ref_to())]);
+        let bond = |source: AliasOrPk| {
+            bond_tx::<Unvalidated>(
+                source,
+                "validator",
+                BondAmount::from_str("1").unwrap(),
+            )
+        };
+
+        // Alias-based self-bonds don't need the validator accounts
+        let self_bond = bond(AliasOrPk::Alias(Alias::from("validator")));
+        assert!(self_bond.is_self_bond(&validator_accounts));
+        assert!(self_bond.is_self_bond(&BTreeMap::new()));
+        let delegation = bond(AliasOrPk::Alias(Alias::from("albert")));
+        assert!(!delegation.is_self_bond(&validator_accounts));
+
+        // PK-based self-bonds are resolved against the validator accounts
+        let self_bond = bond(AliasOrPk::PublicKey(StringEncoded::new(
+            validator_sk.ref_to(),
+        )));
+        assert!(self_bond.is_self_bond(&validator_accounts));
+        assert!(!self_bond.is_self_bond(&BTreeMap::new()));
+        let delegation =
+            bond(AliasOrPk::PublicKey(StringEncoded::new(other_sk.ref_to())));
+        assert!(!delegation.is_self_bond(&validator_accounts));
+    }
+
+    /// Test that the builder adds each tx to the matching list of txs.
+    #[test]
+    fn test_transactions_builder() {
+        let validator_wallet = validator_wallet(0);
+        let source_sk = common_sk_from_simple_seed(100);
+        let albert = SignedEstablishedAccountTx {
+            alias: Alias::from("albert"),
+            vp: "vp_user".to_string(),
+            public_key: None,
+            storage: BTreeMap::new(),
+        };
+        let validator = sign_validator_account_tx(
+            unsigned_validator_account_tx("validator", &validator_wallet),
+            &validator_wallet,
+            &[],
+        );
+        let transfer = |target: &str| {
+            transfer_tx("nam", &source_sk, target, "1").sign(&source_sk)
+        };
+        let bond = BondTx::<Unvalidated> {
+            source: AliasOrPk::Alias(Alias::from("albert")),
+            validator: Alias::from("validator"),
+            amount: DenominatedAmount::from_str("1").unwrap().into(),
+            nonce: None,
+        }
+        .sign(&source_sk);
+
+        let txs = TransactionsBuilder::<Unvalidated>::new()
+            .add_transfer(transfer("albert"))
+            .add_established(albert.clone())
+            .add_validator(validator.clone())
+            .add_bond(bond.clone())
+            .add_transfer(transfer("validator"))
+            .build();
+        assert_eq!(
+            txs,
+            Transactions {
+                established_account: Some(vec![albert]),
+                validator_account: Some(vec![validator]),
+                transfer: Some(vec![transfer("albert"), transfer("validator")]),
+                bond: Some(vec![bond]),
+            }
+        );
+
+        // The kinds of txs that weren't added are left empty
+        let txs = TransactionsBuilder::<Unvalidated>::new()
+            .add_transfer(transfer("albert"))
+            .build();
+        assert!(txs.established_account.is_none());
+        assert!(txs.validator_account.is_none());
+        assert!(txs.bond.is_none());
+        assert_eq!(
+            TransactionsBuilder::<Unvalidated>::default().build(),
+            Transactions::default()
+        );
+    }
+
+    /// Test that a self-bond is only valid when signed with the validator's
+    /// account key.
+    #[test]
+    fn test_validate_self_bond_signer() {
+        let templates = localnet_templates();
+        let validator_wallet = validator_wallet(0);
+        let validator = Alias::from("validator");
+        let native_token = &templates.parameters.parameters.native_token;
+        let validator_accounts = BTreeMap::from([(
+            validator.clone(),
+            validator_wallet.account_key.ref_to(),
+        )]);
+        // An established account with a clashing alias must not be used to
+        // verify a self-bond
+        let established_accounts = BTreeMap::from([(
+            validator.clone(),
+            Some(validator_wallet.consensus_key.ref_to()),
+        )]);
+        let mut balances: BTreeMap<Alias, TokenBalancesForValidation> =
+            BTreeMap::from([(
+                native_token.clone(),
+                TokenBalancesForValidation {
+                    token: native_token.clone(),
+                    aliases: BTreeMap::from([(
+                        validator.clone(),
+                        DenominatedAmount::from_str("100")
+                            .unwrap()
+                            .increase_precision(
+                                NATIVE_MAX_DECIMAL_PLACES.into(),
+                            )
+                            .unwrap(),
+                    )]),
+                    pks: TokenBalances(BTreeMap::new()),
+                },
+            )]);
+        let self_bond = BondTx::<Unvalidated> {
+            source: AliasOrPk::Alias(validator.clone()),
+            validator: validator.clone(),
+            amount: DenominatedAmount::from_str("10").unwrap().into(),
+            nonce: None,
+        };
+        let mut validate = |tx: SignedBondTx| {
+            validate_bond(
+                tx,
+                &mut balances,
+                &established_accounts,
+                &validator_accounts,
+                &templates.parameters,
+            )
+        };
+
+        // Signed with a validator key other than the account key
+        let wrong_signer =
+            self_bond.clone().sign(&validator_wallet.consensus_key);
+        assert!(validate(wrong_signer).is_none());
+
+        // Signed with the account key
+        let signed = sign_self_bond_tx(self_bond, &validator_wallet);
+        assert!(validate(signed).is_some());
+    }
+
+    /// Test iterating the validated txs, which are empty without any txs.
+    #[test]
+    fn test_iter_validated_txs() {
+        let txs = Transactions::<Validated>::default();
+        assert_eq!(txs.iter_established().count(), 0);
+        assert_eq!(txs.iter_validators().count(), 0);
+        assert_eq!(txs.iter_transfers().count(), 0);
+        assert_eq!(txs.iter_bonds().count(), 0);
+
+        // Empty txs are also iterated as empty
+        let txs = Transactions::<Validated> {
+            established_account: Some(vec![]),
+            validator_account: Some(vec![]),
+            transfer: Some(vec![]),
+            bond: Some(vec![]),
+        };
+        assert_eq!(txs.iter_established().count(), 0);
+        assert_eq!(txs.iter_validators().count(), 0);
+        assert_eq!(txs.iter_transfers().count(), 0);
+        assert_eq!(txs.iter_bonds().count(), 0);
+
+        let validator_wallet = validator_wallet(0);
+        let validator = sign_validator_account_tx(
+            unsigned_validator_account_tx("validator", &validator_wallet),
+            &validator_wallet,
+            &[],
+        );
+        let established = SignedEstablishedAccountTx {
+            alias: Alias::from("albert"),
+            vp: "vp_user".to_string(),
+            public_key: None,
+            storage: BTreeMap::new(),
+        };
+        let source_sk = common_sk_from_simple_seed(1);
+        let tokens = tokens(&[("tok", 6)]);
+        let transfer = transfer_tx("tok", &source_sk, "albert", "1")
+            .denominate(&tokens)
+            .unwrap();
+        let bond = BondTx::<Unvalidated> {
+            source: AliasOrPk::Alias(Alias::from("albert")),
+            validator: Alias::from("validator"),
+            amount: DenominatedAmount::from_str("1").unwrap().into(),
+            nonce: None,
+        }
+        .denominate()
+        .unwrap();
+        let txs = Transactions::<Validated> {
+            established_account: Some(vec![established.clone()]),
+            validator_account: Some(vec![validator.clone()]),
+            transfer: Some(vec![transfer.clone(), transfer.clone()]),
+            bond: Some(vec![bond.clone()]),
+        };
+        assert_eq!(txs.iter_established().collect::<Vec<_>>(), [&established]);
+        assert_eq!(txs.iter_validators().collect::<Vec<_>>(), [&validator]);
+        assert_eq!(
+            txs.iter_transfers().collect::<Vec<_>>(),
+            [&transfer, &transfer]
+        );
+        assert_eq!(txs.iter_bonds().collect::<Vec<_>>(), [&bond]);
+    }
+
+    /// Test that the voting powers of validators are limited by the cap.
+    #[test]
+    fn test_capped_voting_powers() {
+        let bond = |validator: &str, amount: u64| {
+            bond_tx::<Validated>(
+                AliasOrPk::Alias(Alias::from("source")),
+                validator,
+                DenominatedAmount::native(token::Amount::native_whole(amount)),
+            )
+        };
+        let votes_per_token = Dec::new(1, 3).unwrap();
+        let validator_wallet = validator_wallet(0);
+        let txs = Transactions::<Validated> {
+            validator_account: Some(vec![sign_validator_account_tx(
+                unsigned_validator_account_tx("validator-3", &validator_wallet),
+                &validator_wallet,
+                &[],
+            )]),
+            bond: Some(vec![
+                bond("validator-1", 1_000),
+                bond("validator-1", 1_000),
+                bond("validator-2", 10),
+            ]),
+            ..Default::default()
+        };
+        let power = |amount: u64| {
+            namada::ledger::pos::into_tm_voting_power(
+                votes_per_token,
+                token::Amount::native_whole(amount),
+            ) as u64
+        };
+
+        let uncapped = BTreeMap::from([
+            (Alias::from("validator-1"), power(2_000)),
+            (Alias::from("validator-2"), power(10)),
+            (Alias::from("validator-3"), 0),
+        ]);
+        assert_eq!(
+            txs.capped_voting_powers(votes_per_token, None).unwrap(),
+            uncapped
+        );
+
+        // Only the validator exceeding the cap is limited
+        let cap = power(100);
+        let capped = BTreeMap::from([
+            (Alias::from("validator-1"), cap),
+            (Alias::from("validator-2"), power(10)),
+            (Alias::from("validator-3"), 0),
+        ]);
+        assert_eq!(
+            txs.capped_voting_powers(votes_per_token, Some(cap))
+                .unwrap(),
+            capped
+        );
+    }
+
+    /// Test that the voting power check rejects bonds to a validator that sum
+    /// up over the maximum amount.
+    #[test]
+    fn test_voting_power_bonds_overflow() {
+        let bond = |validator: &str, amount: token::Amount| {
+            bond_tx::<Validated>(
+                AliasOrPk::Alias(Alias::from("source")),
+                validator,
+                DenominatedAmount::native(amount),
+            )
+        };
+        let votes_per_token = Dec::new(1, 3).unwrap();
+
+        let mut txs = Transactions::<Validated> {
+            bond: Some(vec![bond(
+                "validator-1",
+                token::Amount::native_whole(1_000),
+            )]),
+            ..Default::default()
+        };
+        assert!(
+            txs.has_validator_with_positive_voting_power(votes_per_token)
+                .unwrap()
+        );
+
+        // Bonds to a validator summing up over the maximum amount are an error
+        let bonds = txs.bond.as_mut().unwrap();
+        bonds.push(bond(
+            "validator-2",
+            token::Amount::max() - token::Amount::from_u64(1),
+        ));
+        bonds.push(bond("validator-2", token::Amount::from_u64(2)));
+        assert!(
+            txs.has_validator_with_positive_voting_power(votes_per_token)
+                .is_err()
+        );
+    }
+
+    /// Test finding validators that are only bonded to by delegations.
+    #[test]
+    fn test_validators_without_self_bond() {
+        let validator_tx = |alias: &str, seed: u64| {
+            let validator_wallet = validator_wallet(seed);
+            sign_validator_account_tx(
+                unsigned_validator_account_tx(alias, &validator_wallet),
+                &validator_wallet,
+                &[],
+            )
+        };
+        let validators = vec![
+            validator_tx("validator-1", 0),
+            validator_tx("validator-2", 1),
+        ];
+        let bond = |source: AliasOrPk, validator: &str| {
+            bond_tx::<Validated>(
+                source,
+                validator,
+                DenominatedAmount::native(token::Amount::native_whole(1)),
+            )
+        };
+        let delegator =
+            StringEncoded::new(common_sk_from_simple_seed(100).ref_to());
+
+        let mut txs = Transactions::<Validated> {
+            validator_account: Some(validators.clone()),
+            bond: Some(vec![
+                bond(
+                    AliasOrPk::Alias(Alias::from("validator-1")),
+                    "validator-1",
+                ),
+                bond(AliasOrPk::PublicKey(delegator), "validator-2"),
+            ]),
+            ..Default::default()
+        };
+        assert_eq!(
+            txs.validators_without_self_bond(),
+            vec![Alias::from("validator-2")]
+        );
+
+        // A self-bond from the validator's account key
+        txs.bond.as_mut().unwrap().push(bond(
+            AliasOrPk::PublicKey(validators[1].account_key.pk.clone()),
+            "validator-2",
+        ));
+        assert!(txs.validators_without_self_bond().is_empty());
+    }
+
+    /// Test that the required VPs are collected from all the accounts.
+    #[test]
+    fn test_required_vps() {
+        let validator_wallet = validator_wallet(0);
+        let validator_tx = sign_validator_account_tx(
+            unsigned_validator_account_tx("validator", &validator_wallet),
+            &validator_wallet,
+            &[],
+        );
+        let established_tx =
+            |alias: &str, vp: &str| SignedEstablishedAccountTx {
+                alias: Alias::from(alias),
+                vp: vp.to_string(),
+                public_key: None,
+                storage: BTreeMap::new(),
+            };
+        let txs = Transactions::<Unvalidated> {
+            established_account: Some(vec![
+                established_tx("account-1", "vp_user"),
+                established_tx("account-2", "vp_user"),
+            ]),
+            validator_account: Some(vec![validator_tx]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            txs.required_vps(),
+            BTreeSet::from(["vp_user".to_string(), "vp_validator".to_string()])
+        );
+        assert!(
+            Transactions::<Unvalidated>::default()
+                .required_vps()
+                .is_empty()
+        );
+    }
+
+    /// Test that validating bonds without parameters yields a specific error.
+    #[test]
+    fn test_validate_bonds_without_parameters() {
+        let source_sk = common_sk_from_simple_seed(0);
+        let bond = BondTx::<Unvalidated> {
+            source: AliasOrPk::PublicKey(StringEncoded::new(
+                source_sk.ref_to(),
+            )),
+            validator: Alias::from("validator"),
+            amount: DenominatedAmount::from_str("10").unwrap().into(),
+            nonce: None,
+        }
+        .sign(&source_sk);
+        let txs = Transactions::<Unvalidated> {
+            bond: Some(vec![bond]),
+            ..Default::default()
+        };
+
+        let errors =
+            validate(txs, None, None, &tokens(&[]), None, &Default::default())
+                .map_err(validation_errors)
+                .unwrap_err();
+        assert_eq!(errors, vec![ValidationError::MissingParametersForBonds]);
+    }
+
+    /// Test that transfers without balances are reported with a single error
+    /// rather than a missing balance of each transfer.
+    #[test]
+    fn test_validate_transfers_without_balances() {
+        let source_sk = common_sk_from_simple_seed(0);
+        let txs = Transactions::<Unvalidated> {
+            transfer: Some(vec![
+                transfer_tx("nam", &source_sk, "albert", "10").sign(&source_sk),
+                transfer_tx("nam", &source_sk, "bertha", "5").sign(&source_sk),
+            ]),
+            ..Default::default()
+        };
+        let tokens = tokens(&[("nam", NATIVE_MAX_DECIMAL_PLACES)]);
+
+        let validate = |txs| {
+            validate(txs, None, None, &tokens, None, &Default::default())
+                .map_err(validation_errors)
+        };
+        let errors = validate(txs).unwrap_err();
+        assert_eq!(errors, vec![ValidationError::BalancesRequired]);
+
+        // Without any transfers or bonds, balances are not required
+        let txs = Transactions::<Unvalidated>::default();
+        assert!(validate(txs).is_ok());
+    }
+
+    /// Test that txs signed with a disallowed signature scheme are rejected.
+    #[test]
+    fn test_validate_signature_schemes() {
+        let ed25519_sk = common_sk_from_simple_seed(0);
+        let secp256k1_sk =
+            common::SecretKey::Secp256k1(gen_keypair::<secp256k1::SigScheme>());
+        let transfer = |sk: &common::SecretKey| {
+            transfer_tx("nam", sk, "albert", "10").sign(sk)
+        };
+        let txs = Transactions::<Unvalidated> {
+            transfer: Some(vec![transfer(&ed25519_sk)]),
+            ..Default::default()
+        };
+
+        assert!(validate_signature_schemes(&txs, ALL_SIGNATURE_SCHEMES));
+        assert!(validate_signature_schemes(&txs, &[SchemeType::Ed25519]));
+        assert!(!validate_signature_schemes(&txs, &[SchemeType::Secp256k1]));
+
+        // An Ed25519-only chain rejects a Secp256k1 signature
+        let txs = Transactions::<Unvalidated> {
+            transfer: Some(vec![
+                transfer(&ed25519_sk),
+                transfer(&secp256k1_sk),
+            ]),
+            ..Default::default()
+        };
+        assert!(validate_signature_schemes(&txs, ALL_SIGNATURE_SCHEMES));
+        assert!(!validate_signature_schemes(&txs, &[SchemeType::Ed25519]));
+
+        // The rejection is reported as invalid txs
+        let tokens = tokens(&[("nam", NATIVE_MAX_DECIMAL_PLACES)]);
+        let errors = validate(
+            txs,
+            None,
+            None,
+            &tokens,
+            None,
+            &ValidationOptions {
+                allowed_schemes: &[SchemeType::Ed25519],
+                ..Default::default()
+            },
+        )
+        .map_err(validation_errors)
+        .unwrap_err();
+        assert!(errors.contains(&ValidationError::InvalidTxs), "{errors:?}");
+    }
+
+    /// Test that bonds from PK sources are only valid when their balance is
+    /// proven by the given balance proofs.
+    #[test]
+    fn test_validate_bond_balance_proofs() {
+        let ValidationFixture {
+            templates,
+            native_token,
+            validator_tx,
+            source_sk,
+            source,
+            balance,
+            balances,
+            vps,
+        } = validation_fixture();
+        let txs = Transactions::<Unvalidated> {
+            validator_account: Some(vec![validator_tx]),
+            bond: Some(vec![
+                BondTx::<Unvalidated> {
+                    source: AliasOrPk::PublicKey(source.clone()),
+                    validator: Alias::from("validator"),
+                    amount: DenominatedAmount::from_str("10").unwrap().into(),
+                    nonce: None,
+                }
+                .sign(&source_sk),
+            ]),
+            ..Default::default()
+        };
+        let validate = |proofs: &BalanceProofs| {
+            validate(
+                txs.clone(),
+                Some(&vps),
+                Some(&balances),
+                &templates.tokens,
+                Some(&templates.parameters),
+                &ValidationOptions {
+                    balance_proofs: Some(proofs),
+                    ..Default::default()
+                },
+            )
+            .map_err(validation_errors)
+        };
+
+        // A tree with the source's balance and another leaf
+        let leaf =
+            BalanceProof::leaf_hash(&native_token, &source, balance.amount);
+        let sibling = Hash::sha256("other balance");
+        let proof = BalanceProof {
+            path: vec![(false, sibling)],
+        };
+        let mut proofs = BalanceProofs {
+            root: BalanceProof::node_hash(&leaf, &sibling),
+            proofs: BTreeMap::from([(
+                native_token.clone(),
+                BTreeMap::from([(source.clone(), proof)]),
+            )]),
+        };
+        assert!(validate(&proofs).is_ok());
+
+        // A proof that doesn't match the root is rejected
+        proofs.root = BalanceProof::node_hash(&sibling, &leaf);
+        assert_eq!(
+            validate(&proofs).unwrap_err(),
+            vec![ValidationError::InvalidTxs]
+        );
+    }
+
+    /// Test that transfers are only valid when their source's balance is
+    /// proven by the given balance proofs, and that the hashes of leaves and
+    /// nodes of the proofs are domain separated.
+    #[test]
+    fn test_validate_transfer_balance_proofs() {
+        let ValidationFixture {
+            templates,
+            native_token,
+            validator_tx,
+            source_sk,
+            source,
+            balance,
+            balances,
+            vps,
+        } = validation_fixture();
+        let txs = Transactions::<Unvalidated> {
+            validator_account: Some(vec![validator_tx]),
+            transfer: Some(vec![
+                transfer_tx(
+                    &native_token.to_string(),
+                    &source_sk,
+                    "validator",
+                    "10",
+                )
+                .sign(&source_sk),
+            ]),
+            ..Default::default()
+        };
+        let validate = |proofs: &BalanceProofs| {
+            validate(
+                txs.clone(),
+                Some(&vps),
+                Some(&balances),
+                &templates.tokens,
+                Some(&templates.parameters),
+                &ValidationOptions {
+                    balance_proofs: Some(proofs),
+                    ..Default::default()
+                },
+            )
+            .map_err(validation_errors)
+        };
+
+        let leaf =
+            BalanceProof::leaf_hash(&native_token, &source, balance.amount);
+        let sibling = Hash::sha256("other balance");
+        let mut proofs = BalanceProofs {
+            root: BalanceProof::node_hash(&leaf, &sibling),
+            proofs: BTreeMap::from([(
+                native_token.clone(),
+                BTreeMap::from([(
+                    source.clone(),
+                    BalanceProof {
+                        path: vec![(false, sibling)],
+                    },
+                )]),
+            )]),
+        };
+        assert!(validate(&proofs).is_ok());
+
+        // An unproven balance is rejected
+        proofs.root = sibling;
+        assert_eq!(
+            validate(&proofs).unwrap_err(),
+            vec![ValidationError::InvalidTxs]
+        );
+
+        // A node is hashed with its domain separation tag before the
+        // children, which tells it apart from a leaf
+        assert_ne!(BalanceProof::NODE_PREFIX, BalanceProof::LEAF_PREFIX);
+        assert_eq!(
+            BalanceProof::node_hash(&leaf, &sibling),
+            Hash::sha256([&[1_u8][..], &leaf.0[..], &sibling.0[..]].concat())
+        );
+    }
+
+    /// Test that signed txs are versioned, that txs read from files without
+    /// a version can still be verified and that unknown versions are
+    /// rejected.
+    #[test]
+    fn test_signed_format_version() {
+        let source_sk = common_sk_from_simple_seed(0);
+        let signed =
+            transfer_tx("nam", &source_sk, "albert", "10").sign(&source_sk);
+        assert_eq!(signed.version, SIGNED_FORMAT_VERSION);
+        assert!(signed.verify_sig().is_ok());
+
+        // A tx written without a version is read as the legacy version,
+        // which is signed without a domain separation tag
+        let untagged = transfer_tx("nam", &source_sk, "albert", "10")
+            .sign_with_version(&source_sk, SIGNED_FORMAT_VERSION_UNTAGGED);
+        assert!(untagged.verify_sig().is_ok());
+        let without_version = |signed: &SignedTransferTx| {
+            let toml =
+                String::from_utf8(toml::to_vec(signed).unwrap()).unwrap();
+            let legacy_toml: String = toml
+                .lines()
+                .filter(|line| !line.starts_with("version"))
+                .map(|line| format!("{line}\n"))
+                .collect();
+            toml::from_str::<SignedTransferTx>(&legacy_toml).unwrap()
+        };
+        let legacy = without_version(&untagged);
+        assert_eq!(legacy.version, SIGNED_FORMAT_VERSION_LEGACY);
+        assert_eq!(legacy.data, signed.data);
+        assert!(legacy.verify_sig().is_ok());
+
+        // A tagged tx can't be verified without its version
+        assert!(without_version(&signed).verify_sig().is_err());
+
+        // An unknown version is rejected
+        let bumped = SignedTransferTx {
+            version: SIGNED_FORMAT_VERSION + 1,
+            ..signed
+        };
+        assert!(bumped.verify_sig().is_err());
+    }
+
+    /// Test that txs signed in a format version before the current one are
+    /// only valid when they're explicitly allowed.
+    #[test]
+    fn test_validate_legacy_signatures_opt_in() {
+        let source_sk = common_sk_from_simple_seed(0);
+        let transfer = |version| {
+            transfer_tx("nam", &source_sk, "albert", "10")
+                .sign_with_version(&source_sk, version)
+        };
+        for version in [
+            SIGNED_FORMAT_VERSION_LEGACY,
+            SIGNED_FORMAT_VERSION_UNTAGGED,
+            SIGNED_FORMAT_VERSION_TAGGED,
+        ] {
+            let txs = Transactions::<Unvalidated> {
+                transfer: Some(vec![transfer(version)]),
+                ..Default::default()
+            };
+            assert!(!validate_signed_format_versions(&txs));
+        }
+        let txs = Transactions::<Unvalidated> {
+            transfer: Some(vec![transfer(SIGNED_FORMAT_VERSION)]),
+            ..Default::default()
+        };
+        assert!(validate_signed_format_versions(&txs));
+
+        // The localnet txs are signed without a version, so they must be
+        // opted into
+        let templates = localnet_templates();
+        let templates_dir =
+            std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .parent()
+                .unwrap()
+                .join("genesis/localnet");
+        let txs = templates::read_transactions(
+            &templates_dir.join(templates::TRANSACTIONS_FILE_NAME),
+        )
+        .unwrap();
+        let validate = |options: &ValidationOptions<'_>| {
+            validate(
+                txs.clone(),
+                Some(&templates.vps),
+                Some(&templates.balances),
+                &templates.tokens,
+                Some(&templates.parameters),
+                options,
+            )
+            .map_err(validation_errors)
+        };
+        let errors = validate(&Default::default()).unwrap_err();
+        assert!(errors.contains(&ValidationError::InvalidTxs), "{errors:?}");
+        assert!(validate(&ValidationOptions::with_legacy_signatures()).is_ok());
+    }
+
+    /// Test that the serial and parallel signature verification report the
+    /// same errors for the same txs.
+    #[test]
+    fn test_verify_signatures_serial_parallel() {
+        let validator_accounts: Vec<_> = (0..4)
+            .map(|ix| {
+                let validator_wallet = validator_wallet(ix * 10);
+                let mut tx = sign_validator_account_tx(
+                    unsigned_validator_account_tx(
+                        &format!("validator-{ix}"),
+                        &validator_wallet,
+                    ),
+                    &validator_wallet,
+                    &[],
+                );
+                // Invalidate the consensus key authorization of every other
+                // tx
+                if ix % 2 == 1 {
+                    tx.consensus_key.authorization =
+                        sign_tx(&"garbage", &validator_wallet.consensus_key);
+                }
+                tx
+            })
+            .collect();
+        let transfers: Vec<_> = (0..6)
+            .map(|ix| {
+                let source_sk = common_sk_from_simple_seed(100 + ix);
+                let mut tx = transfer_tx("nam", &source_sk, "albert", "10")
+                    .sign(&source_sk);
+                // Invalidate the signature of every third tx
+                if ix % 3 == 2 {
+                    tx.data.amount = DenominatedAmount::from_str("11").unwrap();
+                }
+                tx
+            })
+            .collect();
+
+        let serial = verify_signatures_serial(&validator_accounts, &transfers);
+        let parallel =
+            verify_signatures_parallel(&validator_accounts, &transfers);
+        assert_eq!(serial, parallel);
+        assert_eq!(
+            serial.validator_account.keys().copied().collect::<Vec<_>>(),
+            vec![1, 3]
+        );
+        assert_eq!(
+            serial.transfer.keys().copied().collect::<Vec<_>>(),
+            vec![2, 5]
+        );
+    }
+
+    /// Test that delegations and self-bonds below the minimum bond amount are
+    /// rejected.
+    #[test]
+    fn test_validate_bond_min_bond() {
+        let mut templates = localnet_templates();
+        templates.parameters.pos_params.min_bond =
+            Some(token::Amount::native_whole(10));
+        let source_sk = common_sk_from_simple_seed(0);
+        let source = StringEncoded::new(source_sk.ref_to());
+        let validator_sk = common_sk_from_simple_seed(1);
+        let validator = Alias::from("validator");
+        let validator_accounts =
+            BTreeMap::from([(validator.clone(), validator_sk.ref_to())]);
+        let balance =
+            DenominatedAmount::native(token::Amount::native_whole(100));
+        let mut balances = BTreeMap::from([(
+            Alias::from("NAM"),
+            TokenBalancesForValidation {
+                token: Alias::from("NAM"),
+                aliases: BTreeMap::from([(validator.clone(), balance)]),
+                pks: TokenBalances(BTreeMap::from([(source.clone(), balance)])),
+            },
+        )]);
+        let mut is_valid =
+            |source: AliasOrPk, sk: &common::SecretKey, amount: &str| {
+                let tx = BondTx::<Unvalidated> {
+                    source,
+                    validator: validator.clone(),
+                    amount: DenominatedAmount::from_str(amount).unwrap().into(),
+                    nonce: None,
+                }
+                .sign(sk);
+                validate_bond(
+                    tx,
+                    &mut balances,
+                    &BTreeMap::new(),
+                    &validator_accounts,
+                    &templates.parameters,
+                )
+                .is_some()
+            };
+
+        // Delegations
+        let delegator = AliasOrPk::PublicKey(source.clone());
+        assert!(!is_valid(delegator.clone(), &source_sk, "9.999999"));
+        assert!(is_valid(delegator.clone(), &source_sk, "10"));
+        assert!(is_valid(delegator, &source_sk, "11"));
+
+        // Self-bonds
+        let self_bond = AliasOrPk::Alias(validator.clone());
+        assert!(!is_valid(self_bond.clone(), &validator_sk, "9.999999"));
+        assert!(is_valid(self_bond.clone(), &validator_sk, "10"));
+        assert!(is_valid(self_bond, &validator_sk, "11"));
+    }
+
+    /// Test parsing an alias or a PK from a string.
+    #[test]
+    fn test_alias_or_pk_from_str() {
+        let pk = common_sk_from_simple_seed(0).ref_to();
+        assert_eq!(
+            AliasOrPk::from_str(&pk.to_string()).unwrap(),
+            AliasOrPk::PublicKey(StringEncoded::new(pk))
+        );
+        assert_eq!(
+            AliasOrPk::from_str("validator-0").unwrap(),
+            AliasOrPk::Alias(Alias::from("validator-0"))
+        );
+        assert_eq!(
+            AliasOrPk::from_str("").unwrap_err(),
+            ParseAliasOrPkError::Empty
+        );
+    }
+
+    /// Test that the TOML value of an alias or a PK is deserialized back into
+    /// the same value, apart from an alias that looks like a PK.
+    #[test]
+    fn test_alias_or_pk_toml_value_round_trip() {
+        let pk = common_sk_from_simple_seed(0).ref_to();
+        let round_trip = |value: &AliasOrPk| {
+            let toml_value = value.to_toml_value();
+            assert_eq!(toml::Value::try_from(value).unwrap(), toml_value);
+            toml_value.try_into::<AliasOrPk>().unwrap()
+        };
+
+        let alias = AliasOrPk::Alias(Alias::from("validator-0"));
+        assert_eq!(round_trip(&alias), alias);
+        assert_eq!(alias.to_toml_value().as_str(), Some("validator-0"));
+
+        let pk_value = AliasOrPk::PublicKey(StringEncoded::new(pk.clone()));
+        assert_eq!(round_trip(&pk_value), pk_value);
+
+        // An alias that is a valid PK encoding is read back as the PK
+        let ambiguous = AliasOrPk::Alias(Alias::from(pk.to_string()));
+        assert_eq!(round_trip(&ambiguous), pk_value);
+    }
+
+    /// Test that transfers with the same token, source and target are netted
+    /// into a single transfer.
+    #[test]
+    fn test_net_transfers() {
+        let source_sk = common_sk_from_simple_seed(0);
+        let other_sk = common_sk_from_simple_seed(1);
+        let txs = Transactions::<Unvalidated> {
+            transfer: Some(vec![
+                transfer_tx("nam", &source_sk, "albert", "10").sign(&source_sk),
+                transfer_tx("nam", &other_sk, "albert", "5").sign(&other_sk),
+                transfer_tx("NAM", &source_sk, "albert", "2.5")
+                    .sign(&source_sk),
+                transfer_tx("nam", &source_sk, "Albert", "0.25")
+                    .sign(&source_sk),
+            ]),
+            ..Default::default()
+        };
+        let netted = txs.net_transfers().unwrap();
+        assert_eq!(
+            netted.transfer,
+            Some(vec![
+                transfer_tx("nam", &source_sk, "albert", "12.75"),
+                transfer_tx("nam", &other_sk, "albert", "5"),
+            ])
+        );
+        assert!(netted.established_account.is_none());
+        assert!(netted.bond.is_none());
+    }
+
+    /// Test that the lint reports each kind of warning.
+    #[test]
+    fn test_lint() {
+        let validator_wallet = validator_wallet(0);
+        let source_sk = common_sk_from_simple_seed(100);
+        let established_account =
+            |alias: &str, public_key: Option<SignedPk>| {
+                SignedEstablishedAccountTx {
+                    alias: Alias::from(alias),
+                    vp: "vp_user".to_string(),
+                    public_key,
+                    storage: BTreeMap::new(),
+                }
+            };
+        let bond = |validator: &str| {
+            bond_tx::<Unvalidated>(
+                AliasOrPk::Alias(Alias::from("albert")),
+                validator,
+                BondAmount::from_str("1").unwrap(),
+            )
+            .sign(&source_sk)
+        };
+        // The lint doesn't check signatures
+        let albert_pk = SignedPk {
+            pk: StringEncoded::new(source_sk.ref_to()),
+            authorization: sign_tx(&"albert", &source_sk),
+        };
+        let valid_txs = Transactions::<Unvalidated> {
+            established_account: Some(vec![established_account(
+                "albert",
+                Some(albert_pk),
+            )]),
+            validator_account: Some(vec![sign_validator_account_tx(
+                unsigned_validator_account_tx("validator", &validator_wallet),
+                &validator_wallet,
+                &[],
+            )]),
+            transfer: Some(vec![
+                transfer_tx("nam", &source_sk, "albert", "1").sign(&source_sk),
+                transfer_tx("nam", &source_sk, "Validator", "1")
+                    .sign(&source_sk),
+            ]),
+            bond: Some(vec![bond("validator")]),
+        };
+        assert!(valid_txs.lint().is_empty());
+
+        // An established account without a public key
+        let mut txs = valid_txs.clone();
+        txs.established_account
+            .as_mut()
+            .unwrap()
+            .push(established_account("bertha", None));
+        assert_eq!(
+            txs.lint(),
+            vec![LintWarning::EstablishedAccountWithoutPk(Alias::from(
+                "bertha"
+            ))]
+        );
+
+        // A transfer to an unknown account
+        let mut txs = valid_txs.clone();
+        txs.transfer.as_mut().unwrap().push(
+            transfer_tx("nam", &source_sk, "christel", "1").sign(&source_sk),
+        );
+        assert_eq!(
+            txs.lint(),
+            vec![LintWarning::OrphanTransferTarget {
+                token: Alias::from("nam"),
+                target: Alias::from("christel"),
+            }]
+        );
+
+        // A bond to an established account that is not a validator
+        let mut txs = valid_txs;
+        txs.bond.as_mut().unwrap().push(bond("albert"));
+        assert_eq!(
+            txs.lint(),
+            vec![LintWarning::UnknownBondValidator {
+                source: AliasOrPk::Alias(Alias::from("albert")),
+                validator: Alias::from("albert"),
+            }]
+        );
+    }
+
+    /// Test that txs are split by the validator that they target.
+    #[test]
+    fn test_partition_by_validator() {
+        let wallet_1 = validator_wallet(0);
+        let wallet_2 = validator_wallet(10);
+        let source_sk = common_sk_from_simple_seed(100);
+        let validator_account = |alias: &str, wallet: &ValidatorWallet| {
+            sign_validator_account_tx(
+                unsigned_validator_account_tx(alias, wallet),
+                wallet,
+                &[],
+            )
+        };
+        let transfer = |target: &str| {
+            transfer_tx("nam", &source_sk, target, "1").sign(&source_sk)
+        };
+        let bond = |source: &str, validator: &str| {
+            bond_tx::<Unvalidated>(
+                AliasOrPk::Alias(Alias::from(source)),
+                validator,
+                BondAmount::from_str("1").unwrap(),
+            )
+            .sign(&source_sk)
+        };
+        let albert = SignedEstablishedAccountTx {
+            alias: Alias::from("albert"),
+            vp: "vp_user".to_string(),
+            public_key: None,
+            storage: BTreeMap::new(),
+        };
+        let txs = Transactions::<Unvalidated> {
+            established_account: Some(vec![albert.clone()]),
+            validator_account: Some(vec![
+                validator_account("validator-1", &wallet_1),
+                validator_account("validator-2", &wallet_2),
+            ]),
+            transfer: Some(vec![
+                transfer("validator-1"),
+                transfer("albert"),
+                transfer("Validator-2"),
+            ]),
+            bond: Some(vec![
+                bond("validator-1", "validator-1"),
+                bond("albert", "validator-2"),
+                bond("albert", "unknown"),
+            ]),
+        };
+
+        let (partition, other) = txs.partition_by_validator();
+        assert_eq!(partition.len(), 2);
+        assert_eq!(
+            partition[&Alias::from("validator-1")],
+            Transactions {
+                established_account: None,
+                validator_account: Some(vec![validator_account(
+                    "validator-1",
+                    &wallet_1
+                )]),
+                transfer: Some(vec![transfer("validator-1")]),
+                bond: Some(vec![bond("validator-1", "validator-1")]),
+            }
+        );
+        assert_eq!(
+            partition[&Alias::from("validator-2")],
+            Transactions {
+                established_account: None,
+                validator_account: Some(vec![validator_account(
+                    "validator-2",
+                    &wallet_2
+                )]),
+                transfer: Some(vec![transfer("Validator-2")]),
+                bond: Some(vec![bond("albert", "validator-2")]),
+            }
+        );
+        assert_eq!(
+            other,
+            Transactions {
+                established_account: Some(vec![albert]),
+                validator_account: None,
+                transfer: Some(vec![transfer("albert")]),
+                bond: Some(vec![bond("albert", "unknown")]),
+            }
+        );
+    }
+
+    /// Test that the source address of a transfer is the implicit address of
+    /// its source public key, using a key from the localnet wallet.
+    #[test]
+    fn test_transfer_source_address() {
+        let source: StringEncoded<common::PublicKey> = StringEncoded::from_str(
+            "tpknam1qz0aphcsrw37j8fy742cjwhphu9jwx7esd3ad4xxtxrkwv07ff63we33t3r",
+        )
+        .unwrap();
+        let tx = TransferTx::<Unvalidated> {
+            token: Alias::from("nam"),
+            source,
+            target: Alias::from("albert"),
+            amount: DenominatedAmount::from_str("1").unwrap(),
+            nonce: None,
+        };
+        let expected =
+            Address::decode("tnam1qp2yqaffsk2wekd8fheu5c0cv3heg8v37vmdd45u")
+                .unwrap();
+        assert_eq!(tx.source_address(), expected);
+    }
+
+    /// Test that a validator account written with the legacy name of the
+    /// `eth_hot_key` is parsed with the key mapped to the `eth_hot_key`.
+    #[test]
+    fn test_parse_legacy_eth_bridge_key() {
+        let validator_wallet = validator_wallet(0);
+        let txs = UnsignedTransactions {
+            validator_account: Some(vec![unsigned_validator_account_tx(
+                "validator",
+                &validator_wallet,
+            )]),
+            ..Default::default()
+        };
+        let current = toml::to_string(&txs).unwrap();
+        assert!(current.contains("eth_hot_key"));
+        assert_eq!(parse_unsigned(current.as_bytes()).unwrap(), txs);
+
+        let legacy = current.replace("eth_hot_key", "eth_bridge_key");
+        assert_eq!(parse_unsigned(legacy.as_bytes()).unwrap(), txs);
+        // The legacy name is not an unknown field
+        assert_eq!(parse_unsigned_strict(legacy.as_bytes()).unwrap(), txs);
+
+        // Only the legacy name is reported by the lint
+        assert!(lint_legacy_fields(current.as_bytes()).unwrap().is_empty());
+        assert_eq!(
+            lint_legacy_fields(legacy.as_bytes()).unwrap(),
+            vec![LintWarning::LegacyFieldName {
+                path: "validator_account[0].eth_bridge_key".to_string(),
+                current: "eth_hot_key".to_string(),
+            }]
+        );
+
+        // Both of the names for the same key are rejected
+        let hot_key = txs.validator_account.as_ref().unwrap()[0]
+            .eth_hot_key
+            .to_string();
+        let both = current.replace(
+            "eth_hot_key",
+            &format!("eth_bridge_key = \"{hot_key}\"\neth_hot_key"),
+        );
+        assert!(parse_unsigned(both.as_bytes()).is_err());
+    }
+
+    /// Test that an established account's initial storage with a duplicated
+    /// key is rejected.
+    #[test]
+    fn test_parse_established_account_duplicate_storage_key() {
+        let parse = |storage: &str| {
+            serde_json::from_str::<UnsignedEstablishedAccountTx>(&format!(
+                r#"{{"alias":"albert","vp":"vp_user","public_key":null,"storage":{storage}}}"#
+            ))
+        };
+
+        let tx = parse(r#"{"a/b":"00","a/c":"01"}"#).unwrap();
+        assert_eq!(tx.storage.len(), 2);
+        assert_eq!(
+            tx.storage[&storage::Key::parse("a/b").unwrap()],
+            HexString("00".to_string())
+        );
+
+        let err = parse(r#"{"a/b":"00","a/b":"01"}"#).unwrap_err();
+        assert!(
+            err.to_string().contains("Duplicate storage key a/b"),
+            "{err}"
+        );
+
+        // Keys written differently that parse to the same key are also
+        // duplicates, e.g. with an address segment in upper case
+        let segment = format!(
+            "#{}",
+            namada::types::address::testing::established_address_1().encode()
+        );
+        let err = parse(&format!(
+            r#"{{"{segment}/a":"00","{}/a":"01"}}"#,
+            segment.to_uppercase()
+        ))
+        .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains(&format!("Duplicate storage key {segment}/a")),
+            "{err}"
+        );
+
+        // The initial storage round-trips through TOML
+        let txs = UnsignedTransactions {
+            established_account: Some(vec![tx]),
+            ..Default::default()
+        };
+        let toml = toml::to_string(&txs).unwrap();
+        assert_eq!(parse_unsigned(toml.as_bytes()).unwrap(), txs);
+    }
+
+    /// Test that an established account with a malformed alias is rejected.
+    #[test]
+    fn test_validate_established_account_alias() {
+        let vps = vps(&["vp_user"]);
+        let is_valid = |alias: &str| {
+            let tx = SignedEstablishedAccountTx {
+                alias: Alias::from(alias),
+                vp: "vp_user".to_string(),
+                public_key: None,
+                storage: BTreeMap::new(),
+            };
+            validate_established_account(
+                &tx,
+                Some(&vps),
+                &mut BTreeSet::new(),
+                &mut BTreeMap::new(),
+            )
+            .is_ok()
+        };
+        assert!(is_valid("albert"));
+        assert!(!is_valid("albert/balance"));
+        assert!(!is_valid("albert\n"));
+        assert!(!is_valid(""));
+    }
+
+    /// Test that the genesis cost estimate counts all the txs.
+    #[test]
+    fn test_estimate_apply_cost() {
+        let validator_wallet = validator_wallet(0);
+        let source_sk = common_sk_from_simple_seed(100);
+        let established_account = |alias: &str| SignedEstablishedAccountTx {
+            alias: Alias::from(alias),
+            vp: "vp_user".to_string(),
+            public_key: None,
+            storage: BTreeMap::new(),
+        };
+        let txs = Transactions::<Unvalidated> {
+            established_account: Some(vec![
+                established_account("albert"),
+                established_account("bertha"),
+            ]),
+            validator_account: Some(vec![sign_validator_account_tx(
+                unsigned_validator_account_tx("validator", &validator_wallet),
+                &validator_wallet,
+                &[],
+            )]),
+            transfer: Some(vec![
+                transfer_tx("nam", &source_sk, "albert", "1").sign(&source_sk),
+                transfer_tx("nam", &source_sk, "bertha", "1").sign(&source_sk),
+                transfer_tx("nam", &source_sk, "validator", "1")
+                    .sign(&source_sk),
+            ]),
+            bond: None,
+        };
+        let cost = txs.estimate_apply_cost();
+        assert_eq!(
+            cost,
+            GenesisCost {
+                established_accounts: 2,
+                validator_accounts: 1,
+                transfers: 3,
+                bonds: 0,
+            }
+        );
+        assert_eq!(cost.accounts(), 3);
+        assert_eq!(cost.txs(), 6);
+        assert_eq!(
+            Transactions::<Unvalidated>::default().estimate_apply_cost(),
+            GenesisCost::default()
+        );
+    }
+
+    /// Test that the strict parser rejects a misspelled field that the
+    /// default parser ignores.
+    #[test]
+    fn test_parse_unsigned_strict() {
+        let pk = common_sk_from_simple_seed(0).ref_to();
+        let txs = |public_key_field: &str| {
+            format!(
+                r#"
+                [[established_account]]
+                alias = "albert"
+                vp = "vp_user"
+                {public_key_field} = "{pk}"
+                "#
+            )
+        };
+
+        let valid = txs("public_key");
+        assert_eq!(
+            parse_unsigned_strict(valid.as_bytes()).unwrap(),
+            parse_unsigned(valid.as_bytes()).unwrap()
+        );
+
+        let misspelled = txs("publik_key");
+        let unsigned = parse_unsigned(misspelled.as_bytes()).unwrap();
+        assert!(unsigned.established_account.unwrap()[0].public_key.is_none());
+        let err = parse_unsigned_strict(misspelled.as_bytes()).unwrap_err();
+        assert!(err.to_string().contains("publik_key"), "{err}");
+
+        // Fields that are left out when serialized are known
+        let tx =
+            unsigned_validator_account_tx("validator", &validator_wallet(0));
+        assert!(tx.account_keys.is_empty());
+        let with_empty_keys = format!(
+            "{}account_keys = []\n",
+            toml::to_string(&UnsignedTransactions {
+                validator_account: Some(vec![tx]),
+                ..Default::default()
+            })
+            .unwrap()
+        );
+        assert_eq!(
+            parse_unsigned_strict(with_empty_keys.as_bytes()).unwrap(),
+            parse_unsigned(with_empty_keys.as_bytes()).unwrap()
+        );
+    }
+
+    /// Test that the genesis transactions functions report the matching
+    /// [`GenesisTxError`] variants.
+    #[test]
+    fn test_genesis_tx_errors() {
+        // Parsing
+        let err = parse_unsigned(b"established_account = 1").unwrap_err();
+        assert!(matches!(err, GenesisTxError::Parse(_)), "{err}");
+        let err = parse_unsigned_strict(
+            b"[[established_account]]\nalias = \"albert\"\nvp = \
+              \"vp_user\"\ntypo = 1",
+        )
+        .unwrap_err();
+        assert!(
+            matches!(&err, GenesisTxError::UnknownField(msg)
+                if msg.contains("typo")),
+            "{err}"
+        );
+
+        // Denomination of transfers
+        let tokens = tokens(&[("zero", 0)]);
+        let source = common_sk_from_simple_seed(0);
+        let err = transfer_tx("unknown", &source, "target", "10")
+            .denominate(&tokens)
+            .unwrap_err();
+        assert!(
+            matches!(&err, GenesisTxError::UnknownToken(token)
+                if token == &Alias::from("unknown")),
+            "{err}"
+        );
+        let err = transfer_tx("zero", &source, "target", "10.5")
+            .denominate(&tokens)
+            .unwrap_err();
+        assert!(
+            matches!(
+                err,
+                GenesisTxError::ExcessivePrecision {
+                    decimal_places: 1,
+                    denom: 0,
+                    ..
+                }
+            ),
+            "{err}"
+        );
+
+        // Denomination of bonds
+        let err = bond_tx::<Unvalidated>(
+            AliasOrPk::Alias(Alias::from("albert")),
+            "validator",
+            BondAmount::AllAvailable,
+        )
+        .denominate()
+        .unwrap_err();
+        assert!(
+            matches!(err, GenesisTxError::NoAvailableBalance(_)),
+            "{err}"
+        );
+
+        // Signing
+        let tmp = tempfile::tempdir().unwrap();
+        let mut wallet = CliWalletUtils::new(tmp.path().to_path_buf());
+        let validator_txs = UnsignedTransactions {
+            validator_account: Some(vec![unsigned_validator_account_tx(
+                "validator",
+                &validator_wallet(1),
+            )]),
+            ..Default::default()
+        };
+        let err = try_sign_txs(validator_txs, &mut wallet).unwrap_err();
+        assert!(
+            matches!(err, GenesisTxError::ValidatorTxsRequireValidatorWallet),
+            "{err}"
+        );
+        let self_bond_txs = UnsignedTransactions {
+            bond: Some(vec![bond(
+                "validator",
+                DenominatedAmount::from_str("1").unwrap().into(),
+            )]),
+            ..Default::default()
+        };
+        let err = try_sign_txs(self_bond_txs, &mut wallet).unwrap_err();
+        assert!(
+            matches!(err, GenesisTxError::SelfBondRequiresValidatorWallet),
+            "{err}"
+        );
+        let transfer_txs = UnsignedTransactions {
+            transfer: Some(vec![transfer_tx("zero", &source, "target", "1")]),
+            ..Default::default()
+        };
+        let err = try_sign_txs(transfer_txs, &mut wallet).unwrap_err();
+        assert!(
+            matches!(
+                err,
+                GenesisTxError::Signing(SigningError::MissingKey {
+                    err: FindKeyError::KeyNotFound,
+                    ..
+                })
+            ),
+            "{err}"
+        );
+    }
+
+    /// Test that transfers are rejected when the denomination of the token's
+    /// balances doesn't match the token's configured denomination.
+    #[test]
+    fn test_validate_transfer_denom_mismatch() {
+        let tokens = tokens(&[("tok", 6)]);
+        let source_sk = common_sk_from_simple_seed(0);
+        let source = StringEncoded::new(source_sk.ref_to());
+        let target = Alias::from("target");
+        let all_used_aliases = BTreeSet::from([target.clone()]);
+        let amount = |amount: u64, denom: u8| DenominatedAmount {
+            amount: token::Amount::from_u64(amount),
+            denom: denom.into(),
+        };
+        let is_valid = |source_denom: u8, target_denom: Option<u8>| {
+            let mut balances = BTreeMap::from([(
+                Alias::from("tok"),
+                TokenBalancesForValidation {
+                    token: Alias::from("tok"),
+                    aliases: target_denom
+                        .map(|denom| (target.clone(), amount(1, denom)))
+                        .into_iter()
+                        .collect(),
+                    pks: TokenBalances(BTreeMap::from([(
+                        source.clone(),
+                        amount(100_000_000, source_denom),
+                    )])),
+                },
+            )]);
+            let tx =
+                transfer_tx("tok", &source_sk, "target", "1").sign(&source_sk);
+            validate_transfer(&tx, &mut balances, &all_used_aliases, &tokens)
+                .is_ok()
+        };
+
+        assert!(is_valid(6, None));
+        assert!(is_valid(6, Some(6)));
+        // Mismatched source balance
+        assert!(!is_valid(3, None));
+        // Mismatched target balance
+        assert!(!is_valid(6, Some(3)));
+    }
+
+    /// Test reassembling a signed validator account tx from the detached
+    /// signatures of its signing payload.
+    #[test]
+    fn test_validator_account_from_authorizations() {
+        let validator_wallet = validator_wallet(1);
+        let extra_keys: Vec<_> =
+            (10..12).map(common_sk_from_simple_seed).collect();
+        let mut unsigned =
+            unsigned_validator_account_tx("validator", &validator_wallet);
+        unsigned.threshold = Some(2);
+        unsigned.account_keys = extra_keys
+            .iter()
+            .map(|sk| StringEncoded::new(sk.ref_to()))
+            .collect();
+
+        // Sign the payload with each key separately, as if on a separate
+        // offline device
+        let payload = unsigned.signing_payload();
+        let sign = |sk: &common::SecretKey| {
+            StringEncoded::new(common::SigScheme::sign_with_hasher::<
+                Sha256Hasher,
+            >(sk, &payload))
+        };
+        let authorizations = ValidatorAccountAuthorizations {
+            account_key: sign(&validator_wallet.account_key),
+            account_keys: extra_keys.iter().map(sign).collect(),
+            consensus_key: sign(&validator_wallet.consensus_key),
+            protocol_key: sign(
+                &validator_wallet.store.validator_keys.protocol_keypair,
+            ),
+            tendermint_node_key: sign(&validator_wallet.tendermint_node_key),
+            eth_hot_key: sign(&validator_wallet.eth_hot_key),
+            eth_cold_key: sign(&validator_wallet.eth_cold_key),
+        };
+        let signed = SignedValidatorAccountTx::from_authorizations(
+            unsigned.clone(),
+            authorizations.clone(),
+        );
+
+        assert_eq!(
+            signed,
+            sign_validator_account_tx(
+                unsigned.clone(),
+                &validator_wallet,
+                &extra_keys
+            )
+        );
+        let vps = vps(&["vp_validator"]);
+        let is_valid = |tx: &SignedValidatorAccountTx| {
+            validate_validator_account(
+                tx,
+                Some(&vps),
+                &mut BTreeSet::new(),
+                &mut BTreeMap::new(),
+            )
+            .is_ok()
+        };
+        assert!(is_valid(&signed));
+
+        // A signature of a key over a different payload is invalid
+        let mut invalid = authorizations;
+        invalid.consensus_key =
+            sign_tx(&"garbage", &validator_wallet.consensus_key);
+        let signed =
+            SignedValidatorAccountTx::from_authorizations(unsigned, invalid);
+        assert!(!is_valid(&signed));
+    }
+
+    /// Test that amounts overflowing when their precision is increased are
+    /// reported with the tx's source and token.
+    #[test]
+    fn test_denominate_precision_overflow() {
+        let tokens = tokens(&[("tok", 6)]);
+        let source_sk = common_sk_from_simple_seed(0);
+        let source = StringEncoded::new(source_sk.ref_to());
+        let max = DenominatedAmount {
+            amount: token::Amount::max(),
+            denom: 0.into(),
+        };
+
+        let transfer = TransferTx::<Unvalidated> {
+            token: Alias::from("tok"),
+            source: source.clone(),
+            target: Alias::from("target"),
+            amount: max,
+            nonce: None,
+        };
+        let err = transfer.denominate(&tokens).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                GenesisTxError::Validation(errors)
+                    if errors == &[ValidationError::AmountPrecisionOverflow {
+                        alias: AliasOrPk::PublicKey(source.clone()),
+                        token: Alias::from("tok"),
+                    }]
+            ),
+            "{err}"
+        );
+
+        let bond = BondTx::<Unvalidated> {
+            source: AliasOrPk::Alias(Alias::from("albert")),
+            validator: Alias::from("validator"),
+            amount: max.into(),
+            nonce: None,
+        };
+        let err = bond.denominate().unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                GenesisTxError::Validation(errors)
+                    if errors == &[ValidationError::AmountPrecisionOverflow {
+                        alias: AliasOrPk::Alias(Alias::from("albert")),
+                        token: Alias::from(DEFAULT_NATIVE_TOKEN_ALIAS),
+                    }]
+            ),
+            "{err}"
+        );
+    }
+
+    /// Test that the initial storage of an established account serializes
+    /// the same regardless of the order in which its keys were inserted.
+    #[test]
+    fn test_established_account_storage_deterministic() {
+        let entries = [("a/b", "00"), ("c/d", "01"), ("b/a", "02")];
+        let account = |order: &[usize]| {
+            let mut storage = BTreeMap::new();
+            for &ix in order {
+                let (key, val) = entries[ix];
+                storage.insert(
+                    storage::Key::parse(key).unwrap(),
+                    HexString(val.to_string()),
+                );
+            }
+            SignedEstablishedAccountTx {
+                alias: Alias::from("albert"),
+                vp: "vp_user".to_string(),
+                public_key: None,
+                storage,
+            }
+        };
+        let first = account(&[0, 1, 2]);
+        let second = account(&[2, 0, 1]);
+
+        assert_eq!(first.serialize_to_vec(), second.serialize_to_vec());
+        assert_eq!(
+            toml::to_string(&first).unwrap(),
+            toml::to_string(&second).unwrap()
+        );
+    }
+
+    /// Test that the kind of a transfer target is told apart between
+    /// validator and established accounts.
+    #[test]
+    fn test_target_kind() {
+        let validator_wallet = validator_wallet(0);
+        let albert = SignedEstablishedAccountTx {
+            alias: Alias::from("albert"),
+            vp: "vp_user".to_string(),
+            public_key: None,
+            storage: BTreeMap::new(),
+        };
+        let validator = sign_validator_account_tx(
+            unsigned_validator_account_tx("validator", &validator_wallet),
+            &validator_wallet,
+            &[],
+        );
+        let txs = TransactionsBuilder::<Unvalidated>::new()
+            .add_established(albert)
+            .add_validator(validator)
+            .build();
+
+        assert_eq!(
+            txs.target_kind(&Alias::from("validator")),
+            Some(AccountKind::Validator)
+        );
+        assert_eq!(
+            txs.target_kind(&Alias::from("albert")),
+            Some(AccountKind::Established)
+        );
+        assert_eq!(txs.target_kind(&Alias::from("bertha")), None);
+    }
+
+    /// Test that the number of validator accounts is limited by the maximum
+    /// validator count, when given.
+    #[test]
+    fn test_validate_max_validators() {
+        let validators =
+            [("validator-1", 0), ("validator-2", 10)].map(|(alias, seed)| {
+                let wallet = validator_wallet(seed);
+                sign_validator_account_tx(
+                    unsigned_validator_account_tx(alias, &wallet),
+                    &wallet,
+                    &[],
+                )
+            });
+        let txs = Transactions::<Unvalidated> {
+            validator_account: Some(validators.to_vec()),
+            ..Default::default()
+        };
+        let vps = vps(&["vp_validator"]);
+        let validate = |max_validators| {
+            validate(
+                txs.clone(),
+                Some(&vps),
+                None,
+                &tokens(&[]),
+                None,
+                &ValidationOptions {
+                    max_validators,
+                    ..Default::default()
+                },
+            )
+            .map_err(validation_errors)
+        };
+
+        assert!(validate(None).is_ok());
+        // At the cap
+        assert!(validate(Some(2)).is_ok());
+        // Above the cap
+        assert_eq!(
+            validate(Some(1)).unwrap_err(),
+            vec![ValidationError::TooManyValidators { count: 2, max: 1 }]
+        );
+    }
+
+    /// Test that loopback and unspecified net addresses are rejected for a
+    /// public network, while a public address is accepted.
+    #[test]
+    fn test_validate_net_address() {
+        let mut tx =
+            unsigned_validator_account_tx("validator", &validator_wallet(0));
+        assert!(validate_net_address(&tx).is_err());
+
+        tx.net_address = SocketAddr::from(([0, 0, 0, 0], 26656));
+        assert!(validate_net_address(&tx).is_err());
+
+        tx.net_address = SocketAddr::from(([203, 0, 113, 7], 26656));
+        assert!(validate_net_address(&tx).is_ok());
+    }
+
+    /// Test that the remaining balances after a transfer are applied to the
+    /// starting balances.
+    #[test]
+    fn test_token_balances_apply_to() {
+        let tokens = tokens(&[("tok", 6)]);
+        let token = Alias::from("tok");
+        let source_sk = common_sk_from_simple_seed(0);
+        let source = StringEncoded::new(source_sk.ref_to());
+        let all_used_aliases = BTreeSet::from([Alias::from("target")]);
+        let amount = |amount: u64| DenominatedAmount {
+            amount: token::Amount::from_u64(amount),
+            denom: 6.into(),
+        };
+        let mut starting_balances = DenominatedBalances {
+            token: BTreeMap::from([(
+                token.clone(),
+                TokenBalances(BTreeMap::from([(
+                    source.clone(),
+                    amount(10_000_000),
+                )])),
+            )]),
+            aliases: BTreeMap::new(),
+        };
+        let mut balances = BTreeMap::from([(
+            token.clone(),
+            TokenBalancesForValidation {
+                token: token.clone(),
+                aliases: BTreeMap::new(),
+                pks: starting_balances.token[&token].clone(),
+            },
+        )]);
+        let tx = transfer_tx("tok", &source_sk, "target", "3").sign(&source_sk);
+        assert!(
+            validate_transfer(&tx, &mut balances, &all_used_aliases, &tokens)
+                .is_ok()
+        );
+
+        balances[&token].apply_to(&mut starting_balances);
+        assert_eq!(
+            starting_balances.token[&token].0,
+            BTreeMap::from([(source, amount(7_000_000))])
+        );
+        // The transferred amount is materialized in the balances of aliases
+        let alias_balances =
+            BTreeMap::from([(Alias::from("target"), amount(3_000_000))]);
+        assert_eq!(starting_balances.aliases[&token], alias_balances);
+
+        // The applied balances are the starting point of another validation
+        let next_balances =
+            token_balances_for_validation(Some(&starting_balances));
+        assert_eq!(next_balances[&token].aliases, alias_balances);
+        assert_eq!(next_balances[&token].pks, starting_balances.token[&token]);
+    }
+
+    /// Test that the borsh schema of the genesis txs is defined.
+    #[test]
+    fn test_transactions_borsh_schema() {
+        let mut definitions = BTreeMap::new();
+        SignedBondTx::add_definitions_recursively(&mut definitions);
+        assert!(!definitions.is_empty());
+        assert!(definitions.contains_key(&SignedBondTx::declaration()));
+
+        let definitions = transactions_borsh_schema();
+        for declaration in [
+            SignedBondTx::declaration(),
+            SignedTransferTx::declaration(),
+            SignedValidatorAccountTx::declaration(),
+            SignedEstablishedAccountTx::declaration(),
+            BondTx::<Validated>::declaration(),
+            TransferTx::<Validated>::declaration(),
+            socket_addr_declaration(),
+        ] {
+            assert!(definitions.contains_key(&declaration), "{declaration}");
+        }
+    }
+
+    /// Test that the hand-written borsh schema of the validator account tx
+    /// has the same fields as its serde encoding and that it describes its
+    /// borsh encoding, i.e. the fields are encoded in the schema's order with
+    /// the declared types.
+    #[test]
+    fn test_validator_account_tx_borsh_schema() {
+        let validator_wallet = validator_wallet(0);
+        let extra_key = common_sk_from_simple_seed(10);
+        let mut unsigned =
+            unsigned_validator_account_tx("validator", &validator_wallet);
+        unsigned.description = Some("A validator".to_string());
+        unsigned.threshold = Some(2);
+        unsigned.account_keys = vec![StringEncoded::new(extra_key.ref_to())];
+        let tx = sign_validator_account_tx(
+            unsigned,
+            &validator_wallet,
+            &[extra_key],
+        );
+
+        let mut definitions = BTreeMap::new();
+        SignedValidatorAccountTx::add_definitions_recursively(&mut definitions);
+        let fields =
+            match &definitions[&SignedValidatorAccountTx::declaration()] {
+                Definition::Struct {
+                    fields: Fields::NamedFields(fields),
+                } => fields.clone(),
+                definition => panic!("Unexpected definition {definition:?}"),
+            };
+
+        // The schema has the fields of the serde encoding
+        let serde_fields: BTreeSet<String> = serde_json::to_value(&tx)
+            .unwrap()
+            .as_object()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect();
+        let schema_fields: BTreeSet<String> =
+            fields.iter().map(|(name, _)| name.clone()).collect();
+        assert_eq!(schema_fields, serde_fields);
+
+        // The borsh encoding is the concatenation of the fields in the
+        // schema's order, each encoded as its declared type
+        let field = |name: &str| -> (Declaration, Vec<u8>) {
+            match name {
+                "alias" => (Alias::declaration(), tx.alias.serialize_to_vec()),
+                "vp" => (String::declaration(), tx.vp.serialize_to_vec()),
+                "commission_rate" => {
+                    (Dec::declaration(), tx.commission_rate.serialize_to_vec())
+                }
+                "max_commission_rate_change" => (
+                    Dec::declaration(),
+                    tx.max_commission_rate_change.serialize_to_vec(),
+                ),
+                "email" => (String::declaration(), tx.email.serialize_to_vec()),
+                "description" => (
+                    Option::<String>::declaration(),
+                    tx.description.serialize_to_vec(),
+                ),
+                "website" => (
+                    Option::<String>::declaration(),
+                    tx.website.serialize_to_vec(),
+                ),
+                "discord_handle" => (
+                    Option::<String>::declaration(),
+                    tx.discord_handle.serialize_to_vec(),
+                ),
+                "net_address" => (
+                    socket_addr_declaration(),
+                    tx.net_address.serialize_to_vec(),
+                ),
+                "threshold" => (
+                    Option::<u8>::declaration(),
+                    tx.threshold.serialize_to_vec(),
+                ),
+                "account_key" => {
+                    (SignedPk::declaration(), tx.account_key.serialize_to_vec())
+                }
+                "consensus_key" => (
+                    SignedPk::declaration(),
+                    tx.consensus_key.serialize_to_vec(),
+                ),
+                "protocol_key" => (
+                    SignedPk::declaration(),
+                    tx.protocol_key.serialize_to_vec(),
+                ),
+                "tendermint_node_key" => (
+                    SignedPk::declaration(),
+                    tx.tendermint_node_key.serialize_to_vec(),
+                ),
+                "eth_hot_key" => {
+                    (SignedPk::declaration(), tx.eth_hot_key.serialize_to_vec())
+                }
+                "eth_cold_key" => (
+                    SignedPk::declaration(),
+                    tx.eth_cold_key.serialize_to_vec(),
+                ),
+                "account_keys" => (
+                    Vec::<SignedPk>::declaration(),
+                    tx.account_keys.serialize_to_vec(),
+                ),
+                _ => panic!("Unknown field \"{name}\" in the schema"),
+            }
+        };
+        let mut encoded = vec![];
+        for (name, declaration) in &fields {
+            let (field_declaration, bytes) = field(name);
+            assert_eq!(declaration, &field_declaration, "{name}");
+            encoded.extend(bytes);
+        }
+        assert_eq!(encoded, tx.serialize_to_vec());
+        assert_eq!(
+            SignedValidatorAccountTx::try_from_slice(&encoded).unwrap(),
+            tx
+        );
+
+        // The IPv4 net address is encoded as the first variant of the
+        // `SocketAddr` schema, i.e. its tag followed by the octets and port
+        match &definitions[&socket_addr_declaration()] {
+            Definition::Enum { variants } => assert_eq!(variants[0].0, "V4"),
+            definition => panic!("Unexpected definition {definition:?}"),
+        }
+        let mut net_address = vec![0_u8, 127, 0, 0, 1];
+        net_address.extend(26656_u16.to_le_bytes());
+        assert_eq!(tx.net_address.serialize_to_vec(), net_address);
+    }
+
+    /// Test that the nonce of transfers and bonds is signed over.
+    #[test]
+    fn test_transfer_and_bond_nonce() {
+        let source_sk = common_sk_from_simple_seed(0);
+        let transfer = |nonce: Option<u64>| {
+            let mut tx = transfer_tx("tok", &source_sk, "target", "1");
+            tx.nonce = nonce;
+            tx.sign(&source_sk)
+        };
+        let bond = |nonce: Option<u64>| {
+            let mut tx = bond_tx::<Unvalidated>(
+                AliasOrPk::PublicKey(StringEncoded::new(source_sk.ref_to())),
+                "validator",
+                BondAmount::from_str("1").unwrap(),
+            );
+            tx.nonce = nonce;
+            tx.sign(&source_sk)
+        };
+
+        // Distinct nonces produce distinct signatures
+        assert_ne!(transfer(Some(1)).signature, transfer(Some(2)).signature);
+        assert_ne!(transfer(None).signature, transfer(Some(1)).signature);
+        assert_ne!(bond(Some(1)).signature, bond(Some(2)).signature);
+        assert_ne!(bond(None).signature, bond(Some(1)).signature);
+
+        // The nonce can't be changed without invalidating the signature
+        let mut tx = transfer(Some(1));
+        assert!(tx.verify_sig().is_ok());
+        tx.data.nonce = Some(2);
+        assert!(tx.verify_sig().is_err());
+        let mut tx = bond(Some(1));
+        assert!(tx.verify_sig(&source_sk.ref_to()).is_ok());
+        tx.data.nonce = None;
+        assert!(tx.verify_sig(&source_sk.ref_to()).is_err());
+
+        // A missing nonce is omitted from the toml
+        let toml = toml::to_string(&transfer(None)).unwrap();
+        assert!(!toml.contains("nonce"), "{toml}");
+        let toml = toml::to_string(&transfer(Some(1))).unwrap();
+        assert!(toml.contains("nonce = 1"), "{toml}");
+
+        // Dedup only removes the txs with the same nonce
+        let mut txs = Transactions::<Unvalidated> {
+            transfer: Some(vec![
+                transfer(None),
+                transfer(None),
+                transfer(Some(1)),
+                transfer(Some(2)),
+                transfer(Some(1)),
+            ]),
+            bond: Some(vec![bond(Some(1)), bond(Some(1)), bond(None)]),
+            ..Default::default()
+        };
+        assert_eq!(txs.dedup(), 3);
+        let transfer_nonces: Vec<_> = txs
+            .transfer
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|tx| tx.data.nonce)
+            .collect();
+        assert_eq!(transfer_nonces, vec![None, Some(1), Some(2)]);
+        let bond_nonces: Vec<_> = txs
+            .bond
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|tx| tx.data.nonce)
+            .collect();
+        assert_eq!(bond_nonces, vec![Some(1), None]);
+        assert_eq!(txs.dedup(), 0);
+    }
+
+    /// Test signing txs with keys split across multiple wallets.
+    #[test]
+    fn test_sign_txs_multi() {
+        let transfer_sk = common_sk_from_simple_seed(0);
+        let established_sk = common_sk_from_simple_seed(1);
+        let delegator_sk = common_sk_from_simple_seed(2);
+        let validator_wallets = [validator_wallet(10), validator_wallet(20)];
+        let validator_wallet = &validator_wallets[1];
+
+        // The source of the transfer is in the first wallet and the keys of
+        // the established account and the delegator are in the second one
+        let tmp_1 = tempfile::tempdir().unwrap();
+        let tmp_2 = tempfile::tempdir().unwrap();
+        let mut wallets = [
+            CliWalletUtils::new(tmp_1.path().to_path_buf()),
+            CliWalletUtils::new(tmp_2.path().to_path_buf()),
+        ];
+        for (wallet, alias, sk) in [
+            (0, "transfer-source", &transfer_sk),
+            (1, "established", &established_sk),
+            (1, "delegator", &delegator_sk),
+        ] {
+            wallets[wallet]
+                .insert_keypair(
+                    alias.to_string(),
+                    true,
+                    sk.clone(),
+                    None,
+                    None,
+                    None,
+                )
+                .unwrap();
+        }
+
+        let delegator_pk = StringEncoded::new(delegator_sk.ref_to());
+        let bond = |source: AliasOrPk| {
+            bond_tx::<Unvalidated>(
+                source,
+                "validator",
+                BondAmount::from_str("1").unwrap(),
+            )
+        };
+        let txs = UnsignedTransactions {
+            established_account: Some(vec![EstablishedAccountTx {
+                alias: Alias::from("established"),
+                vp: "vp_user".to_string(),
+                public_key: Some(StringEncoded::new(established_sk.ref_to())),
+                storage: BTreeMap::new(),
+            }]),
+            validator_account: Some(vec![unsigned_validator_account_tx(
+                "validator",
+                validator_wallet,
+            )]),
+            transfer: Some(vec![transfer_tx(
+                "tok",
+                &transfer_sk,
+                "validator",
+                "10",
+            )]),
+            bond: Some(vec![
+                bond(AliasOrPk::Alias(Alias::from("validator"))),
+                bond(AliasOrPk::PublicKey(delegator_pk.clone())),
+            ]),
+        };
+
+        let signed =
+            sign_txs_multi(txs.clone(), &mut wallets, &validator_wallets)
+                .unwrap();
+        let established = &signed.established_account.as_ref().unwrap()[0];
+        assert_eq!(
+            established.public_key.as_ref().unwrap().pk.raw,
+            established_sk.ref_to()
+        );
+        let validator = &signed.validator_account.as_ref().unwrap()[0];
+        assert_eq!(
+            validator.account_key.authorization,
+            sign_tx(
+                &unsigned_validator_account_tx("validator", validator_wallet),
+                &validator_wallet.account_key
+            )
+        );
+        assert!(signed.transfer.as_ref().unwrap()[0].verify_sig().is_ok());
+        let bonds = signed.bond.as_ref().unwrap();
+        assert!(
+            bonds[0]
+                .verify_sig(&validator_wallet.account_key.ref_to())
+                .is_ok()
+        );
+        assert!(bonds[1].verify_sig(&delegator_pk.raw).is_ok());
+
+        // Without the validator wallet, the validator txs can't be signed
+        let err = sign_txs_multi(txs, &mut wallets, &validator_wallets[..1])
+            .unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                GenesisTxError::Signing(SigningError::MissingValidatorWallet {
+                    alias,
+                    ..
+                }) if alias == &Alias::from("validator")
+            ),
+            "{err}"
+        );
+
+        // Without the second wallet, the delegation can't be signed
+        let source = AliasOrPk::PublicKey(delegator_pk);
+        let txs = UnsignedTransactions {
+            bond: Some(vec![bond(source.clone())]),
+            ..Default::default()
+        };
+        let err = sign_txs_multi(txs, &mut wallets[..1], &[]).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                GenesisTxError::Signing(SigningError::UnknownBondSource(
+                    bond_source
+                )) if bond_source == &source
+            ),
+            "{err}"
+        );
+    }
+
+    /// Test that an established account and a validator account sharing an
+    /// alias are reported as such.
+    #[test]
+    fn test_validate_established_and_validator_alias() {
+        let validator_wallet = validator_wallet(0);
+        let established_account = SignedEstablishedAccountTx {
+            alias: Alias::from("validator"),
+            vp: "vp_user".to_string(),
+            public_key: None,
+            storage: BTreeMap::new(),
+        };
+        let validator = sign_validator_account_tx(
+            unsigned_validator_account_tx("validator", &validator_wallet),
+            &validator_wallet,
+            &[],
+        );
+        let vps = vps(&["vp_user", "vp_validator"]);
+        let validate = |txs: Transactions<Unvalidated>| {
+            validate(
+                txs,
+                Some(&vps),
+                None,
+                &tokens(&[]),
+                None,
+                &Default::default(),
+            )
+            .map_err(validation_errors)
+        };
+
+        let txs = Transactions::<Unvalidated> {
+            established_account: Some(vec![established_account.clone()]),
+            validator_account: Some(vec![validator.clone()]),
+            ..Default::default()
+        };
+        let errors = validate(txs).unwrap_err();
+        assert!(
+            errors.contains(&ValidationError::EstablishedAndValidatorAlias(
+                Alias::from("validator")
+            )),
+            "{errors:?}"
+        );
+        assert!(errors.contains(&ValidationError::InvalidTxs), "{errors:?}");
+
+        // A duplicate within the validator accounts is only invalid
+        let txs = Transactions::<Unvalidated> {
+            validator_account: Some(vec![validator.clone(), validator]),
+            ..Default::default()
+        };
+        assert_eq!(
+            validate(txs).unwrap_err(),
+            vec![ValidationError::InvalidTxs]
+        );
+
+        // A duplicate within the established accounts is only invalid
+        let txs = Transactions::<Unvalidated> {
+            established_account: Some(vec![
+                established_account.clone(),
+                established_account,
+            ]),
+            ..Default::default()
+        };
+        assert_eq!(
+            validate(txs).unwrap_err(),
+            vec![ValidationError::InvalidTxs]
+        );
+    }
+
+    /// Test that a transfer whose source key is absent from the wallet is
+    /// reported, while the other txs are signed.
+    #[test]
+    fn test_sign_transfer_tx_missing_key() {
+        let present_sk = common_sk_from_simple_seed(0);
+        let absent_sk = common_sk_from_simple_seed(1);
+        let tmp = tempfile::tempdir().unwrap();
+        let mut wallet = CliWalletUtils::new(tmp.path().to_path_buf());
+        wallet
+            .insert_keypair(
+                "present".to_string(),
+                true,
+                present_sk.clone(),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        let err = sign_transfer_tx(
+            transfer_tx("tok", &absent_sk, "target", "1"),
+            &mut wallet,
+        )
+        .unwrap_err();
+        assert!(matches!(err, FindKeyError::KeyNotFound), "{err}");
+
+        let txs = UnsignedTransactions {
+            transfer: Some(vec![
+                transfer_tx("tok", &absent_sk, "target", "1"),
+                transfer_tx("tok", &present_sk, "target", "2"),
+            ]),
+            ..Default::default()
+        };
+        let signed = sign_txs(txs, &mut wallet);
+        let transfers = signed.transfer.unwrap();
+        assert_eq!(transfers.len(), 1);
+        assert_eq!(transfers[0].data.source.raw, present_sk.ref_to());
+        assert!(transfers[0].verify_sig().is_ok());
+    }
+
+    /// Test that validated txs are validated again against changed
+    /// parameters.
+    #[test]
+    fn test_revalidate() {
+        let ValidationFixture {
+            templates,
+            native_token,
+            validator_tx,
+            source_sk,
+            source,
+            balances,
+            vps,
+            ..
+        } = validation_fixture();
+        let transfer = transfer_tx(
+            &native_token.to_string(),
+            &source_sk,
+            "validator",
+            "10",
+        )
+        .sign(&source_sk);
+        let txs = Transactions::<Unvalidated> {
+            validator_account: Some(vec![validator_tx]),
+            transfer: Some(vec![transfer]),
+            bond: Some(vec![
+                BondTx::<Unvalidated> {
+                    source: AliasOrPk::PublicKey(source),
+                    validator: Alias::from("validator"),
+                    amount: DenominatedAmount::from_str("10").unwrap().into(),
+                    nonce: None,
+                }
+                .sign(&source_sk),
+            ]),
+            ..Default::default()
+        };
+        let validated = validate(
+            txs,
+            Some(&vps),
+            Some(&balances),
+            &templates.tokens,
+            Some(&templates.parameters),
+            &Default::default(),
+        )
+        .map_err(validation_errors)
+        .unwrap();
+        let revalidate = |parameters: &Parameters<Validated>| {
+            validated.clone().revalidate(
+                Some(&vps),
+                Some(&balances),
+                &templates.tokens,
+                Some(parameters),
+                &Default::default(),
+            )
+        };
+
+        // Unchanged parameters give the same txs
+        assert_eq!(revalidate(&templates.parameters).unwrap(), validated);
+
+        // The bond is below a raised minimum bond amount
+        let mut parameters = templates.parameters.clone();
+        parameters.pos_params.min_bond = Some(token::Amount::native_whole(20));
+        assert_eq!(
+            revalidate(&parameters)
+                .map_err(validation_errors)
+                .unwrap_err(),
+            vec![ValidationError::InvalidTxs]
+        );
+
+        // A bond from an undeclared source is reported with its own error,
+        // the same as in `validate`
+        let mut unknown_source = validated.clone();
+        unknown_source.bond.as_mut().unwrap()[0].source =
+            AliasOrPk::Alias(Alias::from("undeclared"));
+        assert_eq!(
+            unknown_source
+                .revalidate(
+                    Some(&vps),
+                    Some(&balances),
+                    &templates.tokens,
+                    Some(&templates.parameters),
+                    &Default::default(),
+                )
+                .map_err(validation_errors)
+                .unwrap_err(),
+            vec![
+                ValidationError::UnknownBondSource {
+                    alias: Alias::from("undeclared"),
+                },
+                ValidationError::InvalidTxs,
+            ]
+        );
+
+        // Bonds can't be validated again without parameters
+        assert_eq!(
+            validated
+                .revalidate(
+                    Some(&vps),
+                    Some(&balances),
+                    &templates.tokens,
+                    None,
+                    &Default::default(),
+                )
+                .map_err(validation_errors)
+                .unwrap_err(),
+            vec![ValidationError::MissingParametersForBonds]
+        );
+    }
+
+    /// Test that transfers are only valid up to the maximum transfer amount.
+    #[test]
+    fn test_validate_max_transfer() {
+        let ValidationFixture {
+            templates,
+            native_token,
+            validator_tx,
+            source_sk,
+            source,
+            mut balances,
+            vps,
+            ..
+        } = validation_fixture();
+        // The source also has a balance of a token with more decimal places
+        balances.token.insert(
+            Alias::from("BTC"),
+            TokenBalances(BTreeMap::from([(
+                source,
+                DenominatedAmount::from_str("100.00000000").unwrap(),
+            )])),
+        );
+        let validate = |token: &str, amount: &str| {
+            let transfer = transfer_tx(token, &source_sk, "validator", amount)
+                .sign(&source_sk);
+            let txs = Transactions::<Unvalidated> {
+                validator_account: Some(vec![validator_tx.clone()]),
+                transfer: Some(vec![transfer]),
+                ..Default::default()
+            };
+            validate(
+                txs,
+                Some(&vps),
+                Some(&balances),
+                &templates.tokens,
+                Some(&templates.parameters),
+                &ValidationOptions {
+                    max_transfer: Some(
+                        DenominatedAmount::from_str("10").unwrap(),
+                    ),
+                    ..Default::default()
+                },
+            )
+            .map_err(validation_errors)
+        };
+
+        let native_token = native_token.to_string();
+        assert!(validate(&native_token, "9").is_ok());
+        assert!(validate(&native_token, "10").is_ok());
+        assert_eq!(
+            validate(&native_token, "10.000001").unwrap_err(),
+            vec![ValidationError::InvalidTxs]
+        );
+        assert_eq!(
+            validate(&native_token, "50").unwrap_err(),
+            vec![ValidationError::InvalidTxs]
+        );
+
+        // The limit is in whole tokens for any denomination
+        assert!(validate("BTC", "10").is_ok());
+        assert_eq!(
+            validate("BTC", "10.00000001").unwrap_err(),
+            vec![ValidationError::InvalidTxs]
+        );
+    }
+
+    /// Test collecting the tokens moved by transfers.
+    #[test]
+    fn test_referenced_tokens() {
+        let sk = common_sk_from_simple_seed(0);
+        let txs = Transactions::<Unvalidated> {
+            transfer: Some(vec![
+                transfer_tx("NAM", &sk, "validator", "1").sign(&sk),
+                transfer_tx("BTC", &sk, "validator", "1").sign(&sk),
+                transfer_tx("NAM", &sk, "account", "2").sign(&sk),
+            ]),
+            ..Default::default()
+        };
+        assert_eq!(
+            txs.referenced_tokens(),
+            BTreeSet::from([Alias::from("BTC"), Alias::from("NAM")])
+        );
+
+        assert!(
+            Transactions::<Unvalidated>::default()
+                .referenced_tokens()
+                .is_empty()
+        );
+    }
+
+    /// Test that the signatures of transfers and bonds are domain separated,
+    /// so that a signature over the same bytes, or over the data of another
+    /// kind of tx, is not accepted in the current format version.
+    #[test]
+    fn test_signature_domain_separation() {
+        let sk = common_sk_from_simple_seed(0);
+        let pk = sk.ref_to();
+        let bond = BondTx::<Unvalidated> {
+            source: AliasOrPk::PublicKey(StringEncoded::new(pk.clone())),
+            validator: Alias::from("validator"),
+            amount: DenominatedAmount::from_str("10").unwrap().into(),
+            nonce: None,
+        };
+        let signed_with = |data: &[u8], version: u8| SignedBondTx {
+            version,
+            data: bond.clone(),
+            signature: StringEncoded::new(standalone_signature::<
+                _,
+                SerializeWithBorsh,
+            >(&sk, &data.to_vec())),
+        };
+        let untagged = bond.data_to_sign(SIGNED_FORMAT_VERSION_UNTAGGED);
+
+        // A signature over the untagged bytes, e.g. obtained for another
+        // kind of tx with the same bytes, is only valid in the untagged
+        // version
+        assert!(
+            signed_with(&untagged, SIGNED_FORMAT_VERSION_UNTAGGED)
+                .verify_sig(&pk)
+                .is_ok()
+        );
+        assert!(
+            signed_with(&untagged, SIGNED_FORMAT_VERSION)
+                .verify_sig(&pk)
+                .is_err()
+        );
+
+        // The same bytes tagged as a transfer are rejected for a bond
+        let as_transfer = [TRANSFER_DOMAIN_TAG, &untagged[..]].concat();
+        assert!(
+            signed_with(&as_transfer, SIGNED_FORMAT_VERSION)
+                .verify_sig(&pk)
+                .is_err()
+        );
+        assert!(bond.clone().sign(&sk).verify_sig(&pk).is_ok());
+
+        // A transfer's signature is not valid for a bond by the same key
+        let transfer = transfer_tx("nam", &sk, "validator", "10").sign(&sk);
+        let reused = SignedBondTx {
+            version: SIGNED_FORMAT_VERSION,
+            data: bond,
+            signature: transfer.signature,
+        };
+        assert!(reused.verify_sig(&pk).is_err());
+    }
+
+    /// Test that the validators are ordered by their stake, with a tie broken
+    /// by their aliases.
+    #[test]
+    fn test_validators_by_stake() {
+        let bond = |validator: &str, amount: u64| {
+            bond_tx::<Validated>(
+                AliasOrPk::Alias(Alias::from("source")),
+                validator,
+                DenominatedAmount::native(token::Amount::native_whole(amount)),
+            )
+        };
+        let votes_per_token = Dec::new(1, 3).unwrap();
+        let validator_wallet = validator_wallet(0);
+        let txs = Transactions::<Validated> {
+            validator_account: Some(vec![sign_validator_account_tx(
+                unsigned_validator_account_tx("validator-d", &validator_wallet),
+                &validator_wallet,
+                &[],
+            )]),
+            bond: Some(vec![
+                bond("validator-b", 50),
+                bond("validator-c", 60),
+                bond("validator-a", 50),
+                bond("validator-c", 40),
+            ]),
+            ..Default::default()
+        };
+        let entry = |validator: &str, stake: u64| {
+            let stake = token::Amount::native_whole(stake);
+            let power = namada::ledger::pos::into_tm_voting_power(
+                votes_per_token,
+                stake,
+            ) as u64;
+            (Alias::from(validator), stake, power)
+        };
+
+        assert_eq!(
+            txs.validators_by_stake(votes_per_token).unwrap(),
+            vec![
+                entry("validator-c", 100),
+                entry("validator-a", 50),
+                entry("validator-b", 50),
+                entry("validator-d", 0),
+            ]
+        );
+    }
+
+    /// Test that the txs with stripped signatures are the unsigned txs, which
+    /// can be edited and signed again.
+    #[test]
+    fn test_strip_signatures() {
+        let sk = common_sk_from_simple_seed(0);
+        let pk = StringEncoded::new(sk.ref_to());
+        let tmp = tempfile::tempdir().unwrap();
+        let mut wallet = CliWalletUtils::new(tmp.path().to_path_buf());
+        wallet
+            .insert_keypair(
+                "key".to_string(),
+                true,
+                sk.clone(),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        let unsigned = UnsignedTransactions {
+            established_account: Some(vec![UnsignedEstablishedAccountTx {
+                alias: Alias::from("account"),
+                vp: "vp_user".to_string(),
+                public_key: Some(pk.clone()),
+                storage: BTreeMap::new(),
+            }]),
+            transfer: Some(vec![transfer_tx("nam", &sk, "account", "10")]),
+            bond: Some(vec![BondTx {
+                source: AliasOrPk::PublicKey(pk.clone()),
+                validator: Alias::from("validator"),
+                amount: DenominatedAmount::from_str("5").unwrap().into(),
+                nonce: None,
+            }]),
+            ..Default::default()
+        };
+        let signed = try_sign_txs(unsigned.clone(), &mut wallet).unwrap();
+        assert_eq!(signed.strip_signatures(), unsigned);
+
+        // The edited txs are signed again
+        let mut edited = unsigned;
+        edited.transfer.as_mut().unwrap()[0].amount =
+            DenominatedAmount::from_str("20").unwrap();
+        let signed = try_sign_txs(edited.clone(), &mut wallet).unwrap();
+        let established = &signed.established_account.as_ref().unwrap()[0];
+        assert!(validate_established_account_sig(
+            established.public_key.as_ref().unwrap(),
+            established,
+        ));
+        assert!(signed.transfer.as_ref().unwrap()[0].verify_sig().is_ok());
+        assert!(signed.bond.as_ref().unwrap()[0].verify_sig(&pk.raw).is_ok());
+        assert_eq!(signed.strip_signatures(), edited);
+
+        // The authorizations of a validator account are dropped too
+        let validator_wallet = validator_wallet(0);
+        let unsigned_validator =
+            unsigned_validator_account_tx("validator", &validator_wallet);
+        let signed = Transactions::<Unvalidated> {
+            validator_account: Some(vec![sign_validator_account_tx(
+                unsigned_validator.clone(),
+                &validator_wallet,
+                &[],
+            )]),
+            ..Default::default()
+        };
+        assert_eq!(
+            signed.strip_signatures(),
+            UnsignedTransactions {
+                validator_account: Some(vec![unsigned_validator]),
+                ..Default::default()
+            }
+        );
+    }
+
+    /// Test that a bond from an alias without an account is reported with its
+    /// own error.
+    #[test]
+    fn test_validate_unknown_bond_source() {
+        let ValidationFixture {
+            templates,
+            validator_tx,
+            source_sk: sk,
+            vps,
+            ..
+        } = validation_fixture();
+        let balances = DenominatedBalances {
+            token: BTreeMap::new(),
+            aliases: BTreeMap::new(),
+        };
+        let validate = |source: &str| {
+            let bond = BondTx::<Unvalidated> {
+                source: AliasOrPk::Alias(Alias::from(source)),
+                validator: Alias::from("validator"),
+                amount: DenominatedAmount::from_str("1").unwrap().into(),
+                nonce: None,
+            }
+            .sign(&sk);
+            let txs = Transactions::<Unvalidated> {
+                validator_account: Some(vec![validator_tx.clone()]),
+                bond: Some(vec![bond]),
+                ..Default::default()
+            };
+            validate(
+                txs,
+                Some(&vps),
+                Some(&balances),
+                &templates.tokens,
+                Some(&templates.parameters),
+                &Default::default(),
+            )
+            .map_err(validation_errors)
+        };
+
+        assert_eq!(
+            validate("undeclared").unwrap_err(),
+            vec![
+                ValidationError::UnknownBondSource {
+                    alias: Alias::from("undeclared"),
+                },
+                ValidationError::InvalidTxs,
+            ]
+        );
+        // A declared source signed with a wrong key is only an invalid tx
+        assert_eq!(
+            validate("validator").unwrap_err(),
+            vec![ValidationError::InvalidTxs]
+        );
+    }
+
+    /// Test finding the bonds to validators without any voting power.
+    #[test]
+    fn test_bonds_with_zero_power_validators() {
+        let bond = |validator: &str, amount: token::Amount| {
+            bond_tx::<Validated>(
+                AliasOrPk::Alias(Alias::from("source")),
+                validator,
+                DenominatedAmount::native(amount),
+            )
+        };
+        let votes_per_token = Dec::new(1, 3).unwrap();
+        let dust = token::Amount::from_uint(1, 0).unwrap();
+        let txs = Transactions::<Validated> {
+            bond: Some(vec![
+                bond("validator-a", token::Amount::native_whole(1)),
+                bond("validator-b", dust),
+                bond("validator-c", dust),
+                bond("validator-b", dust),
+                bond("validator-c", token::Amount::native_whole(1)),
+            ]),
+            ..Default::default()
+        };
+        assert_eq!(
+            namada::ledger::pos::into_tm_voting_power(
+                votes_per_token,
+                dust + dust
+            ),
+            0
+        );
+
+        // Only the bonds to the validator without voting power are dust, even
+        // if the other validators are bonded to with dust amounts too
+        assert_eq!(
+            txs.bonds_with_zero_power_validators(votes_per_token)
+                .unwrap(),
+            vec![&bond("validator-b", dust), &bond("validator-b", dust)]
+        );
+
+        // The sum of the bonds to a validator overflows
+        let txs = Transactions::<Validated> {
+            bond: Some(vec![
+                bond("validator-a", token::Amount::max()),
+                bond("validator-a", dust),
+            ]),
+            ..Default::default()
+        };
+        assert!(matches!(
+            txs.bonds_with_zero_power_validators(votes_per_token),
+            Err(GenesisTxError::AmountOverflow(_))
+        ));
+    }
+
+    /// Test that a re-keyed established account is signed with its new key.
+    #[test]
+    fn test_rekey_established_account() {
+        let old_sk = common_sk_from_simple_seed(0);
+        let new_sk = common_sk_from_simple_seed(1);
+        let unsigned_tx = UnsignedEstablishedAccountTx {
+            alias: Alias::from("albert"),
+            vp: "vp_user".to_string(),
+            public_key: Some(StringEncoded::new(old_sk.ref_to())),
+            storage: BTreeMap::new(),
+        };
+        let tx = sign_established_account_tx_with(unsigned_tx, |_pk| {
+            Ok(old_sk.clone())
+        })
+        .unwrap();
+        let vps = vps(&["vp_user"]);
+
+        // The new key must differ from the old one and match the secret key
+        assert!(matches!(
+            tx.clone().rekey(old_sk.ref_to(), &old_sk).unwrap_err(),
+            GenesisTxError::Signing(
+                SigningError::SameEstablishedAccountKey { .. }
+            )
+        ));
+        assert!(matches!(
+            tx.clone().rekey(new_sk.ref_to(), &old_sk).unwrap_err(),
+            GenesisTxError::Signing(
+                SigningError::EstablishedAccountKeyMismatch { .. }
+            )
+        ));
+
+        let rekeyed = tx.rekey(new_sk.ref_to(), &new_sk).unwrap();
+        let mut established_accounts = BTreeMap::new();
+        assert!(validate_established_account(
+            &rekeyed,
+            Some(&vps),
+            &mut BTreeSet::new(),
+            &mut established_accounts,
+        )
+        .is_ok());
+        assert_eq!(
+            established_accounts,
+            BTreeMap::from([(Alias::from("albert"), Some(new_sk.ref_to()))])
+        );
+    }
+
+    /// Test that a transfer of a token that's not in the tokens file is
+    /// reported with its own error.
+    #[test]
+    fn test_validate_unknown_transfer_token() {
+        let ValidationFixture {
+            templates,
+            native_token,
+            validator_tx,
+            source_sk,
+            balances,
+            vps,
+            ..
+        } = validation_fixture();
+        let validate = |token: &str| {
+            let transfer = transfer_tx(token, &source_sk, "validator", "1")
+                .sign(&source_sk);
+            let txs = Transactions::<Unvalidated> {
+                validator_account: Some(vec![validator_tx.clone()]),
+                transfer: Some(vec![transfer]),
+                ..Default::default()
+            };
+            validate(
+                txs,
+                Some(&vps),
+                Some(&balances),
+                &templates.tokens,
+                Some(&templates.parameters),
+                &Default::default(),
+            )
+            .map_err(validation_errors)
+        };
+
+        assert!(validate(&native_token.to_string()).is_ok());
+        assert_eq!(
+            validate("undeclared").unwrap_err(),
+            vec![
+                ValidationError::UnknownToken {
+                    token: Alias::from("undeclared"),
+                },
+                ValidationError::InvalidTxs,
+            ]
+        );
+    }
+
+    /// Test validating the txs as they're added one set after another.
+    #[test]
+    fn test_genesis_validator_add() {
+        let ValidationFixture {
+            templates,
+            native_token,
+            validator_tx,
+            source_sk,
+            balance,
+            balances,
+            vps,
+            ..
+        } = validation_fixture();
+        let transfer = |amount: &str| Transactions::<Unvalidated> {
+            transfer: Some(vec![
+                transfer_tx(
+                    &native_token.to_string(),
+                    &source_sk,
+                    "validator",
+                    amount,
+                )
+                .sign(&source_sk),
+            ]),
+            ..Default::default()
+        };
+        let mut validator = GenesisValidator::new(
+            Some(&templates.parameters),
+            &templates.tokens,
+            Some(&vps),
+            Some(&balances),
+        );
+
+        // The first contribution declares the target of the second one
+        validator
+            .add(Transactions {
+                validator_account: Some(vec![validator_tx.clone()]),
+                ..Default::default()
+            })
+            .unwrap();
+        validator.add(transfer("60")).unwrap();
+
+        // The balance left from the previous contribution is exceeded and
+        // the invalid contribution isn't added
+        assert_eq!(
+            validator
+                .add(transfer("60"))
+                .map_err(validation_errors)
+                .unwrap_err(),
+            vec![ValidationError::InvalidTxs]
+        );
+        assert_eq!(
+            validator.transactions().transfer.as_ref().unwrap().len(),
+            1
+        );
+
+        // A declared alias cannot be declared again
+        assert_eq!(
+            validator
+                .add(Transactions {
+                    validator_account: Some(vec![validator_tx.clone()]),
+                    ..Default::default()
+                })
+                .map_err(validation_errors)
+                .unwrap_err(),
+            vec![ValidationError::InvalidTxs]
+        );
+
+        validator.add(transfer("40")).unwrap();
+        let txs = validator.into_transactions();
+        assert_eq!(txs.validator_account, Some(vec![validator_tx]));
+        assert_eq!(txs.transfer.unwrap().len(), 2);
+    }
+
+    /// Test summing up the bonds to each validator.
+    #[test]
+    fn test_aggregate_bond_stakes() {
+        let bond = |validator: &str, amount: token::Amount| {
+            bond_tx::<Validated>(
+                AliasOrPk::Alias(Alias::from("source")),
+                validator,
+                DenominatedAmount::native(amount),
+            )
+        };
+        let bonds = [
+            bond("validator-a", token::Amount::native_whole(10)),
+            bond("validator-b", token::Amount::native_whole(5)),
+            bond("validator-a", token::Amount::native_whole(20)),
+        ];
+        assert_eq!(
+            aggregate_bond_stakes(&bonds).unwrap(),
+            BTreeMap::from([
+                (Alias::from("validator-a"), token::Amount::native_whole(30)),
+                (Alias::from("validator-b"), token::Amount::native_whole(5)),
+            ])
+        );
+        assert!(aggregate_bond_stakes(&[]).unwrap().is_empty());
+
+        // The sum of the bonds to a validator overflows
+        let bonds = [
+            bond("validator-a", token::Amount::native_whole(10)),
+            bond("validator-b", token::Amount::max()),
+            bond("validator-b", token::Amount::native_whole(1)),
+        ];
+        assert!(matches!(
+            aggregate_bond_stakes(&bonds),
+            Err(GenesisTxError::AmountOverflow(_))
+        ));
+    }
+
+    /// Test that the txs are verified against their canonical hash, which
+    /// doesn't depend on the order of the txs.
+    #[test]
+    fn test_verify_hash() {
+        let sk = common_sk_from_simple_seed(0);
+        let txs = Transactions::<Unvalidated> {
+            transfer: Some(vec![
+                transfer_tx("nam", &sk, "albert", "1").sign(&sk),
+                transfer_tx("nam", &sk, "bertha", "2").sign(&sk),
+            ]),
+            ..Default::default()
+        };
+        let hash = txs.canonical_hash();
+        assert!(txs.verify_hash(&hash));
+
+        // The order of the txs doesn't matter
+        let mut reordered = txs.clone();
+        reordered.transfer.as_mut().unwrap().reverse();
+        assert!(reordered.verify_hash(&hash));
+
+        // A tampered tx doesn't match
+        let mut tampered = txs;
+        tampered.transfer.as_mut().unwrap()[1] =
+            transfer_tx("nam", &sk, "bertha", "20").sign(&sk);
+        assert!(!tampered.verify_hash(&hash));
+    }
+
+    /// Test that the signable data of bonds with different optional fields
+    /// are not the same, so that a signature of one of them is not valid for
+    /// another.
+    #[test]
+    fn test_bond_signable_data_unambiguous() {
+        let sk = common_sk_from_simple_seed(0);
+        let source = AliasOrPk::PublicKey(StringEncoded::new(sk.ref_to()));
+        let bond = |amount, nonce| BondTx::<Unvalidated> {
+            nonce,
+            ..bond_tx(source.clone(), "validator", amount)
+        };
+        let fixed = || BondAmount::from_str("1").unwrap();
+        let bonds = [
+            bond(fixed(), None),
+            bond(fixed(), Some(1)),
+            bond(BondAmount::AllAvailable, None),
+            bond(BondAmount::AllAvailable, Some(1)),
+        ];
+        let data: BTreeSet<Vec<u8>> = bonds
+            .iter()
+            .map(|bond| bond.data_to_sign(SIGNED_FORMAT_VERSION))
+            .collect();
+        assert_eq!(data.len(), bonds.len());
+
+        let signed = bonds[0].clone().sign(&sk);
+        let replayed = SignedBondTx {
+            version: signed.version,
+            data: bonds[1].clone(),
+            signature: signed.signature.clone(),
+        };
+        assert!(signed.verify_sig(&sk.ref_to()).is_ok());
+        assert!(replayed.verify_sig(&sk.ref_to()).is_err());
+    }
+
+    /// Test that the txs added to a genesis validator are checked against
+    /// its validation options, the same as in `validate`.
+    #[test]
+    fn test_genesis_validator_options() {
+        let ValidationFixture {
+            templates,
+            native_token,
+            source_sk,
+            balances,
+            vps,
+            ..
+        } = validation_fixture();
+        let validator_tx = |alias: &str, seed: u64| {
+            let validator_wallet = validator_wallet(seed);
+            sign_validator_account_tx(
+                unsigned_validator_account_tx(alias, &validator_wallet),
+                &validator_wallet,
+                &[],
+            )
+        };
+        let options = ValidationOptions {
+            max_validators: Some(1),
+            max_transfer: Some(DenominatedAmount::from_str("10").unwrap()),
+            ..Default::default()
+        };
+        let mut validator = GenesisValidator::new(
+            Some(&templates.parameters),
+            &templates.tokens,
+            Some(&vps),
+            Some(&balances),
+        )
+        .with_options(options.clone());
+
+        validator
+            .add(Transactions {
+                validator_account: Some(vec![validator_tx("validator-1", 0)]),
+                ..Default::default()
+            })
+            .unwrap();
+
+        // The maximum number of validators counts the previously added ones
+        assert_eq!(
+            validator
+                .add(Transactions {
+                    validator_account: Some(vec![validator_tx(
+                        "validator-2",
+                        10
+                    )]),
+                    ..Default::default()
+                })
+                .map_err(validation_errors)
+                .unwrap_err(),
+            vec![ValidationError::TooManyValidators { count: 2, max: 1 }]
+        );
+
+        // A transfer over the maximum is invalid
+        let transfer = |amount: &str| Transactions::<Unvalidated> {
+            transfer: Some(vec![
+                transfer_tx(
+                    &native_token.to_string(),
+                    &source_sk,
+                    "validator-1",
+                    amount,
+                )
+                .sign(&source_sk),
+            ]),
+            ..Default::default()
+        };
+        assert_eq!(
+            validator
+                .add(transfer("11"))
+                .map_err(validation_errors)
+                .unwrap_err(),
+            vec![ValidationError::InvalidTxs]
+        );
+        validator.add(transfer("10")).unwrap();
+
+        // Adding all the txs at once gives the same result as validating them
+        // in a batch
+        let all: Transactions<Unvalidated> = Transactions {
+            validator_account: Some(vec![validator_tx("validator-1", 0)]),
+            ..transfer("10")
+        };
+        assert_eq!(
+            &validate(
+                all,
+                Some(&vps),
+                Some(&balances),
+                &templates.tokens,
+                Some(&templates.parameters),
+                &options,
+            )
+            .map_err(validation_errors)
+            .unwrap(),
+            validator.transactions()
+        );
+    }
+}
@@ -2327,6 +2327,95 @@ mod test_finalize_block {
         assert!(token_diff < token_uncertainty);
     }
 
+    /// Test that the queried claimable rewards are equal to the rewards that
+    /// are subsequently claimed
+    #[test]
+    fn test_query_reward_tokens() {
+        let (mut shell, _recv, _, _) = setup_with_cfg(SetupCfg {
+            last_height: 0,
+            num_validators: 1,
+            ..Default::default()
+        });
+        let validator = read_consensus_validator_set_addresses_with_stake(
+            &shell.wl_storage,
+            Epoch::default(),
+        )
+        .unwrap()
+        .into_iter()
+        .next()
+        .unwrap();
+        let params = read_pos_params(&shell.wl_storage).unwrap();
+        let consensus_key = validator_consensus_key_handle(&validator.address)
+            .get(&shell.wl_storage, Epoch::default(), &params)
+            .unwrap()
+            .unwrap();
+        let hash_string = tm_consensus_key_raw_hash(&consensus_key);
+        let pkh1: [u8; 20] = HEXUPPER
+            .decode(hash_string.as_bytes())
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let votes = vec![VoteInfo {
+            validator: Validator {
+                address: pkh1,
+                power: (u128::try_from(validator.bonded_stake).unwrap() as u64)
+                    .try_into()
+                    .unwrap(),
+            },
+            sig_info: tendermint::abci::types::BlockSignatureInfo::LegacySigned,
+        }];
+        let query = |shell: &TestShell, current_epoch: Epoch| {
+            namada_proof_of_stake::query_reward_tokens(
+                &shell.wl_storage,
+                None,
+                &validator.address,
+                current_epoch,
+            )
+            .unwrap()
+        };
+        let claim = |shell: &mut TestShell, current_epoch: Epoch| {
+            namada_proof_of_stake::claim_reward_tokens(
+                &mut shell.wl_storage,
+                None,
+                &validator.address,
+                current_epoch,
+            )
+            .unwrap()
+        };
+
+        // Nothing to claim in the first epoch
+        assert_eq!(query(&shell, Epoch::default()), token::Amount::zero());
+
+        next_block_for_inflation(&mut shell, pkh1.to_vec(), vec![], None);
+        let (current_epoch, _inflation) =
+            advance_epoch(&mut shell, &pkh1, &votes, None);
+
+        // Querying doesn't claim the rewards
+        let claimable = query(&shell, current_epoch);
+        assert!(!claimable.is_zero());
+        assert_eq!(query(&shell, current_epoch), claimable);
+        assert_eq!(claim(&mut shell, current_epoch), claimable);
+
+        // Nothing more to claim in the same epoch
+        assert_eq!(query(&shell, current_epoch), token::Amount::zero());
+
+        // Rewards tallied on unbonding are included too
+        let (current_epoch, _inflation) =
+            advance_epoch(&mut shell, &pkh1, &votes, None);
+        namada_proof_of_stake::unbond_tokens(
+            &mut shell.wl_storage,
+            None,
+            &validator.address,
+            token::Amount::native_whole(50_000),
+            current_epoch,
+            false,
+        )
+        .unwrap();
+        let claimable = query(&shell, current_epoch);
+        assert!(!claimable.is_zero());
+        assert_eq!(claim(&mut shell, current_epoch), claimable);
+    }
+
     /// A unit test for PoS inflationary rewards claiming
     #[test]
     fn test_claim_validator_commissions() {
@@ -4506,6 +4595,127 @@ mod test_finalize_block {
         Ok(())
     }
 
+    /// Test that a change of a validator's consensus key is communicated to
+    /// Tendermint for the pipeline epoch, at which the new key replaces the
+    /// previous one.
+    #[test]
+    fn test_change_consensus_key_validator_updates() -> storage_api::Result<()>
+    {
+        let (mut shell, _recv, _, _) = setup_with_cfg(SetupCfg {
+            last_height: 0,
+            num_validators: 2,
+            ..Default::default()
+        });
+        let params = read_pos_params(&shell.wl_storage).unwrap();
+        let current_epoch = shell.wl_storage.storage.block.epoch;
+        let pipeline_epoch = current_epoch + params.pipeline_len;
+
+        let validator = read_consensus_validator_set_addresses_with_stake(
+            &shell.wl_storage,
+            current_epoch,
+        )?
+        .into_iter()
+        .next()
+        .unwrap()
+        .address;
+        let prev_key = validator_consensus_key_handle(&validator)
+            .get(&shell.wl_storage, current_epoch, &params)?
+            .unwrap();
+        let new_key = gen_keypair().ref_to();
+        namada_proof_of_stake::change_consensus_key(
+            &mut shell.wl_storage,
+            &validator,
+            &new_key,
+            current_epoch,
+        )?;
+
+        // Finalize blocks up to the pipeline epoch, collecting the validator
+        // set updates with the epoch of the block that sent them
+        let mut all_updates = vec![];
+        while shell.wl_storage.storage.block.epoch < pipeline_epoch {
+            let epoch = shell.wl_storage.storage.block.epoch;
+            let proposer = get_pkh_from_address(
+                &shell.wl_storage,
+                &params,
+                validator.clone(),
+                epoch,
+            );
+            let req = FinalizeBlock {
+                header: Header {
+                    time: shell
+                        .wl_storage
+                        .storage
+                        .next_epoch_min_start_time
+                        .next_second(),
+                    ..Default::default()
+                },
+                proposer_address: proposer.to_vec(),
+                votes: get_default_true_votes(&shell.wl_storage, epoch),
+                ..Default::default()
+            };
+            let resp = shell.finalize_block(req).unwrap();
+            shell.commit();
+            all_updates.extend(
+                resp.validator_updates
+                    .into_iter()
+                    .map(|update| (epoch, update)),
+            );
+        }
+
+        let tm_key = |pk: &common::PublicKey| {
+            Some(crate::facade::tendermint_proto::v0_37::crypto::PublicKey {
+                sum: Some(key_to_tendermint(pk).unwrap()),
+            })
+        };
+        // The previous key is removed and the new key is given the
+        // validator's voting power in the same updates, which are sent in the
+        // epoch before the pipeline epoch
+        let prev_key_updates: Vec<_> = all_updates
+            .iter()
+            .filter(|(_epoch, update)| update.pub_key == tm_key(&prev_key))
+            .collect();
+        assert_eq!(prev_key_updates.len(), 1);
+        let (epoch, update) = prev_key_updates[0];
+        assert_eq!(*epoch, pipeline_epoch.prev());
+        assert_eq!(update.power, 0);
+
+        let new_key_updates: Vec<_> = all_updates
+            .iter()
+            .filter(|(_epoch, update)| update.pub_key == tm_key(&new_key))
+            .collect();
+        assert_eq!(new_key_updates.len(), 1);
+        let (epoch, update) = new_key_updates[0];
+        assert_eq!(*epoch, pipeline_epoch.prev());
+        let stake = read_validator_stake(
+            &shell.wl_storage,
+            &params,
+            &validator,
+            pipeline_epoch,
+        )?;
+        assert_eq!(
+            update.power,
+            into_tm_voting_power(params.tm_votes_per_token, stake)
+        );
+
+        // The new key is the validator's key from the pipeline epoch
+        assert_eq!(
+            get_pkh_from_address(
+                &shell.wl_storage,
+                &params,
+                validator,
+                pipeline_epoch,
+            ),
+            <[u8; 20]>::try_from(
+                HEXUPPER
+                    .decode(tm_consensus_key_raw_hash(&new_key).as_bytes())
+                    .unwrap()
+            )
+            .unwrap()
+        );
+
+        Ok(())
+    }
+
     fn get_default_true_votes<S>(storage: &S, epoch: Epoch) -> Vec<VoteInfo>
     where
         S: StorageRead,
@@ -433,6 +433,8 @@ where
                         tendermint_node_key: _,
                         eth_hot_key,
                         eth_cold_key,
+                        threshold,
+                        account_keys,
                     },
             } in txs
             {
@@ -453,6 +455,25 @@ where
                     0,
                 )
                 .unwrap();
+                // Additional validator account keys
+                for (ix, key) in account_keys.iter().enumerate() {
+                    storage_api::account::set_public_key_at(
+                        &mut self.wl_storage,
+                        address,
+                        &key.pk.raw,
+                        (ix + 1) as u8,
+                    )
+                    .unwrap();
+                }
+                // Without a threshold, all the account keys must sign. The
+                // number of keys is bounded in validation.
+                let threshold = (*threshold).unwrap_or_else(|| {
+                    u8::try_from(1 + account_keys.len())
+                        .expect("The number of account keys must fit a u8")
+                });
+                self.wl_storage
+                    .write(&threshold_key(address), threshold)
+                    .expect("Unable to set validator account threshold");
 
                 self.wl_storage
                     .write(&protocol_pk_key(address), &protocol_key.pk.raw)
@@ -42,6 +42,7 @@ use namada::ledger::protocol::{
     apply_wasm_tx, get_fee_unshielding_transaction,
     get_transfer_hash_from_storage, ShellParams,
 };
+use namada::ledger::queries::DEFAULT_MAX_QUERY_DATA_LEN;
 use namada::ledger::storage::wl_storage::WriteLogAndStorage;
 use namada::ledger::storage::write_log::WriteLog;
 use namada::ledger::storage::{
@@ -393,6 +394,9 @@ where
     /// limit the how many block heights in the past can the storage be
     /// queried for reading values.
     storage_read_past_height_limit: Option<u64>,
+    /// Taken from config `max_query_data_len`. The maximum length in bytes
+    /// of the data of a query.
+    max_query_data_len: usize,
     /// Proposal execution tracking
     pub proposal_data: HashSet<u64>,
     /// Log of events emitted by `FinalizeBlock` ABCI calls.
@@ -444,6 +448,10 @@ where
         let mode = config.shell.tendermint_mode;
         let storage_read_past_height_limit =
             config.shell.storage_read_past_height_limit;
+        let max_query_data_len = config
+            .shell
+            .max_query_data_len
+            .unwrap_or(DEFAULT_MAX_QUERY_DATA_LEN);
         if !Path::new(&base_dir).is_dir() {
             std::fs::create_dir(&base_dir)
                 .expect("Creating directory for Namada should not fail");
@@ -562,6 +570,7 @@ where
                 tx_wasm_compilation_cache as usize,
             ),
             storage_read_past_height_limit,
+            max_query_data_len,
             proposal_data: HashSet::new(),
             // TODO: config event log params
             event_log: EventLog::default(),
@@ -595,6 +595,7 @@ impl<'a> Client for &'a MockNode {
             vp_wasm_cache: borrowed.vp_wasm_cache.read_only(),
             tx_wasm_cache: borrowed.tx_wasm_cache.read_only(),
             storage_read_past_height_limit: None,
+            max_query_data_len: borrowed.max_query_data_len,
         };
         if request.path == "/shell/dry_run_tx" {
             dry_run_tx(ctx, &request)
@@ -1229,6 +1229,40 @@ impl DB for RocksDB {
         }
     }
 
+    fn read_subspace_val_last_height(
+        &self,
+        key: &Key,
+        last_height: BlockHeight,
+        oldest_height: BlockHeight,
+    ) -> Result<Option<BlockHeight>> {
+        let diffs_cf = self.get_column_family(DIFFS_CF)?;
+        let mut raw_height = last_height.0;
+        loop {
+            // A "new" diff is written on every write and an "old" one on
+            // every deletion
+            let key_prefix = Key::from(BlockHeight(raw_height).to_db_key());
+            for diff in ["new", "old"] {
+                let diff_key = key_prefix
+                    .push(&diff.to_owned())
+                    .map_err(Error::KeyError)?
+                    .join(key)
+                    .to_string();
+                if self
+                    .0
+                    .get_pinned_cf(diffs_cf, diff_key)
+                    .map_err(|e| Error::DBError(e.into_string()))?
+                    .is_some()
+                {
+                    return Ok(Some(BlockHeight(raw_height)));
+                }
+            }
+            if raw_height <= oldest_height.0 {
+                return Ok(None);
+            }
+            raw_height -= 1;
+        }
+    }
+
     fn write_subspace_val(
         &mut self,
         height: BlockHeight,
@@ -1687,6 +1721,48 @@ mod test {
             .expect("Block should have been written");
     }
 
+    /// Test that the last height at which a value was written is only found
+    /// from the given oldest height.
+    #[test]
+    fn test_read_subspace_val_last_height() {
+        let dir = tempdir().unwrap();
+        let mut db = open(dir.path(), None).unwrap();
+
+        let key = Key::parse("test").unwrap();
+        db.write_subspace_val(BlockHeight(10), &key, vec![1_u8])
+            .unwrap();
+
+        let last_height = BlockHeight(20);
+        for (oldest_height, expected) in [
+            (BlockHeight(0), Some(BlockHeight(10))),
+            (BlockHeight(10), Some(BlockHeight(10))),
+            (BlockHeight(11), None),
+            (last_height, None),
+        ] {
+            assert_eq!(
+                db.read_subspace_val_last_height(
+                    &key,
+                    last_height,
+                    oldest_height,
+                )
+                .unwrap(),
+                expected
+            );
+        }
+
+        // A deletion is found too
+        db.delete_subspace_val(BlockHeight(15), &key).unwrap();
+        assert_eq!(
+            db.read_subspace_val_last_height(
+                &key,
+                last_height,
+                BlockHeight(11),
+            )
+            .unwrap(),
+            Some(BlockHeight(15))
+        );
+    }
+
     #[test]
     fn test_read() {
         let dir = tempdir().unwrap();
@@ -465,6 +465,32 @@ impl DB for MockDB {
         self.read_subspace_val(key)
     }
 
+    fn read_subspace_val_last_height(
+        &self,
+        key: &Key,
+        last_height: BlockHeight,
+        oldest_height: BlockHeight,
+    ) -> Result<Option<BlockHeight>> {
+        let db = self.0.borrow();
+        let mut raw_height = last_height.0;
+        loop {
+            let diff_prefix = Key::from(BlockHeight(raw_height).to_db_key());
+            for diff in ["new", "old"] {
+                let diff_key = diff_prefix
+                    .push(&diff.to_string().to_db_key())
+                    .map_err(Error::KeyError)?
+                    .join(key);
+                if db.contains_key(&diff_key.to_string()) {
+                    return Ok(Some(BlockHeight(raw_height)));
+                }
+            }
+            if raw_height <= oldest_height.0 {
+                return Ok(None);
+            }
+            raw_height -= 1;
+        }
+    }
+
     fn write_subspace_val(
         &mut self,
         height: BlockHeight,
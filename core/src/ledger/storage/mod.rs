@@ -304,6 +304,20 @@ pub trait DB: std::fmt::Debug {
         last_height: BlockHeight,
     ) -> Result<Option<Vec<u8>>>;
 
+    /// Find the last height, from the `oldest_height` up to the
+    /// `last_height`, at which the value of the given account subspace key was
+    /// written or deleted. The key's diffs are looked for from the
+    /// `last_height` backwards, so this is linear in the number of blocks
+    /// searched. Returns `None` when no diff is found in this range, e.g. for a
+    /// key that has never been written or was last written before the
+    /// `oldest_height`.
+    fn read_subspace_val_last_height(
+        &self,
+        key: &Key,
+        last_height: BlockHeight,
+        oldest_height: BlockHeight,
+    ) -> Result<Option<BlockHeight>>;
+
     /// Write the value with the given height and account subspace key to the
     /// DB. Returns the size difference from previous value, if any, or the
     /// size of the value otherwise.
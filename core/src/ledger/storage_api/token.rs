@@ -85,7 +85,10 @@ where
     S: StorageRead + StorageWrite,
 {
     let key = token::denom_key(token);
-    storage.write(&key, denom)
+    storage.write(&key, denom)?;
+    // Register the token to find all the denominations without iterating the
+    // whole storage
+    storage.write(&token::denominated_token_key(token), ())
 }
 
 /// Transfer `token` from `src` to `dest`. Returns an `Err` if `src` has
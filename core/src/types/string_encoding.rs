@@ -12,7 +12,7 @@ use std::ops::Deref;
 use std::str::FromStr;
 
 use bech32::{self, FromBase32, ToBase32, Variant};
-use borsh::{BorshDeserialize, BorshSerialize};
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -149,6 +149,7 @@ pub const fn hrp_len<T: Format>() -> usize {
     Hash,
     BorshSerialize,
     BorshDeserialize,
+    BorshSchema,
 )]
 #[serde(transparent)]
 pub struct StringEncoded<T>
@@ -890,6 +890,8 @@ impl From<DenominatedAmount> for IbcAmount {
 pub const BALANCE_STORAGE_KEY: &str = "balance";
 /// Key segment for a denomination key
 pub const DENOM_STORAGE_KEY: &str = "denomination";
+/// Key segment for the registry of the tokens with a denomination
+pub const DENOMINATED_STORAGE_KEY: &str = "denominated";
 /// Key segment for multitoken minter
 pub const MINTER_STORAGE_KEY: &str = "minter";
 /// Key segment for minted balance
@@ -1109,6 +1111,39 @@ pub fn denom_key(token_addr: &Address) -> Key {
         .expect("Cannot obtain a storage key")
 }
 
+/// Obtain a storage key prefix of the registry of the tokens that have their
+/// denomination written in storage.
+pub fn denominated_tokens_prefix() -> Key {
+    Key::from(Address::Internal(InternalAddress::Multitoken).to_db_key())
+        .push(&DENOMINATED_STORAGE_KEY.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Obtain a storage key of the registry entry of a token that has its
+/// denomination written in storage.
+pub fn denominated_token_key(token_addr: &Address) -> Key {
+    denominated_tokens_prefix()
+        .push(&token_addr.to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Check if the given storage key is a key of the registry of the tokens that
+/// have their denomination written in storage. If it is, returns the token.
+pub fn is_denominated_token_key(key: &Key) -> Option<&Address> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::AddressSeg(token),
+        ] if *addr == Address::Internal(InternalAddress::Multitoken)
+            && prefix == DENOMINATED_STORAGE_KEY =>
+        {
+            Some(token)
+        }
+        _ => None,
+    }
+}
+
 /// Check if the given storage key is a denomination key for the given token.
 pub fn is_denom_key(token_addr: &Address, key: &Key) -> bool {
     matches!(&key.segments[..],
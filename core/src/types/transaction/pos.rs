@@ -2,11 +2,12 @@
 
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 use crate::types::address::Address;
 use crate::types::dec::Dec;
 use crate::types::hash::Hash;
-use crate::types::key::{common, secp256k1};
+use crate::types::key::{common, ed25519, secp256k1};
 use crate::types::token;
 
 /// A tx data type to initialize a new validator account.
@@ -53,6 +54,38 @@ pub struct InitValidator {
     pub validator_vp_code_hash: Hash,
 }
 
+/// An error from the validation of the schemes of a validator's keys
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum KeySchemeError {
+    /// The consensus key is not an ed25519 key, which is the only scheme
+    /// supported by Tendermint
+    #[error("The consensus key must be an ed25519 key, got {0}")]
+    ConsensusKeyNotEd25519(common::PublicKey),
+}
+
+impl InitValidator {
+    /// Get the consensus key as an ed25519 key, which is the only scheme
+    /// supported by Tendermint.
+    pub fn consensus_key_ed25519(
+        &self,
+    ) -> Result<&ed25519::PublicKey, KeySchemeError> {
+        match &self.consensus_key {
+            common::PublicKey::Ed25519(pk) => Ok(pk),
+            pk @ common::PublicKey::Secp256k1(_) => {
+                Err(KeySchemeError::ConsensusKeyNotEd25519(pk.clone()))
+            }
+        }
+    }
+
+    /// Check that the validator's keys use the required schemes. The Eth
+    /// keys are secp256k1 by their type, so only the consensus key has to be
+    /// checked.
+    pub fn validate_key_schemes(&self) -> Result<(), KeySchemeError> {
+        self.consensus_key_ed25519()?;
+        Ok(())
+    }
+}
+
 /// A bond is a validator's self-bond or a delegation from non-validator to a
 /// validator.
 #[derive(
@@ -193,3 +226,93 @@ pub struct MetaDataChange {
     /// Validator's commission rate
     pub commission_rate: Option<Dec>,
 }
+
+/// A change to the validator's consensus key, taking effect at the pipeline
+/// offset.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshSchema,
+    Hash,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+pub struct ConsensusKeyChange {
+    /// Validator address
+    pub validator: Address,
+    /// The new consensus key
+    pub new_consensus_key: common::PublicKey,
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh_ext::BorshSerializeExt;
+
+    use super::*;
+    use crate::types::address::testing::established_address_1;
+    use crate::types::key::testing::{keypair_1, keypair_2, keypair_3};
+    use crate::types::key::RefTo;
+
+    fn init_validator(consensus_key: common::PublicKey) -> InitValidator {
+        let eth_key = match keypair_3().ref_to() {
+            common::PublicKey::Secp256k1(pk) => pk,
+            common::PublicKey::Ed25519(_) => unreachable!(),
+        };
+        InitValidator {
+            account_keys: vec![keypair_1().ref_to()],
+            threshold: 1,
+            consensus_key,
+            eth_cold_key: eth_key.clone(),
+            eth_hot_key: eth_key,
+            protocol_key: keypair_2().ref_to(),
+            commission_rate: Dec::new(5, 2).unwrap(),
+            max_commission_rate_change: Dec::new(1, 2).unwrap(),
+            email: "validator@namada.net".to_string(),
+            description: None,
+            website: None,
+            discord_handle: None,
+            validator_vp_code_hash: Hash::zero(),
+        }
+    }
+
+    /// Test that an ed25519 consensus key is accepted.
+    #[test]
+    fn test_validate_key_schemes_ed25519_consensus_key() {
+        let tx = init_validator(keypair_1().ref_to());
+        assert!(tx.validate_key_schemes().is_ok());
+        assert!(tx.consensus_key_ed25519().is_ok());
+    }
+
+    /// Test that a secp256k1 consensus key is rejected.
+    #[test]
+    fn test_validate_key_schemes_secp256k1_consensus_key() {
+        let consensus_key = keypair_3().ref_to();
+        let tx = init_validator(consensus_key.clone());
+        assert_eq!(
+            tx.validate_key_schemes(),
+            Err(KeySchemeError::ConsensusKeyNotEd25519(consensus_key))
+        );
+    }
+
+    /// Test that a consensus key change round-trips through Borsh and serde
+    /// encoding.
+    #[test]
+    fn test_consensus_key_change_serialization() {
+        let change = ConsensusKeyChange {
+            validator: established_address_1(),
+            new_consensus_key: keypair_1().ref_to(),
+        };
+
+        let bytes = change.serialize_to_vec();
+        let decoded = ConsensusKeyChange::try_from_slice(&bytes).unwrap();
+        assert_eq!(decoded, change);
+
+        let json = serde_json::to_string(&change).unwrap();
+        let decoded: ConsensusKeyChange = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, change);
+    }
+}
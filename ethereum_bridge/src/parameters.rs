@@ -3,10 +3,11 @@ use std::num::NonZeroU64;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use eyre::{eyre, Result};
-use namada_core::ledger::eth_bridge::storage::whitelist;
+use namada_core::ledger::eth_bridge::storage::{whitelist, wrapped_erc20s};
 use namada_core::ledger::storage;
 use namada_core::ledger::storage::types::encode;
 use namada_core::ledger::storage::WlStorage;
+use namada_core::ledger::storage_api::token::write_denom;
 use namada_core::ledger::storage_api::{StorageRead, StorageWrite};
 use namada_core::types::ethereum_events::EthAddress;
 use namada_core::types::ethereum_structs;
@@ -234,12 +235,10 @@ impl EthereumBridgeParams {
             .into();
             wl_storage.write_bytes(&key, encode(cap)).unwrap();
 
-            let key = whitelist::Key {
-                asset: *addr,
-                suffix: whitelist::KeyType::Denomination,
-            }
-            .into();
-            wl_storage.write_bytes(&key, encode(denom)).unwrap();
+            // The denomination is written to the same key as
+            // `whitelist::KeyType::Denomination`
+            write_denom(wl_storage, &wrapped_erc20s::token(addr), *denom)
+                .unwrap();
         }
         // Initialize the storage for the Ethereum Bridge VP.
         vp::init_storage(wl_storage);
@@ -256,7 +256,8 @@ where
         storage.read(&key)
     }
 
-    fn get_data_handler(&self) -> LazyMap<Epoch, Data> {
+    /// Get handle to the raw LazyMap data
+    pub fn get_data_handler(&self) -> LazyMap<Epoch, Data> {
         let key = self
             .storage_prefix
             .push(&LAZY_MAP_SUB_KEY.to_owned())
@@ -113,6 +113,15 @@ pub enum CommissionRateChangeError {
     CannotRead(Address),
 }
 
+#[allow(missing_docs)]
+#[derive(Error, Debug)]
+pub enum ConsensusKeyChangeError {
+    #[error("The given address {0} is not a validator address")]
+    NotAValidator(Address),
+    #[error("The consensus key is already in use")]
+    KeyAlreadyUsed,
+}
+
 #[allow(missing_docs)]
 #[derive(Error, Debug)]
 pub enum UnjailValidatorError {
@@ -163,6 +172,16 @@ pub enum ReactivationError {
     NoStateFound(Address, Epoch),
 }
 
+#[allow(missing_docs)]
+#[derive(Error, Debug)]
+pub enum ClaimRewardsError {
+    #[error(
+        "Rewards cannot be claimed until the epoch {0}, which is past the \
+         current epoch {1}"
+    )]
+    EpochInFuture(Epoch, Epoch),
+}
+
 #[allow(missing_docs)]
 #[derive(Error, Debug)]
 pub enum MetadataError {
@@ -206,6 +225,12 @@ impl From<InflationError> for storage_api::Error {
     }
 }
 
+impl From<ConsensusKeyChangeError> for storage_api::Error {
+    fn from(err: ConsensusKeyChangeError) -> Self {
+        Self::new(err)
+    }
+}
+
 impl From<UnjailValidatorError> for storage_api::Error {
     fn from(err: UnjailValidatorError) -> Self {
         Self::new(err)
@@ -230,6 +255,12 @@ impl From<ReactivationError> for storage_api::Error {
     }
 }
 
+impl From<ClaimRewardsError> for storage_api::Error {
+    fn from(err: ClaimRewardsError) -> Self {
+        Self::new(err)
+    }
+}
+
 impl From<MetadataError> for storage_api::Error {
     fn from(err: MetadataError) -> Self {
         Self::new(err)
@@ -48,11 +48,12 @@ pub use parameters::{OwnedPosParams, PosParams};
 use rewards::PosRewardsCalculator;
 use storage::{
     bonds_for_source_prefix, bonds_prefix, consensus_keys_key,
-    get_validator_address_from_bond, is_bond_key, is_unbond_key,
+    get_validator_address_from_bond,
+    get_validator_address_from_rewards_counter, is_bond_key, is_unbond_key,
     is_validator_slashes_key, last_block_proposer_key,
     last_pos_reward_claim_epoch_key, params_key, rewards_counter_key,
-    slashes_prefix, unbonds_for_source_prefix, unbonds_prefix,
-    validator_address_raw_hash_key, validator_description_key,
+    rewards_counter_source_prefix, slashes_prefix, unbonds_for_source_prefix,
+    unbonds_prefix, validator_address_raw_hash_key, validator_description_key,
     validator_discord_key, validator_email_key, validator_last_slash_key,
     validator_max_commission_rate_change_key, validator_website_key,
 };
@@ -3006,6 +3007,42 @@ where
     commission_handle.set(storage, new_rate, current_epoch, params.pipeline_len)
 }
 
+/// Change the consensus key of a validator. The new key takes effect at the
+/// pipeline offset. The new key must not be already used by any validator.
+pub fn change_consensus_key<S>(
+    storage: &mut S,
+    validator: &Address,
+    consensus_key: &common::PublicKey,
+    current_epoch: Epoch,
+) -> storage_api::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    tracing::debug!("Changing consensus key for validator {}", validator);
+
+    if !is_validator(storage, validator)? {
+        return Err(
+            ConsensusKeyChangeError::NotAValidator(validator.clone()).into()
+        );
+    }
+    if is_consensus_key_used(storage, consensus_key)? {
+        return Err(ConsensusKeyChangeError::KeyAlreadyUsed.into());
+    }
+    try_insert_consensus_key(storage, consensus_key)?;
+
+    let params = read_pos_params(storage)?;
+    validator_consensus_key_handle(validator).set(
+        storage,
+        consensus_key.clone(),
+        current_epoch,
+        params.pipeline_len,
+    )?;
+
+    // Map the new key's raw hash to the validator too. The mapping for the
+    // previous key is kept, as it remains active until the pipeline epoch.
+    write_validator_address_raw_hash(storage, validator, consensus_key)
+}
+
 /// Check if the given consensus key is already being used to ensure uniqueness.
 ///
 /// If it's not being used, it will be inserted into the set that's being used
@@ -3305,7 +3342,7 @@ where
 
     let new_consensus_validators = new_consensus_validator_handle
         .iter(storage)?
-        .filter_map(|validator| {
+        .flat_map(|validator| {
             let (
                 NestedSubKey::Data {
                     key: new_stake,
@@ -3319,6 +3356,12 @@ where
                 new_stake.to_string_native()
             );
 
+            let consensus_key = validator_consensus_key_handle(&address)
+                .get(storage, next_epoch, params)
+                .unwrap()
+                .unwrap();
+            let mut updates = Vec::with_capacity(2);
+
             // Check if the validator was consensus in the previous epoch with
             // the same stake. If so, no updated is needed.
             // Look up previous state and prev and current voting powers
@@ -3342,17 +3385,30 @@ where
                 let new_tm_voting_power = Lazy::new(|| {
                     into_tm_voting_power(params.tm_votes_per_token, new_stake)
                 });
+                let was_consensus =
+                    matches!(prev_state, Some(ValidatorState::Consensus));
+                // The key that Tendermint knows the validator by, if the
+                // validator changed its consensus key for the next epoch
+                let prev_consensus_key = if was_consensus {
+                    validator_consensus_key_handle(&address)
+                        .get(storage, current_epoch, params)
+                        .unwrap()
+                        .filter(|prev_key| prev_key != &consensus_key)
+                } else {
+                    None
+                };
 
-                // If it was in `Consensus` before and voting power has not
-                // changed, skip the update
-                if matches!(prev_state, Some(ValidatorState::Consensus))
+                // If it was in `Consensus` before with the same key and voting
+                // power has not changed, skip the update
+                if was_consensus
+                    && prev_consensus_key.is_none()
                     && *prev_tm_voting_power == *new_tm_voting_power
                 {
                     tracing::debug!(
                         "skipping validator update, {address} is in consensus \
                          set but voting power hasn't changed"
                     );
-                    return None;
+                    return updates;
                 }
                 // If both previous and current voting powers are 0, and the
                 // validator_stake_threshold is 0, skip update
@@ -3364,22 +3420,37 @@ where
                         "skipping validator update, {address} is in consensus \
                          set but without voting power"
                     );
-                    return None;
+                    return updates;
                 }
                 // TODO: maybe debug_assert that the new stake is >= threshold?
+
+                if let Some(prev_consensus_key) = prev_consensus_key {
+                    tracing::debug!(
+                        "{address} changed consensus key from {} to {}",
+                        prev_consensus_key.tm_raw_hash(),
+                        consensus_key.tm_raw_hash()
+                    );
+                    // Tendermint can only remove a key that has voting power
+                    // and only add one with voting power
+                    if *prev_tm_voting_power != 0 {
+                        updates.push(ValidatorSetUpdate::Deactivated(
+                            prev_consensus_key,
+                        ));
+                    }
+                    if *new_tm_voting_power == 0 {
+                        return updates;
+                    }
+                }
             }
-            let consensus_key = validator_consensus_key_handle(&address)
-                .get(storage, next_epoch, params)
-                .unwrap()
-                .unwrap();
             tracing::debug!(
                 "{address} consensus key {}",
                 consensus_key.tm_raw_hash()
             );
-            Some(ValidatorSetUpdate::Consensus(ConsensusValidator {
+            updates.push(ValidatorSetUpdate::Consensus(ConsensusValidator {
                 consensus_key,
                 bonded_stake: new_stake,
-            }))
+            }));
+            updates
         });
 
     let prev_consensus_validators = prev_consensus_validator_handle
@@ -3429,9 +3500,10 @@ where
             }
 
             // The remaining validators were previously Consensus but no longer
-            // are, so they must be deactivated
+            // are, so they must be deactivated by the key that Tendermint
+            // knows them by, which changes only at the next epoch
             let consensus_key = validator_consensus_key_handle(&address)
-                .get(storage, next_epoch, params)
+                .get(storage, current_epoch, params)
                 .unwrap()
                 .unwrap();
             tracing::debug!(
@@ -3472,6 +3544,31 @@ where
     Ok(delegations)
 }
 
+/// Find all validators from which a given `source` has rewards tallied in its
+/// rewards counters, e.g. from bonds that have been unbonded since
+pub fn find_rewards_counter_validators<S>(
+    storage: &S,
+    source: &Address,
+) -> storage_api::Result<HashSet<Address>>
+where
+    S: StorageRead,
+{
+    let prefix = rewards_counter_source_prefix(source);
+    let mut validators: HashSet<Address> = HashSet::new();
+
+    for iter_result in storage_api::iter_prefix_bytes(storage, &prefix)? {
+        let (key, _counter_bytes) = iter_result?;
+        let validator = get_validator_address_from_rewards_counter(&key)
+            .ok_or_else(|| {
+                storage_api::Error::new_const(
+                    "Rewards counter key should contain validator address.",
+                )
+            })?;
+        validators.insert(validator);
+    }
+    Ok(validators)
+}
+
 /// Find all validators to which a given bond `owner` (or source) has a
 /// delegation with the amount
 pub fn find_delegations<S>(
@@ -4262,6 +4359,162 @@ where
     Ok(Dec::new(9, 0).unwrap() * sum_vp_fraction * sum_vp_fraction)
 }
 
+/// Estimate the worst-case amount of an unbond of the given `amount` from the
+/// bond of the `source` (or a self-bond when `None`) to the `validator` that
+/// can be slashed by the validator's enqueued slashes, which are not yet
+/// processed. The rates of the enqueued slashes are estimated from the
+/// infractions recorded so far, so further infractions in the cubic slashing
+/// window may still increase them. The `amount` is capped at the bonded
+/// amount.
+pub fn estimate_unbond_slash_exposure<S>(
+    storage: &S,
+    source: Option<&Address>,
+    validator: &Address,
+    amount: token::Amount,
+    current_epoch: Epoch,
+) -> storage_api::Result<token::Amount>
+where
+    S: StorageRead,
+{
+    let params = read_pos_params(storage)?;
+    let source = source.cloned().unwrap_or_else(|| validator.clone());
+    let pipeline_epoch = current_epoch + params.pipeline_len;
+    let bonded = bond_handle(&source, validator)
+        .get_sum(storage, pipeline_epoch, &params)?
+        .unwrap_or_default();
+    let amount = cmp::min(amount, bonded);
+
+    let enqueued_slashes = find_all_enqueued_slashes(storage, current_epoch)?
+        .remove(validator)
+        .unwrap_or_default();
+    let mut slash_rate = Dec::zero();
+    for slash in enqueued_slashes.into_values().flatten() {
+        let cubic_slash_rate =
+            compute_cubic_slash_rate(storage, &params, slash.epoch)?;
+        slash_rate +=
+            cmp::max(slash.r#type.get_slash_rate(&params), cubic_slash_rate);
+    }
+    Ok(cmp::min(Dec::one(), slash_rate) * amount)
+}
+
+/// Find the amounts slashed from the bond of the `source` (or a self-bond
+/// when `None`) to the `validator` by the validator's processed slashes,
+/// grouped by the epoch of the slashed infraction. The bonds that have been
+/// unbonded, but not withdrawn yet, are included. Slashes that don't affect
+/// the bond, e.g. those for infractions before the bond's start or after an
+/// unbond's end, are not included. The tokens of the bond that have been
+/// redelegated from another validator are also slashed by the source
+/// validator's slashes from before the redelegation, as in
+/// `compute_amount_after_slashing_withdraw`, and these are included too.
+pub fn bond_slashes<S>(
+    storage: &S,
+    source: Option<&Address>,
+    validator: &Address,
+) -> storage_api::Result<Vec<(Epoch, token::Amount)>>
+where
+    S: StorageRead,
+{
+    let params = read_pos_params(storage)?;
+    let source = source.cloned().unwrap_or_else(|| validator.clone());
+    let mut slashes = find_validator_slashes(storage, validator)?;
+    slashes.sort_by_key(|slash| slash.epoch);
+
+    // The bonds with their start epoch, the end epoch of the unbonded ones,
+    // which are only slashable for infractions before the end, and their
+    // tokens redelegated from other validators
+    let redelegated_bonds =
+        delegator_redelegated_bonds_handle(&source).at(validator);
+    let mut bonds: Vec<(
+        Epoch,
+        Option<Epoch>,
+        token::Amount,
+        EagerRedelegatedBondsMap,
+    )> = vec![];
+    for (start, amount) in find_bonds(storage, &source, validator)? {
+        let redelegated = redelegated_bonds.at(&start).collect_map(storage)?;
+        bonds.push((start, None, amount, redelegated));
+    }
+    let redelegated_unbonds =
+        delegator_redelegated_unbonds_handle(&source).at(validator);
+    for unbond in unbond_handle(&source, validator).iter(storage)? {
+        let (
+            NestedSubKey::Data {
+                key: start,
+                nested_sub_key: SubKey::Data(withdraw_epoch),
+            },
+            amount,
+        ) = unbond?;
+        let end = withdraw_epoch
+            - params.unbonding_len
+            - params.cubic_slashing_window_length;
+        let redelegated = redelegated_unbonds
+            .at(&start)
+            .at(&withdraw_epoch)
+            .collect_map(storage)?;
+        bonds.push((start, Some(end), amount, redelegated));
+    }
+
+    let mut slashed_amounts = BTreeMap::<Epoch, token::Amount>::new();
+    // Apply the slashes, ordered by their epochs, to an amount, the same as
+    // in `apply_list_slashes`
+    let mut apply_slashes = |slashes: &[Slash], amount: token::Amount| {
+        let mut computed_slashes = BTreeMap::<Epoch, token::Amount>::new();
+        for slash in slashes {
+            let slashed_amount = compute_slashable_amount(
+                &params,
+                slash,
+                amount,
+                &computed_slashes,
+            );
+            computed_slashes.insert(slash.epoch, slashed_amount);
+            *slashed_amounts.entry(slash.epoch).or_default() += slashed_amount;
+        }
+    };
+    for (start, end, amount, redelegated) in bonds {
+        let list_slashes: Vec<Slash> = slashes
+            .iter()
+            .filter(|slash| {
+                start <= slash.epoch
+                    && end.map_or(true, |end| end > slash.epoch)
+            })
+            .cloned()
+            .collect();
+
+        // The redelegated tokens are also slashed by the source validator's
+        // slashes in the redelegation's slashing window, as in
+        // `fold_and_slash_redelegated_bonds`
+        let mut total_redelegated = token::Amount::zero();
+        for (src_validator, src_bonds) in redelegated {
+            let src_slashes = find_validator_slashes(storage, &src_validator)?;
+            for (bond_start, change) in src_bonds {
+                let mut merged: Vec<Slash> = src_slashes
+                    .iter()
+                    .filter(|slash| {
+                        params.in_redelegation_slashing_window(
+                            slash.epoch,
+                            params.redelegation_start_epoch_from_end(start),
+                            start,
+                        ) && bond_start <= slash.epoch
+                    })
+                    .chain(list_slashes.iter())
+                    .cloned()
+                    .collect();
+                merged.sort_by_key(|slash| slash.epoch);
+                apply_slashes(&merged, change);
+                total_redelegated += change;
+            }
+        }
+
+        let not_redelegated =
+            amount.checked_sub(total_redelegated).unwrap_or_default();
+        apply_slashes(&list_slashes, not_redelegated);
+    }
+    Ok(slashed_amounts
+        .into_iter()
+        .filter(|(_epoch, amount)| !amount.is_zero())
+        .collect())
+}
+
 /// Record a slash for a misbehavior that has been received from Tendermint and
 /// then jail the validator, removing it from the validator set. The slash rate
 /// will be computed at a later epoch.
@@ -5839,7 +6092,10 @@ where
 
 /// Change validator's metadata. In addition to changing any of the data from
 /// [`ValidatorMetaData`], the validator's commission rate can be changed within
-/// here as well.
+/// here as well. The commission rate change is subject to the same checks as
+/// in [`change_validator_commission_rate`], including the validator's maximum
+/// rate change per epoch, and it's applied first so that no metadata gets
+/// changed when it's rejected.
 #[allow(clippy::too_many_arguments)]
 pub fn change_validator_metadata<S>(
     storage: &mut S,
@@ -5854,6 +6110,14 @@ pub fn change_validator_metadata<S>(
 where
     S: StorageRead + StorageWrite,
 {
+    if let Some(commission_rate) = commission_rate {
+        change_validator_commission_rate(
+            storage,
+            validator,
+            commission_rate,
+            current_epoch,
+        )?;
+    }
     if let Some(email) = email {
         write_validator_email(storage, validator, &email)?;
     }
@@ -5866,14 +6130,6 @@ where
     if let Some(discord) = discord_handle {
         write_validator_discord_handle(storage, validator, &discord)?;
     }
-    if let Some(commission_rate) = commission_rate {
-        change_validator_commission_rate(
-            storage,
-            validator,
-            commission_rate,
-            current_epoch,
-        )?;
-    }
     Ok(())
 }
 
@@ -5889,24 +6145,204 @@ where
 {
     tracing::debug!("Claiming rewards in epoch {current_epoch}");
 
-    let rewards_products = validator_rewards_products_handle(validator);
     let source = source.cloned().unwrap_or_else(|| validator.clone());
     tracing::debug!("Source {} --> Validator {}", source, validator);
 
+    let reward_tokens =
+        match compute_bond_rewards(storage, &source, validator, current_epoch)?
+        {
+            Some(reward_tokens) => reward_tokens,
+            None => return Ok(token::Amount::zero()),
+        };
+
+    pay_reward_tokens(storage, &source, validator, current_epoch, reward_tokens)
+}
+
+/// Claim the rewards of the `source` from all the validators that it's
+/// bonded to or that it has rewards tallied in the rewards counter from.
+/// Validators with nothing to claim are skipped, without updating the last
+/// claim epoch. Returns the total amount of claimed rewards.
+pub fn claim_all_reward_tokens<S>(
+    storage: &mut S,
+    source: &Address,
+    current_epoch: Epoch,
+) -> storage_api::Result<token::Amount>
+where
+    S: StorageRead + StorageWrite,
+{
+    tracing::debug!(
+        "Claiming all rewards of {source} in epoch {current_epoch}"
+    );
+
+    // Sorted for a deterministic order of the claims
+    let mut validators: BTreeSet<Address> =
+        find_delegation_validators(storage, source)?
+            .into_iter()
+            .collect();
+    validators.extend(find_rewards_counter_validators(storage, source)?);
+
+    let mut total = token::Amount::zero();
+    for validator in validators {
+        let reward_tokens = match compute_bond_rewards(
+            storage,
+            source,
+            &validator,
+            current_epoch,
+        )? {
+            Some(reward_tokens) => reward_tokens,
+            None => continue,
+        };
+        if reward_tokens.is_zero()
+            && !storage.has_key(&rewards_counter_key(source, &validator))?
+        {
+            continue;
+        }
+        total += pay_reward_tokens(
+            storage,
+            source,
+            &validator,
+            current_epoch,
+            reward_tokens,
+        )?;
+    }
+    Ok(total)
+}
+
+/// Pay out the computed rewards of a bond together with the rewards tallied
+/// in the rewards counter and update the last claim epoch. Returns the total
+/// amount paid out.
+fn pay_reward_tokens<S>(
+    storage: &mut S,
+    source: &Address,
+    validator: &Address,
+    current_epoch: Epoch,
+    mut reward_tokens: token::Amount,
+) -> storage_api::Result<token::Amount>
+where
+    S: StorageRead + StorageWrite,
+{
+    // Add reward tokens tallied during previous withdrawals
+    reward_tokens += take_rewards_from_counter(storage, source, validator)?;
+
+    // Update the last claim epoch in storage
+    write_last_reward_claim_epoch(storage, source, validator, current_epoch)?;
+
+    // Transfer the bonded tokens from PoS to the source
+    let staking_token = staking_token_address(storage);
+    token::transfer(storage, &staking_token, &ADDRESS, source, reward_tokens)?;
+
+    Ok(reward_tokens)
+}
+
+/// Claim the rewards of a bond accrued before the `until_epoch`, leaving the
+/// rewards of the later epochs unclaimed. The `until_epoch` must not be past
+/// the `current_epoch`. Claiming until the current epoch is the same as
+/// [`claim_reward_tokens`], otherwise the rewards tallied in the rewards
+/// counter during unbonding are left unclaimed, as they're not split by
+/// epoch.
+pub fn claim_reward_tokens_until<S>(
+    storage: &mut S,
+    source: Option<&Address>,
+    validator: &Address,
+    until_epoch: Epoch,
+    current_epoch: Epoch,
+) -> storage_api::Result<token::Amount>
+where
+    S: StorageRead + StorageWrite,
+{
+    if until_epoch > current_epoch {
+        return Err(ClaimRewardsError::EpochInFuture(
+            until_epoch,
+            current_epoch,
+        )
+        .into());
+    }
+    if until_epoch == current_epoch {
+        return claim_reward_tokens(storage, source, validator, current_epoch);
+    }
+    tracing::debug!("Claiming rewards until epoch {until_epoch}");
+
+    let source = source.cloned().unwrap_or_else(|| validator.clone());
+    tracing::debug!("Source {} --> Validator {}", source, validator);
+
+    if let Some(last_claim_epoch) =
+        get_last_reward_claim_epoch(storage, &source, validator)?
+    {
+        if last_claim_epoch > until_epoch {
+            // The rewards up to this epoch have already been claimed
+            return Ok(token::Amount::zero());
+        }
+    }
+
+    let reward_tokens =
+        match compute_bond_rewards(storage, &source, validator, until_epoch)? {
+            Some(reward_tokens) => reward_tokens,
+            None => return Ok(token::Amount::zero()),
+        };
+
+    // Update the last claim epoch in storage
+    write_last_reward_claim_epoch(storage, &source, validator, until_epoch)?;
+
+    // Transfer the bonded tokens from PoS to the source
+    let staking_token = staking_token_address(storage);
+    token::transfer(storage, &staking_token, &ADDRESS, &source, reward_tokens)?;
+
+    Ok(reward_tokens)
+}
+
+/// Query the rewards that can be claimed in the current epoch, without
+/// claiming them
+pub fn query_reward_tokens<S>(
+    storage: &S,
+    source: Option<&Address>,
+    validator: &Address,
+    current_epoch: Epoch,
+) -> storage_api::Result<token::Amount>
+where
+    S: StorageRead,
+{
+    let source = source.cloned().unwrap_or_else(|| validator.clone());
+    let reward_tokens =
+        match compute_bond_rewards(storage, &source, validator, current_epoch)?
+        {
+            Some(reward_tokens) => reward_tokens,
+            None => return Ok(token::Amount::zero()),
+        };
+
+    // Add reward tokens tallied during previous withdrawals
+    let counter_rewards = storage
+        .read::<token::Amount>(&rewards_counter_key(&source, validator))?
+        .unwrap_or_default();
+    Ok(reward_tokens + counter_rewards)
+}
+
+/// Compute the rewards of a bond from the epoch of the last claim up to the
+/// last epoch, excluding the rewards tallied in the rewards counter. Returns
+/// `None` when nothing can be claimed in the current epoch.
+fn compute_bond_rewards<S>(
+    storage: &S,
+    source: &Address,
+    validator: &Address,
+    current_epoch: Epoch,
+) -> storage_api::Result<Option<token::Amount>>
+where
+    S: StorageRead,
+{
     if current_epoch == Epoch::default() {
         // Nothing to claim in the first epoch
-        return Ok(token::Amount::zero());
+        return Ok(None);
     }
 
     let last_claim_epoch =
-        get_last_reward_claim_epoch(storage, &source, validator)?;
+        get_last_reward_claim_epoch(storage, source, validator)?;
     if let Some(last_epoch) = last_claim_epoch {
         if last_epoch == current_epoch {
             // Already claimed in this epoch
-            return Ok(token::Amount::zero());
+            return Ok(None);
         }
     }
 
+    let rewards_products = validator_rewards_products_handle(validator);
     let mut reward_tokens = token::Amount::zero();
 
     // Want to claim from `last_claim_epoch` to `current_epoch.prev()` since
@@ -5932,18 +6368,7 @@ where
         let reward = rp * bond_amount;
         reward_tokens += reward;
     }
-
-    // Add reward tokens tallied during previous withdrawals
-    reward_tokens += take_rewards_from_counter(storage, &source, validator)?;
-
-    // Update the last claim epoch in storage
-    write_last_reward_claim_epoch(storage, &source, validator, current_epoch)?;
-
-    // Transfer the bonded tokens from PoS to the source
-    let staking_token = staking_token_address(storage);
-    token::transfer(storage, &staking_token, &ADDRESS, &source, reward_tokens)?;
-
-    Ok(reward_tokens)
+    Ok(Some(reward_tokens))
 }
 
 /// Get the last epoch in which rewards were claimed from storage, if any
@@ -268,15 +268,30 @@ pub fn rewards_counter_prefix() -> Key {
         .expect("Cannot obtain a storage key")
 }
 
-/// Storage key for rewards counter.
-pub fn rewards_counter_key(source: &Address, validator: &Address) -> Key {
+/// Storage prefix for the rewards counters of the given source.
+pub fn rewards_counter_source_prefix(source: &Address) -> Key {
     rewards_counter_prefix()
         .push(&source.to_db_key())
         .expect("Cannot obtain a storage key")
+}
+
+/// Storage key for rewards counter.
+pub fn rewards_counter_key(source: &Address, validator: &Address) -> Key {
+    rewards_counter_source_prefix(source)
         .push(&validator.to_db_key())
         .expect("Cannot obtain a storage key")
 }
 
+/// Get validator address from a rewards counter key
+pub fn get_validator_address_from_rewards_counter(
+    key: &Key,
+) -> Option<Address> {
+    match key.get_at(3) {
+        Some(DbKeySeg::AddressSeg(addr)) => Some(addr.clone()),
+        _ => None,
+    }
+}
+
 /// Storage key for a validator's incoming redelegations, where the prefixed
 /// validator is the destination validator.
 pub fn validator_incoming_redelegations_key(validator: &Address) -> Key {
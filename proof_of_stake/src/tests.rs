@@ -51,7 +51,7 @@ use crate::types::{
 };
 use crate::{
     apply_list_slashes, become_validator, below_capacity_validator_set_handle,
-    bond_handle, bond_tokens, bonds_and_unbonds,
+    bond_handle, bond_tokens, bonds_and_unbonds, change_consensus_key,
     compute_amount_after_slashing_unbond,
     compute_amount_after_slashing_withdraw, compute_bond_at_epoch,
     compute_modified_redelegation, compute_new_redelegated_unbonds,
@@ -1277,6 +1277,80 @@ fn test_validator_raw_hash() {
     assert_eq!(found, Some(address));
 }
 
+#[test]
+fn test_change_consensus_key() {
+    let mut s = TestWlStorage::default();
+    let params = OwnedPosParams::default();
+    let current_epoch = s.storage.block.epoch;
+
+    let gen_validator = |seed: u64| GenesisValidator {
+        address: address_from_simple_seed(seed),
+        tokens: token::Amount::native_whole(1),
+        consensus_key: common_sk_from_simple_seed(seed).to_public(),
+        protocol_key: common_sk_from_simple_seed(seed + 100).to_public(),
+        eth_hot_key: key::common::PublicKey::Secp256k1(
+            key::testing::gen_keypair::<key::secp256k1::SigScheme>().ref_to(),
+        ),
+        eth_cold_key: key::common::PublicKey::Secp256k1(
+            key::testing::gen_keypair::<key::secp256k1::SigScheme>().ref_to(),
+        ),
+        commission_rate: Dec::new(1, 1).expect("Dec creation failed"),
+        max_commission_rate_change: Dec::new(1, 1)
+            .expect("Dec creation failed"),
+        metadata: Default::default(),
+    };
+    let val1 = gen_validator(1);
+    let val2 = gen_validator(2);
+    let params = test_init_genesis(
+        &mut s,
+        params,
+        [val1.clone(), val2.clone()].into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+
+    // A new unused key can be set and takes effect at the pipeline offset
+    let new_key = common_sk_from_simple_seed(3).to_public();
+    change_consensus_key(&mut s, &val1.address, &new_key, current_epoch)
+        .unwrap();
+    let keys_handle = validator_consensus_key_handle(&val1.address);
+    for epoch in current_epoch.iter_range(params.pipeline_len) {
+        assert_eq!(
+            keys_handle.get(&s, epoch, &params).unwrap(),
+            Some(val1.consensus_key.clone())
+        );
+    }
+    let pipeline_epoch = current_epoch + params.pipeline_len;
+    assert_eq!(
+        keys_handle.get(&s, pipeline_epoch, &params).unwrap(),
+        Some(new_key.clone())
+    );
+    assert_eq!(
+        find_validator_by_raw_hash(
+            &s,
+            key::tm_consensus_key_raw_hash(&new_key)
+        )
+        .unwrap(),
+        Some(val1.address.clone())
+    );
+
+    // A key that's already used by a validator cannot be set
+    for used_key in [&val1.consensus_key, &val2.consensus_key, &new_key] {
+        assert!(
+            change_consensus_key(&mut s, &val1.address, used_key, current_epoch)
+                .is_err()
+        );
+    }
+
+    // Only a validator can change its consensus key
+    let non_validator = address_from_simple_seed(4);
+    let unused_key = common_sk_from_simple_seed(4).to_public();
+    assert!(
+        change_consensus_key(&mut s, &non_validator, &unused_key, current_epoch)
+            .is_err()
+    );
+}
+
 #[test]
 fn test_validator_sets() {
     let mut s = TestWlStorage::default();
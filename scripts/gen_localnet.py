@@ -133,7 +133,7 @@ if not os.path.isdir(WASM_PATH) or not os.listdir(WASM_PATH):
     print(f"Cannot find wasm directory that is not empty at {WASM_PATH}")
     sys.exit(1)
 
-os.system(f"{namadac_bin} utils init-network --chain-prefix {CHAIN_PREFIX} --genesis-time {GENESIS_TIME} --templates-path {TEMPLATES_PATH} --wasm-checksums-path {WASM_CHECKSUMS_PATH}")
+os.system(f"{namadac_bin} utils init-network --chain-prefix {CHAIN_PREFIX} --genesis-time {GENESIS_TIME} --templates-path {TEMPLATES_PATH} --wasm-checksums-path {WASM_CHECKSUMS_PATH} --allow-legacy-signatures")
 
 base_dir_files = os.listdir(BASE_DIR)
 CHAIN_ID=""
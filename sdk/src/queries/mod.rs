@@ -10,7 +10,7 @@ pub use shell::Shell;
 use shell::SHELL;
 pub use types::{
     EncodedResponseQuery, Error, RequestCtx, RequestQuery, ResponseQuery,
-    Router,
+    Router, DEFAULT_MAX_QUERY_DATA_LEN,
 };
 use vp::{Vp, VP};
 
@@ -44,6 +44,7 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
+    ctx.check_query_data_len(request)?;
     RPC.handle(ctx, request)
 }
 
@@ -99,13 +100,40 @@ pub fn require_no_data(request: &RequestQuery) -> storage_api::Result<()> {
 mod testing {
 
     use namada_core::ledger::storage::testing::TestWlStorage;
+    use namada_core::types::address::Address;
+    use namada_core::types::dec::Dec;
+    use namada_core::types::key::RefTo;
     use namada_core::types::storage::BlockHeight;
+    use namada_core::types::token;
+    use namada_ethereum_bridge::test_utils::TestValidatorKeys;
+    use namada_proof_of_stake::types::GenesisValidator;
     use tendermint_rpc::Response;
 
     use super::*;
     use crate::events::log::EventLog;
     use crate::tendermint_rpc::error::Error as RpcError;
 
+    #[allow(dead_code)]
+    /// A genesis validator with the given whole tokens amount, newly
+    /// generated keys and the default commission rates
+    pub fn genesis_validator(
+        address: Address,
+        tokens: u64,
+    ) -> GenesisValidator {
+        let keys = TestValidatorKeys::generate();
+        GenesisValidator {
+            address,
+            tokens: token::Amount::native_whole(tokens),
+            consensus_key: keys.consensus.ref_to(),
+            protocol_key: keys.protocol.ref_to(),
+            eth_cold_key: keys.eth_gov.ref_to(),
+            eth_hot_key: keys.eth_bridge.ref_to(),
+            commission_rate: Dec::new(5, 2).unwrap(),
+            max_commission_rate_change: Dec::new(1, 2).unwrap(),
+            metadata: Default::default(),
+        }
+    }
+
     /// A test client that has direct access to the storage
     pub struct TestClient<RPC>
     where
@@ -188,6 +216,7 @@ mod testing {
                 vp_wasm_cache: (),
                 tx_wasm_cache: (),
                 storage_read_past_height_limit: None,
+                max_query_data_len: DEFAULT_MAX_QUERY_DATA_LEN,
             };
             // TODO: this is a hack to propagate errors to the caller, we should
             // really permit error types other than [`std::io::Error`]
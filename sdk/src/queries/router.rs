@@ -1009,7 +1009,9 @@ mod test {
 
     use super::test_rpc::TEST_RPC;
     use crate::queries::testing::TestClient;
-    use crate::queries::{RequestCtx, RequestQuery, Router};
+    use crate::queries::{
+        RequestCtx, RequestQuery, Router, DEFAULT_MAX_QUERY_DATA_LEN,
+    };
 
     /// Test all the possible paths in `TEST_RPC` router.
     #[tokio::test]
@@ -1029,6 +1031,7 @@ mod test {
             vp_wasm_cache: (),
             tx_wasm_cache: (),
             storage_read_past_height_limit: None,
+            max_query_data_len: DEFAULT_MAX_QUERY_DATA_LEN,
         };
         let result = TEST_RPC.handle(ctx, &request);
         assert!(result.is_err());
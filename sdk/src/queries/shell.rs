@@ -1,10 +1,11 @@
 pub(super) mod eth_bridge;
 
-use borsh::BorshDeserialize;
+use borsh::{BorshDeserialize, BorshSerialize};
 use borsh_ext::BorshSerializeExt;
 use masp_primitives::asset_type::AssetType;
 use masp_primitives::merkle_tree::MerklePath;
 use masp_primitives::sapling::Node;
+use namada_core::ledger::parameters::{self, EpochDuration};
 use namada_core::ledger::storage::traits::StorageHasher;
 use namada_core::ledger::storage::{DBIter, LastBlock, DB};
 use namada_core::ledger::storage_api::{self, ResultExt, StorageRead};
@@ -14,6 +15,7 @@ use namada_core::types::hash::Hash;
 use namada_core::types::storage::{
     self, BlockHeight, BlockResults, Epoch, KeySeg, PrefixValue,
 };
+use namada_core::types::time::DateTimeUtc;
 use namada_core::types::token::MaspDenom;
 #[cfg(any(test, feature = "async-client"))]
 use namada_core::types::transaction::TxResult;
@@ -35,6 +37,51 @@ type Conversion = (
     MerklePath<Node>,
 );
 
+/// The maximum number of blocks searched backwards for the height at which a
+/// storage value was last written, regardless of the node's
+/// `storage_read_past_height_limit`
+pub const MAX_STORAGE_VALUE_HEIGHT_SCAN: u64 = 10_000;
+
+/// Information about the current epoch and the bounds of the next one
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct EpochInfo {
+    /// Epoch of the last committed block
+    pub current_epoch: Epoch,
+    /// Height of the first block of the current epoch
+    pub first_block_height: BlockHeight,
+    /// Minimum block height at which the next epoch may start
+    pub next_epoch_min_start_height: BlockHeight,
+    /// Minimum block time at which the next epoch may start
+    pub next_epoch_min_start_time: DateTimeUtc,
+}
+
+/// A summary of the key parameters of the chain
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct ChainParams {
+    /// The address of the native token
+    pub native_token: Address,
+    /// The minimum duration of an epoch
+    pub epoch_duration: EpochDuration,
+    /// The PoS pipeline length in epochs
+    pub pipeline_len: u64,
+    /// The PoS unbonding length in epochs
+    pub unbonding_len: u64,
+    /// The maximum number of validators in the consensus set
+    pub max_validator_slots: u64,
+}
+
+/// A storage value with the height of the block in which it was last written
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct StorageValueWithHeight {
+    /// The raw value
+    pub value: Vec<u8>,
+    /// Height of the last block in which the value was written. This is
+    /// `None` when it's further in the past than the node's
+    /// `storage_read_past_height_limit` or [`MAX_STORAGE_VALUE_HEIGHT_SCAN`]
+    /// blocks, or when the block's diffs are not available in the DB.
+    pub height: Option<BlockHeight>,
+}
+
 router! {SHELL,
     // Shell provides storage read access, block metadata and can dry-run a tx
 
@@ -44,9 +91,16 @@ router! {SHELL,
     // Epoch of the last committed block
     ( "epoch" ) -> Epoch = epoch,
 
+    // Current epoch with its first block height and the minimum start of
+    // the next epoch
+    ( "epoch_info" ) -> EpochInfo = epoch_info,
+
     // The address of the native token
     ( "native_token" ) -> Address = native_token,
 
+    // A summary of the key protocol and PoS parameters
+    ( "chain_params" ) -> ChainParams = chain_params,
+
     // Epoch of the input block height
     ( "epoch_at_height" / [height: BlockHeight]) -> Option<Epoch> = epoch_at_height,
 
@@ -57,6 +111,10 @@ router! {SHELL,
     ( "value" / [storage_key: storage::Key] )
         -> Vec<u8> = (with_options storage_value),
 
+    // Raw storage access - read value with the height of its last change
+    ( "value_with_height" / [storage_key: storage::Key] )
+        -> Option<StorageValueWithHeight> = storage_value_with_height,
+
     // Dry run a transaction
     ( "dry_run_tx" ) -> TxResult = (with_options dry_run_tx),
 
@@ -188,6 +246,51 @@ where
     Ok(data)
 }
 
+fn epoch_info<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+) -> storage_api::Result<EpochInfo>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let storage = &ctx.wl_storage.storage;
+    let current_epoch = storage.last_epoch;
+    let first_block_height = storage
+        .block
+        .pred_epochs
+        .get_start_height_of_epoch(current_epoch)
+        .ok_or_else(|| {
+            storage_api::Error::new_const(
+                "The first block height of the current epoch is not known",
+            )
+        })?;
+    Ok(EpochInfo {
+        current_epoch,
+        first_block_height,
+        next_epoch_min_start_height: storage.next_epoch_min_start_height,
+        next_epoch_min_start_time: storage.next_epoch_min_start_time,
+    })
+}
+
+fn chain_params<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+) -> storage_api::Result<ChainParams>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let epoch_duration =
+        parameters::read_epoch_duration_parameter(ctx.wl_storage)?;
+    let pos_params = namada_proof_of_stake::read_pos_params(ctx.wl_storage)?;
+    Ok(ChainParams {
+        native_token: ctx.wl_storage.storage.native_token.clone(),
+        epoch_duration,
+        pipeline_len: pos_params.pipeline_len,
+        unbonding_len: pos_params.unbonding_len,
+        max_validator_slots: pos_params.max_validator_slots,
+    })
+}
+
 fn native_token<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
 ) -> storage_api::Result<Address>
@@ -233,39 +336,12 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    if let Some(past_height_limit) = ctx.storage_read_past_height_limit {
-        if request.height.value() + past_height_limit
-            < ctx.wl_storage.storage.get_last_block_height().0
-        {
-            return Err(storage_api::Error::new(std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                format!(
-                    "Cannot query more than {past_height_limit} blocks in the \
-                     past (configured via \
-                     `shell.storage_read_past_height_limit`)."
-                ),
-            )));
-        }
-    }
+    let pinned = ctx.pin_height(request.height.into())?;
 
-    match ctx
-        .wl_storage
-        .storage
-        .read_with_height(&storage_key, request.height.into())
-        .into_storage_result()?
-    {
-        (Some(value), _gas) => {
+    match pinned.read_bytes(&storage_key)? {
+        Some(value) => {
             let proof = if request.prove {
-                let proof = ctx
-                    .wl_storage
-                    .storage
-                    .get_existence_proof(
-                        &storage_key,
-                        &value,
-                        request.height.into(),
-                    )
-                    .into_storage_result()?;
-                Some(proof)
+                Some(pinned.existence_proof(&storage_key, &value)?)
             } else {
                 None
             };
@@ -275,17 +351,9 @@ where
                 info: Default::default(),
             })
         }
-        (None, _gas) => {
+        None => {
             let proof = if request.prove {
-                let proof = ctx
-                    .wl_storage
-                    .storage
-                    .get_non_existence_proof(
-                        &storage_key,
-                        request.height.into(),
-                    )
-                    .into_storage_result()?;
-                Some(proof)
+                Some(pinned.non_existence_proof(&storage_key)?)
             } else {
                 None
             };
@@ -298,6 +366,39 @@ where
     }
 }
 
+/// Returns the latest value of the storage key with the height of the last
+/// committed block in which it was written, or `None` when the key is not
+/// found. The height is found from the storage diffs, which are searched from
+/// the last committed block backwards, up to the
+/// `storage_read_past_height_limit`, but no further than
+/// [`MAX_STORAGE_VALUE_HEIGHT_SCAN`] blocks. Beyond it, the height is `None`.
+fn storage_value_with_height<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    storage_key: storage::Key,
+) -> storage_api::Result<Option<StorageValueWithHeight>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let db = &ctx.wl_storage.storage.db;
+    let value =
+        match db.read_subspace_val(&storage_key).into_storage_result()? {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+    let last_height = ctx.wl_storage.storage.get_last_block_height();
+    let limit = ctx
+        .storage_read_past_height_limit
+        .map_or(MAX_STORAGE_VALUE_HEIGHT_SCAN, |limit| {
+            limit.min(MAX_STORAGE_VALUE_HEIGHT_SCAN)
+        });
+    let oldest_height = BlockHeight(last_height.0.saturating_sub(limit));
+    let height = db
+        .read_subspace_val_last_height(&storage_key, last_height, oldest_height)
+        .into_storage_result()?;
+    Ok(Some(StorageValueWithHeight { value, height }))
+}
+
 fn storage_prefix<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
     request: &RequestQuery,
@@ -479,8 +580,16 @@ where
 
 #[cfg(test)]
 mod test {
+    use borsh_ext::BorshSerializeExt;
+    use namada_core::ledger::parameters::{self, EpochDuration};
+    use namada_core::ledger::storage_api::StorageWrite;
+    use namada_core::types::storage::{BlockHeight, Epoch};
+    use namada_core::types::time::DurationSecs;
     use namada_core::types::{address, token};
+    use namada_proof_of_stake::OwnedPosParams;
 
+    use super::{ChainParams, MAX_STORAGE_VALUE_HEIGHT_SCAN};
+    use crate::queries::testing::TestClient;
     use crate::queries::RPC;
 
     #[test]
@@ -503,4 +612,146 @@ mod test {
         let path = RPC.shell().storage_has_key_path(&key);
         assert_eq!(format!("/shell/has_key/{}", key), path);
     }
+
+    #[tokio::test]
+    async fn test_epoch_info() {
+        let mut client = TestClient::new(RPC);
+
+        // Start epoch 1 at height 10
+        let storage = &mut client.wl_storage.storage;
+        storage.block.pred_epochs.new_epoch(BlockHeight(10));
+        storage.block.height = BlockHeight(12);
+        storage.last_epoch = Epoch(1);
+        storage.next_epoch_min_start_height = BlockHeight(20);
+
+        let info = RPC.shell().epoch_info(&client).await.unwrap();
+        assert_eq!(info.current_epoch, Epoch(1));
+        assert_eq!(info.first_block_height, BlockHeight(10));
+        assert_eq!(info.next_epoch_min_start_height, BlockHeight(20));
+        assert_eq!(
+            info.next_epoch_min_start_time,
+            client.wl_storage.storage.next_epoch_min_start_time
+        );
+    }
+
+    #[tokio::test]
+    async fn test_storage_value_with_height() {
+        let mut client = TestClient::new(RPC);
+        let token_addr = address::testing::established_address_1();
+        let owner = address::testing::established_address_2();
+        let key = token::balance_key(&token_addr, &owner);
+        client.wl_storage.storage.block.height = BlockHeight(1);
+        client
+            .wl_storage
+            .write(&key, token::Amount::from_u64(10))
+            .unwrap();
+        client.wl_storage.commit_block().unwrap();
+
+        let value = RPC
+            .shell()
+            .storage_value_with_height(&client, &key)
+            .await
+            .unwrap()
+            .expect("The value must be found");
+        assert_eq!(value.value, token::Amount::from_u64(10).serialize_to_vec());
+        assert_eq!(value.height, Some(BlockHeight(1)));
+
+        // The height is updated after a write
+        client.wl_storage.storage.block.height = BlockHeight(3);
+        client
+            .wl_storage
+            .write(&key, token::Amount::from_u64(20))
+            .unwrap();
+        client.wl_storage.commit_block().unwrap();
+        let value = RPC
+            .shell()
+            .storage_value_with_height(&client, &key)
+            .await
+            .unwrap()
+            .expect("The value must be found");
+        assert_eq!(value.value, token::Amount::from_u64(20).serialize_to_vec());
+        assert_eq!(value.height, Some(BlockHeight(3)));
+
+        // A key that was never written is not found
+        let other_key = token::balance_key(&token_addr, &token_addr);
+        let value = RPC
+            .shell()
+            .storage_value_with_height(&client, &other_key)
+            .await
+            .unwrap();
+        assert!(value.is_none());
+
+        // The height is not searched beyond the maximum scan, even without a
+        // limit on reading past heights
+        client.wl_storage.storage.block.height =
+            BlockHeight(4 + MAX_STORAGE_VALUE_HEIGHT_SCAN);
+        client
+            .wl_storage
+            .write(&other_key, token::Amount::from_u64(30))
+            .unwrap();
+        client.wl_storage.commit_block().unwrap();
+        let value = RPC
+            .shell()
+            .storage_value_with_height(&client, &key)
+            .await
+            .unwrap()
+            .expect("The value must be found");
+        assert_eq!(value.value, token::Amount::from_u64(20).serialize_to_vec());
+        assert_eq!(value.height, None);
+    }
+
+    #[tokio::test]
+    async fn test_native_token() {
+        let mut client = TestClient::new(RPC);
+        let path = RPC.shell().native_token_path();
+        assert_eq!("/shell/native_token", path);
+
+        let native_token = client.wl_storage.storage.native_token.clone();
+        let token = RPC.shell().native_token(&client).await.unwrap();
+        assert_eq!(token, native_token);
+
+        // The query follows the configured native token
+        let native_token = address::testing::established_address_1();
+        client.wl_storage.storage.native_token = native_token.clone();
+        let token = RPC.shell().native_token(&client).await.unwrap();
+        assert_eq!(token, native_token);
+    }
+
+    #[tokio::test]
+    async fn test_chain_params() {
+        let mut client = TestClient::new(RPC);
+        let epoch_duration = EpochDuration {
+            min_num_of_blocks: 10,
+            min_duration: DurationSecs(60),
+        };
+        parameters::update_epoch_parameter(
+            &mut client.wl_storage,
+            &epoch_duration,
+        )
+        .unwrap();
+        let pos_params = OwnedPosParams {
+            max_validator_slots: 42,
+            pipeline_len: 3,
+            unbonding_len: 7,
+            ..Default::default()
+        };
+        namada_proof_of_stake::write_pos_params(
+            &mut client.wl_storage,
+            &pos_params,
+        )
+        .unwrap();
+        client.wl_storage.commit_block().unwrap();
+
+        let params = RPC.shell().chain_params(&client).await.unwrap();
+        assert_eq!(
+            params,
+            ChainParams {
+                native_token: client.wl_storage.storage.native_token.clone(),
+                epoch_duration,
+                pipeline_len: 3,
+                unbonding_len: 7,
+                max_validator_slots: 42,
+            }
+        );
+    }
 }
@@ -1,13 +1,18 @@
 use std::fmt::Debug;
 
 use namada_core::ledger::storage::{DBIter, StorageHasher, WlStorage, DB};
-use namada_core::ledger::storage_api;
-use namada_core::types::storage::BlockHeight;
+use namada_core::ledger::storage_api::{self, ResultExt};
+use namada_core::types::storage::{self, BlockHeight, Epoch};
 use thiserror::Error;
 
 use crate::events::log::EventLog;
 use crate::tendermint::merkle::proof::ProofOps;
 pub use crate::tendermint::v0_37::abci::request::Query as RequestQuery;
+
+/// The default limit of the length of a query's data in bytes. This is large
+/// enough to fit any transaction that can be dry-ran.
+pub const DEFAULT_MAX_QUERY_DATA_LEN: usize = 16 * 1024 * 1024;
+
 /// A request context provides read-only access to storage and WASM compilation
 /// caches to request handlers.
 #[derive(Debug, Clone)]
@@ -28,6 +33,205 @@ where
     /// limit the how many block heights in the past can the storage be
     /// queried for reading values.
     pub storage_read_past_height_limit: Option<u64>,
+    /// The maximum length of a query's data in bytes. Queries with larger
+    /// data are rejected before they're handled.
+    pub max_query_data_len: usize,
+}
+
+impl<'shell, D, H, VpCache, TxCache> RequestCtx<'shell, D, H, VpCache, TxCache>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    /// Check that the storage may be read at the given block height with
+    /// respect to the `storage_read_past_height_limit`. The height `0` means
+    /// the last committed height, which can always be read.
+    pub fn check_past_height_limit(
+        &self,
+        height: BlockHeight,
+    ) -> storage_api::Result<()> {
+        if height == BlockHeight(0) {
+            return Ok(());
+        }
+        if let Some(past_height_limit) = self.storage_read_past_height_limit {
+            if height.0 + past_height_limit
+                < self.wl_storage.storage.get_last_block_height().0
+            {
+                return Err(storage_api::Error::new(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!(
+                        "Cannot query more than {past_height_limit} blocks in \
+                         the past (configured via \
+                         `shell.storage_read_past_height_limit`)."
+                    ),
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that the data of the given query doesn't exceed the
+    /// `max_query_data_len`.
+    pub fn check_query_data_len(
+        &self,
+        request: &RequestQuery,
+    ) -> storage_api::Result<()> {
+        if request.data.len() > self.max_query_data_len {
+            return Err(storage_api::Error::new(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "The query data of {} bytes exceeds the maximum length of \
+                     {} bytes.",
+                    request.data.len(),
+                    self.max_query_data_len
+                ),
+            )));
+        }
+        Ok(())
+    }
+
+    /// Validate the given block height against the
+    /// `storage_read_past_height_limit` and pin it for the reads and proofs
+    /// of a request, so that they can't be made at any other height.
+    pub fn pin_height(
+        self,
+        height: BlockHeight,
+    ) -> storage_api::Result<PinnedRequestCtx<'shell, D, H, VpCache, TxCache>>
+    {
+        self.check_past_height_limit(height)?;
+        Ok(PinnedRequestCtx { ctx: self, height })
+    }
+
+    /// Get the height of the first block of the given epoch. `None` resolves
+    /// to the height `0`, i.e. the last committed height.
+    pub fn epoch_first_height(
+        &self,
+        epoch: Option<Epoch>,
+    ) -> storage_api::Result<BlockHeight> {
+        match epoch {
+            Some(epoch) => self
+                .wl_storage
+                .storage
+                .block
+                .pred_epochs
+                .get_start_height_of_epoch(epoch)
+                .ok_or_else(|| {
+                    storage_api::Error::new(format!(
+                        "The first block height of epoch {epoch} is not known"
+                    ))
+                }),
+            None => Ok(BlockHeight(0)),
+        }
+    }
+}
+
+/// A query request at an epoch, rather than at a block height. The epoch is
+/// resolved to the height of its first block.
+#[derive(Debug, Clone, Default)]
+pub struct EpochRequestQuery {
+    /// Borsh-encoded request data
+    pub data: Vec<u8>,
+    /// The request path
+    pub path: String,
+    /// The epoch to query at. `None` queries the last committed block.
+    pub epoch: Option<Epoch>,
+    /// Whether to return a proof with the response
+    pub prove: bool,
+}
+
+impl EpochRequestQuery {
+    /// Resolve the epoch to the height of its first block to get a
+    /// height-based [`RequestQuery`].
+    pub fn resolve<D, H, VpCache, TxCache>(
+        self,
+        ctx: &RequestCtx<'_, D, H, VpCache, TxCache>,
+    ) -> storage_api::Result<RequestQuery>
+    where
+        D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+        H: 'static + StorageHasher + Sync,
+    {
+        let height = ctx.epoch_first_height(self.epoch)?;
+        Ok(RequestQuery {
+            data: self.data.into(),
+            path: self.path,
+            height: height.try_into().into_storage_result()?,
+            prove: self.prove,
+        })
+    }
+}
+
+/// A request context with a block height that has already been validated.
+/// The pinned height cannot be changed.
+#[derive(Debug, Clone)]
+pub struct PinnedRequestCtx<'shell, D, H, VpCache, TxCache>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    ctx: RequestCtx<'shell, D, H, VpCache, TxCache>,
+    height: BlockHeight,
+}
+
+impl<'shell, D, H, VpCache, TxCache>
+    PinnedRequestCtx<'shell, D, H, VpCache, TxCache>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    /// The pinned block height. `0` means the last committed height.
+    pub fn height(&self) -> BlockHeight {
+        self.height
+    }
+
+    /// The underlying request context
+    pub fn ctx(&self) -> &RequestCtx<'shell, D, H, VpCache, TxCache> {
+        &self.ctx
+    }
+
+    /// Read a raw storage value at the pinned height
+    pub fn read_bytes(
+        &self,
+        key: &storage::Key,
+    ) -> storage_api::Result<Option<Vec<u8>>> {
+        let (value, _gas) = self
+            .ctx
+            .wl_storage
+            .storage
+            .read_with_height(key, self.height)
+            .into_storage_result()?;
+        Ok(value)
+    }
+
+    /// Check if the given storage key is present at the pinned height
+    pub fn has_key(&self, key: &storage::Key) -> storage_api::Result<bool> {
+        self.read_bytes(key).map(|value| value.is_some())
+    }
+
+    /// Get a proof of the existence of the given value of a storage key at
+    /// the pinned height
+    pub fn existence_proof(
+        &self,
+        key: &storage::Key,
+        value: &[u8],
+    ) -> storage_api::Result<ProofOps> {
+        self.ctx
+            .wl_storage
+            .storage
+            .get_existence_proof(key, value, self.height)
+            .into_storage_result()
+    }
+
+    /// Get a proof of the non-existence of a storage key at the pinned height
+    pub fn non_existence_proof(
+        &self,
+        key: &storage::Key,
+    ) -> storage_api::Result<ProofOps> {
+        self.ctx
+            .wl_storage
+            .storage
+            .get_non_existence_proof(key, self.height)
+            .into_storage_result()
+    }
 }
 
 /// A `Router` handles parsing read-only query requests and dispatching them to
@@ -47,6 +251,21 @@ pub trait Router {
         self.internal_handle(ctx, request, 0)
     }
 
+    /// Handle a given request at an epoch, which is resolved to the height of
+    /// its first block. This must be invoked on the root `Router`.
+    fn handle_at_epoch<D, H, V, T>(
+        &self,
+        ctx: RequestCtx<'_, D, H, V, T>,
+        request: EpochRequestQuery,
+    ) -> storage_api::Result<EncodedResponseQuery>
+    where
+        D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+        H: 'static + StorageHasher + Sync,
+    {
+        let request = request.resolve(&ctx)?;
+        self.handle(ctx, &request)
+    }
+
     /// Internal method which shouldn't be invoked directly. Instead, you may
     /// want to call `self.handle()`.
     ///
@@ -90,3 +309,125 @@ pub struct ResponseQuery<T> {
 
 /// [`ResponseQuery`] with borsh-encoded `data` field
 pub type EncodedResponseQuery = ResponseQuery<Vec<u8>>;
+
+#[cfg(test)]
+mod test {
+    use namada_core::ledger::storage::testing::TestWlStorage;
+    use namada_core::ledger::storage::LastBlock;
+    use namada_core::types::time::DateTimeUtc;
+
+    use super::*;
+
+    /// Test that a height is validated when it's pinned and that the pinned
+    /// context reads at the pinned height.
+    #[test]
+    fn test_pin_height() {
+        let mut wl_storage = TestWlStorage::default();
+        let key = storage::Key::parse("key").unwrap();
+        let value = vec![1_u8, 2, 3];
+        wl_storage.storage.write(&key, value.clone()).unwrap();
+        wl_storage.storage.last_block = Some(LastBlock {
+            height: BlockHeight(10),
+            hash: Default::default(),
+            time: DateTimeUtc::now(),
+        });
+        let event_log = EventLog::default();
+        let ctx = RequestCtx {
+            wl_storage: &wl_storage,
+            event_log: &event_log,
+            vp_wasm_cache: (),
+            tx_wasm_cache: (),
+            storage_read_past_height_limit: Some(5),
+            max_query_data_len: DEFAULT_MAX_QUERY_DATA_LEN,
+        };
+
+        // A height too far in the past cannot be pinned
+        assert!(ctx.check_past_height_limit(BlockHeight(4)).is_err());
+        assert!(ctx.clone().pin_height(BlockHeight(4)).is_err());
+
+        // The latest height `0` and recent heights can be pinned
+        assert!(ctx.check_past_height_limit(BlockHeight(0)).is_ok());
+        assert!(ctx.clone().pin_height(BlockHeight(0)).is_ok());
+        assert!(ctx.clone().pin_height(BlockHeight(5)).is_ok());
+        let pinned = ctx.pin_height(BlockHeight(10)).unwrap();
+        assert_eq!(pinned.height(), BlockHeight(10));
+
+        // Reads are made at the pinned height
+        let missing_key = storage::Key::parse("missing").unwrap();
+        assert_eq!(pinned.read_bytes(&key).unwrap(), Some(value));
+        assert!(pinned.has_key(&key).unwrap());
+        assert!(!pinned.has_key(&missing_key).unwrap());
+    }
+
+    /// Test that a query with data exceeding the limit is rejected.
+    #[test]
+    fn test_check_query_data_len() {
+        let wl_storage = TestWlStorage::default();
+        let event_log = EventLog::default();
+        let ctx = RequestCtx {
+            wl_storage: &wl_storage,
+            event_log: &event_log,
+            vp_wasm_cache: (),
+            tx_wasm_cache: (),
+            storage_read_past_height_limit: None,
+            max_query_data_len: 4,
+        };
+        let query = |len: usize| RequestQuery {
+            data: vec![0_u8; len].into(),
+            path: "/shell/epoch".to_string(),
+            height: Default::default(),
+            prove: false,
+        };
+
+        assert!(ctx.check_query_data_len(&query(0)).is_ok());
+        assert!(ctx.check_query_data_len(&query(4)).is_ok());
+        let err = ctx.check_query_data_len(&query(5)).unwrap_err();
+        assert!(err.to_string().contains("exceeds the maximum length"));
+
+        // Oversized queries are rejected before they're routed
+        assert!(crate::queries::handle_path(ctx, &query(5)).is_err());
+    }
+
+    /// Test that an epoch is resolved to the height of its first block.
+    #[test]
+    fn test_epoch_request_query() {
+        let mut wl_storage = TestWlStorage::default();
+        wl_storage
+            .storage
+            .block
+            .pred_epochs
+            .new_epoch(BlockHeight(10));
+        wl_storage
+            .storage
+            .block
+            .pred_epochs
+            .new_epoch(BlockHeight(20));
+        let event_log = EventLog::default();
+        let ctx = RequestCtx {
+            wl_storage: &wl_storage,
+            event_log: &event_log,
+            vp_wasm_cache: (),
+            tx_wasm_cache: (),
+            storage_read_past_height_limit: None,
+            max_query_data_len: DEFAULT_MAX_QUERY_DATA_LEN,
+        };
+        let query = |epoch: Option<Epoch>| EpochRequestQuery {
+            path: "/shell/epoch".to_string(),
+            epoch,
+            ..Default::default()
+        };
+
+        let request = query(Some(Epoch(1))).resolve(&ctx).unwrap();
+        assert_eq!(BlockHeight::from(request.height), BlockHeight(10));
+        assert_eq!(request.path, "/shell/epoch");
+        let request = query(Some(Epoch(2))).resolve(&ctx).unwrap();
+        assert_eq!(BlockHeight::from(request.height), BlockHeight(20));
+
+        // No epoch queries the last committed block
+        let request = query(None).resolve(&ctx).unwrap();
+        assert_eq!(BlockHeight::from(request.height), BlockHeight(0));
+
+        // An epoch that hasn't started yet cannot be resolved
+        assert!(query(Some(Epoch(3))).resolve(&ctx).is_err());
+    }
+}
@@ -3,14 +3,19 @@
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use borsh_ext::BorshSerializeExt;
+use namada_core::ledger::parameters::storage as params_storage;
 use namada_core::ledger::storage::{DBIter, StorageHasher, DB};
 use namada_core::ledger::storage_api;
 use namada_core::ledger::storage_api::collections::lazy_map;
-use namada_core::ledger::storage_api::OptionExt;
+use namada_core::ledger::storage_api::{OptionExt, ResultExt, StorageRead};
 use namada_core::types::address::Address;
-use namada_core::types::storage::Epoch;
+use namada_core::types::dec::Dec;
+use namada_core::types::key::common;
+use namada_core::types::storage::{self, Epoch};
 use namada_core::types::token;
 use namada_proof_of_stake::parameters::PosParams;
+use namada_proof_of_stake::storage::{bonds_prefix, is_bond_key};
 use namada_proof_of_stake::types::{
     BondId, BondsAndUnbondsDetail, BondsAndUnbondsDetails, CommissionPair,
     Slash, ValidatorMetaData, ValidatorState, WeightedValidator,
@@ -25,11 +30,19 @@ use namada_proof_of_stake::{
     read_validator_discord_handle, read_validator_email,
     read_validator_max_commission_rate_change, read_validator_stake,
     read_validator_website, unbond_handle, validator_commission_rate_handle,
-    validator_incoming_redelegations_handle, validator_slashes_handle,
-    validator_state_handle,
+    validator_consensus_key_handle, validator_deltas_handle,
+    validator_eth_cold_key_handle, validator_eth_hot_key_handle,
+    validator_incoming_redelegations_handle, validator_protocol_key_handle,
+    validator_slashes_handle, validator_state_handle,
 };
 
-use crate::queries::types::RequestCtx;
+use crate::queries::types::{RequestCtx, RequestQuery};
+use crate::queries::{require_latest_height, EncodedResponseQuery};
+use crate::tendermint::merkle::proof::ProofOps;
+
+/// The maximum number of delegators returned in a single page of the
+/// `delegators` query.
+pub const MAX_DELEGATORS_LIMIT: u64 = 1000;
 
 // PoS validity predicate queries
 router! {POS,
@@ -42,12 +55,25 @@ router! {POS,
         ( "stake" / [validator: Address] / [epoch: opt Epoch] )
             -> Option<token::Amount> = validator_stake,
 
+        ( "stake_with_proof" / [validator: Address] / [epoch: opt Epoch] )
+            -> Option<ValidatorStakeDeltas> = (with_options validator_stake_with_proof),
+
         ( "slashes" / [validator: Address] )
             -> Vec<Slash> = validator_slashes,
 
+        ( "pending_slashes" / [validator: Address] )
+            -> Vec<(Epoch, Dec)> = validator_pending_slashes,
+
+        ( "delegators" / [validator: Address] / [limit: u64]
+          / [after: opt Address] / [epoch: opt Epoch] )
+            -> Vec<(Address, token::Amount)> = validator_delegators,
+
         ( "commission" / [validator: Address] / [epoch: opt Epoch] )
             -> Option<CommissionPair> = validator_commission,
 
+        ( "commission_history" / [validator: Address] )
+            -> Vec<(Epoch, Dec)> = validator_commission_history,
+
         ( "metadata" / [validator: Address] )
             -> Option<ValidatorMetaData> = validator_metadata,
 
@@ -56,6 +82,9 @@ router! {POS,
 
         ( "incoming_redelegation" / [src_validator: Address] / [delegator: Address] )
             -> Option<Epoch> = validator_incoming_redelegation,
+
+        ( "keys" / [validator: Address] / [epoch: opt Epoch] )
+            -> Option<ValidatorKeys> = validator_keys,
     },
 
     ( "validator_set" ) = {
@@ -65,6 +94,9 @@ router! {POS,
         ( "below_capacity" / [epoch: opt Epoch] )
             -> BTreeSet<WeightedValidator> = below_capacity_validator_set,
 
+        ( "size" / [epoch: opt Epoch] )
+            -> ValidatorSetSize = validator_set_size,
+
         // TODO: add "below_threshold"
     },
 
@@ -73,6 +105,8 @@ router! {POS,
     ( "total_stake" / [epoch: opt Epoch] )
         -> token::Amount = total_stake,
 
+    ( "reward_rate" ) -> RewardRate = reward_rate,
+
     ( "delegations" / [owner: Address] )
         -> HashSet<Address> = delegation_validators,
 
@@ -112,6 +146,88 @@ router! {POS,
 
 }
 
+/// The public keys of a validator for each of their roles
+#[derive(Debug, Clone, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct ValidatorKeys {
+    /// The consensus key used by Tendermint
+    pub consensus_key: Option<common::PublicKey>,
+    /// The protocol key used for signing protocol txs
+    pub protocol_key: Option<common::PublicKey>,
+    /// The Ethereum bridge hot key used for validator set updates
+    pub eth_hot_key: Option<common::PublicKey>,
+    /// The Ethereum bridge cold key used for governance
+    pub eth_cold_key: Option<common::PublicKey>,
+}
+
+/// The stake of a validator with the stake deltas that it's the sum of.
+///
+/// There is no single storage value holding the stake, so the proof that can
+/// come with it is only partial: it proves that each of the returned deltas
+/// is stored with its value, but not that no other deltas are stored. A node
+/// can leave out some of the deltas, e.g. the negative ones, and the proofs
+/// of the rest still verify, so the `stake` can't be verified trustlessly
+/// from the proof, e.g. by a light client.
+#[derive(
+    Debug, Clone, PartialEq, Eq, BorshDeserialize, BorshSerialize, BorshSchema,
+)]
+pub struct ValidatorStakeDeltas {
+    /// The total stake of the validator, i.e. the sum of the `deltas`
+    pub stake: token::Amount,
+    /// The storage keys and the values of the stake deltas. When a proof is
+    /// requested, the response's proof ops hold the proof of each delta in
+    /// the same order, with two ops per delta: the proof of the value in its
+    /// sub-tree followed by the proof of the sub-tree root in the base tree.
+    /// The ops are not a single proof, so each pair has to be verified on its
+    /// own against the Merkle root.
+    pub deltas: Vec<(storage::Key, token::Change)>,
+}
+
+/// The number of validators in each of the validator sets
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    BorshDeserialize,
+    BorshSerialize,
+    BorshSchema,
+)]
+pub struct ValidatorSetSize {
+    /// The number of validators in the consensus set
+    pub consensus: u64,
+    /// The number of validators in the below-capacity set
+    pub below_capacity: u64,
+    /// The number of validators with stake below the validator stake
+    /// threshold
+    pub below_threshold: u64,
+}
+
+/// The PoS rewards at an epoch
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    BorshDeserialize,
+    BorshSerialize,
+    BorshSchema,
+)]
+pub struct RewardRate {
+    /// The PoS inflation that is minted as rewards in an epoch
+    pub inflation: token::Amount,
+    /// The total stake at the current epoch
+    pub total_stake: token::Amount,
+    /// The rewards per unit of stake in an epoch, i.e. the inflation over the
+    /// total stake. Zero when there's no stake.
+    pub rate: Dec,
+    /// The maximum annual inflation rate from the PoS parameters
+    pub max_inflation_rate: Dec,
+}
+
 /// Enriched bonds data with extra information calculated from the data queried
 /// from the node.
 #[derive(Debug, Clone, BorshDeserialize, BorshSerialize, BorshSchema)]
@@ -235,6 +351,26 @@ where
     }
 }
 
+/// Get the validator's commission rates recorded in storage with the epochs
+/// from which they apply, in ascending order of the epochs. Only the rates
+/// that are still kept for past epochs are found.
+fn validator_commission_history<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    validator: Address,
+) -> storage_api::Result<Vec<(Epoch, Dec)>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let mut history: Vec<(Epoch, Dec)> =
+        validator_commission_rate_handle(&validator)
+            .get_data_handler()
+            .iter(ctx.wl_storage)?
+            .collect::<storage_api::Result<_>>()?;
+    history.sort_by_key(|(epoch, _rate)| *epoch);
+    Ok(history)
+}
+
 /// Get the validator metadata
 fn validator_metadata<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
@@ -307,6 +443,64 @@ where
     }
 }
 
+/// Get the total stake of a validator at the given epoch or current when
+/// `None`, with the stake deltas that it's the sum of. When a proof is
+/// requested, the response carries the existence proof of each of the deltas,
+/// which doesn't prove that the deltas are complete, as described in
+/// [`ValidatorStakeDeltas`]. Returns `None` when the given address is not a
+/// validator address.
+fn validator_stake_with_proof<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    request: &RequestQuery,
+    validator: Address,
+    epoch: Option<Epoch>,
+) -> storage_api::Result<EncodedResponseQuery>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    require_latest_height(&ctx, request)?;
+
+    if !namada_proof_of_stake::is_validator(ctx.wl_storage, &validator)? {
+        return Ok(EncodedResponseQuery {
+            data: Option::<ValidatorStakeDeltas>::None.serialize_to_vec(),
+            ..Default::default()
+        });
+    }
+    let epoch = epoch.unwrap_or(ctx.wl_storage.storage.last_epoch);
+    let handle = validator_deltas_handle(&validator).get_data_handler();
+    let mut deltas = vec![];
+    let mut ops = vec![];
+    for delta in handle.iter(ctx.wl_storage)? {
+        let (delta_epoch, change) = delta?;
+        if delta_epoch > epoch {
+            continue;
+        }
+        let key = handle.get_data_key(&delta_epoch);
+        if request.prove {
+            let value = ctx
+                .wl_storage
+                .read_bytes(&key)?
+                .ok_or_err_msg("Stake delta must be present")?;
+            let mut proof = ctx
+                .wl_storage
+                .storage
+                .get_existence_proof(&key, &value, request.height.into())
+                .into_storage_result()?;
+            ops.append(&mut proof.ops);
+        }
+        deltas.push((key, change));
+    }
+    let stake = validator_stake(ctx, validator, Some(epoch))?
+        .ok_or_err_msg("The stake of a validator must be present")?;
+    let data = Some(ValidatorStakeDeltas { stake, deltas }).serialize_to_vec();
+    Ok(EncodedResponseQuery {
+        data,
+        proof: request.prove.then_some(ProofOps { ops }),
+        ..Default::default()
+    })
+}
+
 /// Get the incoming redelegation epoch for a source validator - delegator pair,
 /// if there is any.
 fn validator_incoming_redelegation<D, H, V, T>(
@@ -322,6 +516,50 @@ where
     handle.get(ctx.wl_storage, &delegator)
 }
 
+/// Get the keys of a validator at the given epoch or current when `None`.
+/// Returns `None` when the given address is not a validator address.
+fn validator_keys<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    validator: Address,
+    epoch: Option<Epoch>,
+) -> storage_api::Result<Option<ValidatorKeys>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    if !namada_proof_of_stake::is_validator(ctx.wl_storage, &validator)? {
+        return Ok(None);
+    }
+    let epoch = epoch.unwrap_or(ctx.wl_storage.storage.last_epoch);
+    let params = read_pos_params(ctx.wl_storage)?;
+    let consensus_key = validator_consensus_key_handle(&validator).get(
+        ctx.wl_storage,
+        epoch,
+        &params,
+    )?;
+    let protocol_key = validator_protocol_key_handle(&validator).get(
+        ctx.wl_storage,
+        epoch,
+        &params,
+    )?;
+    let eth_hot_key = validator_eth_hot_key_handle(&validator).get(
+        ctx.wl_storage,
+        epoch,
+        &params,
+    )?;
+    let eth_cold_key = validator_eth_cold_key_handle(&validator).get(
+        ctx.wl_storage,
+        epoch,
+        &params,
+    )?;
+    Ok(Some(ValidatorKeys {
+        consensus_key,
+        protocol_key,
+        eth_hot_key,
+        eth_cold_key,
+    }))
+}
+
 /// Get all the validator in the consensus set with their bonded stake.
 fn consensus_validator_set<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
@@ -351,6 +589,48 @@ where
     )
 }
 
+/// Get the number of validators in each of the validator sets at the given
+/// epoch or current when `None`.
+fn validator_set_size<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    epoch: Option<Epoch>,
+) -> storage_api::Result<ValidatorSetSize>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let epoch = epoch.unwrap_or(ctx.wl_storage.storage.last_epoch);
+    let params = read_pos_params(ctx.wl_storage)?;
+    let consensus = read_consensus_validator_set_addresses_with_stake(
+        ctx.wl_storage,
+        epoch,
+    )?
+    .len() as u64;
+    let below_capacity = read_below_capacity_validator_set_addresses_with_stake(
+        ctx.wl_storage,
+        epoch,
+    )?
+    .len() as u64;
+    // There is no set for the validators below the threshold, so they are
+    // found from the validators' states
+    let mut below_threshold = 0_u64;
+    for validator in read_all_validator_addresses(ctx.wl_storage, epoch)? {
+        let state = validator_state_handle(&validator).get(
+            ctx.wl_storage,
+            epoch,
+            &params,
+        )?;
+        if state == Some(ValidatorState::BelowThreshold) {
+            below_threshold += 1;
+        }
+    }
+    Ok(ValidatorSetSize {
+        consensus,
+        below_capacity,
+        below_threshold,
+    })
+}
+
 /// Get the total stake in PoS system at the given epoch or current when `None`.
 fn total_stake<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
@@ -365,6 +645,35 @@ where
     read_total_stake(ctx.wl_storage, &params, epoch)
 }
 
+/// Get the PoS reward rate at the current epoch. Only the last inflation is
+/// stored, so the rate can't be found for other epochs.
+fn reward_rate<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+) -> storage_api::Result<RewardRate>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let epoch = ctx.wl_storage.storage.last_epoch;
+    let params = read_pos_params(ctx.wl_storage)?;
+    let inflation: token::Amount = ctx
+        .wl_storage
+        .read(&params_storage::get_pos_inflation_amount_key())?
+        .unwrap_or_default();
+    let total_stake = read_total_stake(ctx.wl_storage, &params, epoch)?;
+    let rate = if total_stake.is_zero() {
+        Dec::zero()
+    } else {
+        Dec::from(inflation) / Dec::from(total_stake)
+    };
+    Ok(RewardRate {
+        inflation,
+        total_stake,
+        rate,
+        max_inflation_rate: params.max_inflation_rate,
+    })
+}
+
 fn bond_deltas<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
     source: Address,
@@ -535,6 +844,69 @@ where
     find_delegations(ctx.wl_storage, &owner, &epoch)
 }
 
+/// Find the delegators of a validator with their bonded amounts at the given
+/// epoch or current when `None`, ordered by their addresses. The validator's
+/// self-bond and the delegators without any bonded tokens at the epoch are
+/// not included.
+///
+/// The results are paginated: at most `limit` delegators (capped at
+/// [`MAX_DELEGATORS_LIMIT`]) are returned, starting after the `after`
+/// delegator, if any. The next page starts after the last delegator received,
+/// until a page shorter than the `limit` is returned.
+///
+/// The bonds are keyed by their source first, so every page still scans the
+/// bond keys of all the sources to find the validator's delegators, which is
+/// not bounded by the `limit`. Only the bonded amounts of the delegators after
+/// the `after` one are read, until the page is full.
+fn validator_delegators<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    validator: Address,
+    limit: u64,
+    after: Option<Address>,
+    epoch: Option<Epoch>,
+) -> storage_api::Result<Vec<(Address, token::Amount)>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let params = read_pos_params(ctx.wl_storage)?;
+    let epoch = epoch.unwrap_or(ctx.wl_storage.storage.last_epoch);
+
+    // The bonds are keyed by their source first, so the bonds to the
+    // validator have to be found among all of them
+    let mut delegators: BTreeSet<Address> = BTreeSet::new();
+    let bonds =
+        storage_api::iter_prefix_bytes(ctx.wl_storage, &bonds_prefix())?;
+    for result in bonds {
+        let (key, _val_bytes) = result?;
+        if let Some((bond_id, _start)) = is_bond_key(&key) {
+            let is_after =
+                after.as_ref().map_or(true, |after| bond_id.source > *after);
+            if bond_id.validator == validator
+                && bond_id.source != validator
+                && is_after
+            {
+                delegators.insert(bond_id.source);
+            }
+        }
+    }
+
+    let limit = limit.min(MAX_DELEGATORS_LIMIT);
+    let mut amounts = vec![];
+    for delegator in delegators {
+        if amounts.len() as u64 >= limit {
+            break;
+        }
+        let amount = bond_handle(&delegator, &validator)
+            .get_sum(ctx.wl_storage, epoch, &params)?
+            .unwrap_or_default();
+        if !amount.is_zero() {
+            amounts.push((delegator, amount));
+        }
+    }
+    Ok(amounts)
+}
+
 /// Validator slashes
 fn validator_slashes<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
@@ -548,6 +920,34 @@ where
     slash_handle.iter(ctx.wl_storage)?.collect()
 }
 
+/// The slashes of a validator that are enqueued to be processed in future
+/// epochs, with the epochs in which they're processed. The final rate of a
+/// slash is only known once it's processed, so the given rate is the minimum
+/// slash rate of the infraction's type.
+fn validator_pending_slashes<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    validator: Address,
+) -> storage_api::Result<Vec<(Epoch, Dec)>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let current_epoch = ctx.wl_storage.storage.last_epoch;
+    let params = read_pos_params(ctx.wl_storage)?;
+    let mut enqueued =
+        find_all_enqueued_slashes(ctx.wl_storage, current_epoch)?;
+    Ok(enqueued
+        .remove(&validator)
+        .into_iter()
+        .flatten()
+        .flat_map(|(processing_epoch, slashes)| {
+            slashes.into_iter().map(move |slash| {
+                (processing_epoch, slash.r#type.get_slash_rate(&params))
+            })
+        })
+        .collect())
+}
+
 /// All slashes
 fn slashes<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
@@ -676,3 +1076,572 @@ fn enrich_bonds_and_unbonds(
         total_withdrawable,
     }
 }
+
+#[cfg(test)]
+mod test {
+    use namada_core::ledger::parameters::storage::get_pos_inflation_amount_key;
+    use namada_core::ledger::storage_api::token::credit_tokens;
+    use namada_core::ledger::storage_api::{StorageRead, StorageWrite};
+    use namada_core::types::address::{self, Address};
+    use namada_core::types::dec::Dec;
+    use namada_core::types::key::RefTo;
+    use namada_core::types::storage::{BlockHeight, Epoch};
+    use namada_core::types::token;
+    use namada_ethereum_bridge::test_utils::{self, TestValidatorKeys};
+    use namada_proof_of_stake::types::{SlashType, ValidatorState};
+    use namada_proof_of_stake::{
+        become_validator, bond_tokens, read_pos_params, staking_token_address,
+        validator_deltas_handle, BecomeValidator, OwnedPosParams,
+    };
+
+    use super::{RewardRate, ValidatorSetSize};
+    use crate::queries::testing::{genesis_validator, TestClient};
+    use crate::queries::RPC;
+
+    /// Test that the keys of a validator initialized with `become_validator`
+    /// are found by its address.
+    #[tokio::test]
+    async fn test_validator_keys() {
+        let mut client = TestClient::new(RPC);
+        let genesis = address::testing::established_address_1();
+        namada_proof_of_stake::test_utils::test_init_genesis(
+            &mut client.wl_storage,
+            OwnedPosParams::default(),
+            [genesis_validator(genesis, 100)].into_iter(),
+            Epoch(0),
+        )
+        .unwrap();
+        let params = read_pos_params(&client.wl_storage).unwrap();
+
+        // Initialize a new validator with its own keys
+        let validator = address::testing::established_address_2();
+        let expected = TestValidatorKeys::generate();
+        become_validator(BecomeValidator {
+            storage: &mut client.wl_storage,
+            params: &params,
+            address: &validator,
+            consensus_key: &expected.consensus.ref_to(),
+            protocol_key: &expected.protocol.ref_to(),
+            eth_cold_key: &expected.eth_gov.ref_to(),
+            eth_hot_key: &expected.eth_bridge.ref_to(),
+            current_epoch: Epoch(0),
+            commission_rate: Dec::new(5, 2).unwrap(),
+            max_commission_rate_change: Dec::new(1, 2).unwrap(),
+            metadata: Default::default(),
+            offset_opt: None,
+        })
+        .unwrap();
+        client.wl_storage.commit_block().unwrap();
+
+        // The keys are set from the pipeline epoch
+        let pipeline_epoch = Epoch(params.pipeline_len);
+        let keys = RPC
+            .vp()
+            .pos()
+            .validator_keys(&client, &validator, &Some(pipeline_epoch))
+            .await
+            .unwrap()
+            .expect("The validator must exist");
+        assert_eq!(keys.consensus_key, Some(expected.consensus.ref_to()));
+        assert_eq!(keys.protocol_key, Some(expected.protocol.ref_to()));
+        assert_eq!(keys.eth_hot_key, Some(expected.eth_bridge.ref_to()));
+        assert_eq!(keys.eth_cold_key, Some(expected.eth_gov.ref_to()));
+
+        // A non-validator address has no keys
+        let non_validator = address::testing::established_address_3();
+        let keys = RPC
+            .vp()
+            .pos()
+            .validator_keys(&client, &non_validator, &None)
+            .await
+            .unwrap();
+        assert!(keys.is_none());
+    }
+
+    /// Test that the validators are counted in each of the validator sets.
+    #[tokio::test]
+    async fn test_validator_set_size() {
+        let mut client = TestClient::new(RPC);
+        let stakes = [
+            (address::testing::established_address_1(), 100),
+            (address::testing::established_address_2(), 90),
+            (address::testing::established_address_3(), 80),
+            (address::testing::established_address_4(), 0),
+        ];
+        let validators: Vec<_> = stakes
+            .into_iter()
+            .map(|(address, stake)| genesis_validator(address, stake))
+            .collect();
+        // Only two validators fit into the consensus set
+        let params = OwnedPosParams {
+            max_validator_slots: 2,
+            ..Default::default()
+        };
+        namada_proof_of_stake::test_utils::test_init_genesis(
+            &mut client.wl_storage,
+            params,
+            validators.into_iter(),
+            Epoch(0),
+        )
+        .unwrap();
+        client.wl_storage.commit_block().unwrap();
+
+        let size = RPC
+            .vp()
+            .pos()
+            .validator_set_size(&client, &None)
+            .await
+            .unwrap();
+        assert_eq!(
+            size,
+            ValidatorSetSize {
+                consensus: 2,
+                below_capacity: 1,
+                below_threshold: 1,
+            }
+        );
+
+        // The sets are also found at a given epoch
+        let size = RPC
+            .vp()
+            .pos()
+            .validator_set_size(&client, &Some(Epoch(1)))
+            .await
+            .unwrap();
+        assert_eq!(size.consensus, 2);
+    }
+
+    /// Test the state of a jailed and of an active validator.
+    #[tokio::test]
+    async fn test_validator_state() {
+        let mut client = TestClient::new(RPC);
+        let jailed = address::testing::established_address_1();
+        let active = address::testing::established_address_2();
+        let validators: Vec<_> = [jailed.clone(), active.clone()]
+            .into_iter()
+            .map(|address| genesis_validator(address, 100))
+            .collect();
+        namada_proof_of_stake::test_utils::test_init_genesis(
+            &mut client.wl_storage,
+            OwnedPosParams::default(),
+            validators.into_iter(),
+            Epoch(0),
+        )
+        .unwrap();
+
+        // Jail the validator from the next epoch
+        let params = read_pos_params(&client.wl_storage).unwrap();
+        namada_proof_of_stake::slash(
+            &mut client.wl_storage,
+            &params,
+            Epoch(0),
+            Epoch(0),
+            0_u64,
+            SlashType::DuplicateVote,
+            &jailed,
+            Epoch(1),
+        )
+        .unwrap();
+        client.wl_storage.commit_block().unwrap();
+
+        let state = |validator: Address, epoch: Option<Epoch>| {
+            let client = &client;
+            async move {
+                RPC.vp()
+                    .pos()
+                    .validator_state(client, &validator, &epoch)
+                    .await
+                    .unwrap()
+            }
+        };
+        assert_eq!(
+            state(jailed.clone(), None).await,
+            Some(ValidatorState::Consensus)
+        );
+        assert_eq!(
+            state(jailed.clone(), Some(Epoch(1))).await,
+            Some(ValidatorState::Jailed)
+        );
+        assert_eq!(
+            state(active.clone(), Some(Epoch(1))).await,
+            Some(ValidatorState::Consensus)
+        );
+
+        // A non-validator address has no state
+        let non_validator = address::testing::established_address_3();
+        assert_eq!(state(non_validator, None).await, None);
+    }
+
+    /// Test that the commission rate changes of a validator are all found in
+    /// its commission history.
+    #[tokio::test]
+    async fn test_validator_commission_history() {
+        let mut client = TestClient::new(RPC);
+        let validator = address::testing::established_address_1();
+        namada_proof_of_stake::test_utils::test_init_genesis(
+            &mut client.wl_storage,
+            OwnedPosParams::default(),
+            [genesis_validator(validator.clone(), 100)].into_iter(),
+            Epoch(0),
+        )
+        .unwrap();
+
+        // Change the rate in two consecutive epochs
+        let params = read_pos_params(&client.wl_storage).unwrap();
+        namada_proof_of_stake::change_validator_commission_rate(
+            &mut client.wl_storage,
+            &validator,
+            Dec::new(6, 2).unwrap(),
+            Epoch(0),
+        )
+        .unwrap();
+        namada_proof_of_stake::change_validator_commission_rate(
+            &mut client.wl_storage,
+            &validator,
+            Dec::new(7, 2).unwrap(),
+            Epoch(1),
+        )
+        .unwrap();
+        client.wl_storage.commit_block().unwrap();
+
+        let history = RPC
+            .vp()
+            .pos()
+            .validator_commission_history(&client, &validator)
+            .await
+            .unwrap();
+        assert_eq!(
+            history,
+            vec![
+                (Epoch(0), Dec::new(5, 2).unwrap()),
+                (Epoch(params.pipeline_len), Dec::new(6, 2).unwrap()),
+                (Epoch(params.pipeline_len + 1), Dec::new(7, 2).unwrap()),
+            ]
+        );
+
+        // A non-validator address has no history
+        let non_validator = address::testing::established_address_2();
+        let history = RPC
+            .vp()
+            .pos()
+            .validator_commission_history(&client, &non_validator)
+            .await
+            .unwrap();
+        assert!(history.is_empty());
+    }
+
+    /// Test that the reward rate is the stored PoS inflation over the total
+    /// stake.
+    #[tokio::test]
+    async fn test_reward_rate() {
+        let mut client = TestClient::new(RPC);
+        let validator = address::testing::established_address_1();
+        namada_proof_of_stake::test_utils::test_init_genesis(
+            &mut client.wl_storage,
+            OwnedPosParams::default(),
+            [genesis_validator(validator, 100)].into_iter(),
+            Epoch(0),
+        )
+        .unwrap();
+        let params = read_pos_params(&client.wl_storage).unwrap();
+
+        // Without any stored inflation, there are no rewards
+        client.wl_storage.commit_block().unwrap();
+        let rate = RPC.vp().pos().reward_rate(&client).await.unwrap();
+        assert_eq!(rate.inflation, token::Amount::zero());
+        assert_eq!(rate.rate, Dec::zero());
+
+        client
+            .wl_storage
+            .write(
+                &get_pos_inflation_amount_key(),
+                token::Amount::native_whole(1),
+            )
+            .unwrap();
+        client.wl_storage.commit_block().unwrap();
+        let rate = RPC.vp().pos().reward_rate(&client).await.unwrap();
+        assert_eq!(
+            rate,
+            RewardRate {
+                inflation: token::Amount::native_whole(1),
+                total_stake: token::Amount::native_whole(100),
+                rate: Dec::new(1, 2).unwrap(),
+                max_inflation_rate: params.max_inflation_rate,
+            }
+        );
+    }
+
+    /// Test that a recorded slash is pending for the slashed validator only,
+    /// until its processing epoch.
+    #[tokio::test]
+    async fn test_validator_pending_slashes() {
+        let mut client = TestClient::new(RPC);
+        let slashed = address::testing::established_address_1();
+        let other = address::testing::established_address_2();
+        let validators: Vec<_> = [slashed.clone(), other.clone()]
+            .into_iter()
+            .map(|address| genesis_validator(address, 100))
+            .collect();
+        namada_proof_of_stake::test_utils::test_init_genesis(
+            &mut client.wl_storage,
+            OwnedPosParams::default(),
+            validators.into_iter(),
+            Epoch(0),
+        )
+        .unwrap();
+        client.wl_storage.commit_block().unwrap();
+
+        let pending = |validator: Address| {
+            let client = &client;
+            async move {
+                RPC.vp()
+                    .pos()
+                    .validator_pending_slashes(client, &validator)
+                    .await
+                    .unwrap()
+            }
+        };
+        assert!(pending(slashed.clone()).await.is_empty());
+
+        let params = read_pos_params(&client.wl_storage).unwrap();
+        namada_proof_of_stake::slash(
+            &mut client.wl_storage,
+            &params,
+            Epoch(0),
+            Epoch(0),
+            0_u64,
+            SlashType::DuplicateVote,
+            &slashed,
+            Epoch(1),
+        )
+        .unwrap();
+        client.wl_storage.commit_block().unwrap();
+
+        assert_eq!(
+            pending(slashed).await,
+            vec![(
+                Epoch(params.slash_processing_epoch_offset()),
+                params.duplicate_vote_min_slash_rate
+            )]
+        );
+        assert!(pending(other).await.is_empty());
+    }
+
+    /// Test that the stake of a validator is returned with its stake deltas
+    /// and that the proofs of the deltas, given only when requested, verify
+    /// against the Merkle root.
+    #[tokio::test]
+    async fn test_validator_stake_with_proof() {
+        use borsh_ext::BorshSerializeExt;
+        use namada_core::ledger::storage::ics23_specs::proof_specs;
+        use namada_core::ledger::storage::{Sha256Hasher, StoreType};
+        use prost::Message;
+
+        use crate::tendermint::merkle::proof::ProofOp;
+
+        let mut client = TestClient::new(RPC);
+        test_utils::init_default_storage(&mut client.wl_storage);
+        client.wl_storage.commit_block().unwrap();
+        let (validator, stake) = test_utils::default_validator();
+
+        // Without the prove flag, there's no proof
+        let response = RPC
+            .vp()
+            .pos()
+            .validator_stake_with_proof(
+                &client, None, None, false, &validator, &None,
+            )
+            .await
+            .unwrap();
+        let data = response.data.expect("The validator must be found");
+        assert_eq!(data.stake, stake);
+        assert!(!data.deltas.is_empty());
+        let sum = data
+            .deltas
+            .iter()
+            .fold(token::Change::zero(), |sum, (_key, change)| sum + *change);
+        assert_eq!(token::Amount::from_change(sum), stake);
+        assert!(response.proof.is_none());
+
+        // With the prove flag, there are two proof ops for each delta, which
+        // verify the delta's value against the Merkle root
+        let response = RPC
+            .vp()
+            .pos()
+            .validator_stake_with_proof(
+                &client, None, None, true, &validator, &None,
+            )
+            .await
+            .unwrap();
+        let proof = response.proof.expect("The proof must be present");
+        assert_eq!(response.data.as_ref(), Some(&data));
+        assert_eq!(proof.ops.len(), 2 * data.deltas.len());
+
+        // The sub proof is verified with the value, then the base proof with
+        // the resulting sub root, which must give the Merkle root
+        let specs = proof_specs::<Sha256Hasher>();
+        let root = client.wl_storage.storage.merkle_root().0.to_vec();
+        let verify = |key: &storage::Key, value: Vec<u8>, ops: &[ProofOp]| {
+            let (store_type, sub_key) = StoreType::sub_key(key).unwrap();
+            let paths = [sub_key.to_string(), store_type.to_string()];
+            let mut value = value;
+            for ((op, spec), path) in ops.iter().zip(&specs).zip(&paths) {
+                assert_eq!(op.key, key.to_string().as_bytes());
+                let commitment_proof =
+                    ics23::CommitmentProof::decode(op.data.as_slice()).unwrap();
+                let existence_proof = match commitment_proof.proof.as_ref() {
+                    Some(ics23::commitment_proof::Proof::Exist(ep)) => ep,
+                    _ => panic!("The proof must be an existence proof"),
+                };
+                let sub_root = ics23::calculate_existence_root::<
+                    ics23::HostFunctionsManager,
+                >(existence_proof)
+                .unwrap();
+                if !ics23::verify_membership::<ics23::HostFunctionsManager>(
+                    &commitment_proof,
+                    spec,
+                    &sub_root,
+                    path.as_bytes(),
+                    &value,
+                ) {
+                    return false;
+                }
+                value = sub_root;
+            }
+            value == root
+        };
+        for ((key, change), ops) in data.deltas.iter().zip(proof.ops.chunks(2))
+        {
+            assert!(verify(key, change.serialize_to_vec(), ops));
+        }
+
+        // A tampered delta doesn't verify
+        let (key, change) = &data.deltas[0];
+        let tampered = *change + token::Change::from(1_i64);
+        assert!(!verify(key, tampered.serialize_to_vec(), &proof.ops[..2]));
+
+        // A non-validator has no stake
+        let response = RPC
+            .vp()
+            .pos()
+            .validator_stake_with_proof(
+                &client,
+                None,
+                None,
+                true,
+                &address::testing::established_address_1(),
+                &None,
+            )
+            .await
+            .unwrap();
+        assert!(response.data.is_none());
+    }
+
+    /// Test that the delegators of a validator are found with their bonds.
+    #[tokio::test]
+    async fn test_validator_delegators() {
+        let mut client = TestClient::new(RPC);
+        let validator = address::testing::established_address_1();
+        let delegators = [
+            address::testing::established_address_2(),
+            address::testing::established_address_3(),
+        ];
+        namada_proof_of_stake::test_utils::test_init_genesis(
+            &mut client.wl_storage,
+            OwnedPosParams::default(),
+            [genesis_validator(validator.clone(), 100)].into_iter(),
+            Epoch(0),
+        )
+        .unwrap();
+
+        let staking_token = staking_token_address(&client.wl_storage);
+        for (delegator, amount) in delegators.iter().zip([10, 20]) {
+            let amount = token::Amount::native_whole(amount);
+            credit_tokens(
+                &mut client.wl_storage,
+                &staking_token,
+                delegator,
+                amount,
+            )
+            .unwrap();
+            bond_tokens(
+                &mut client.wl_storage,
+                Some(delegator),
+                &validator,
+                amount,
+                Epoch(0),
+                None,
+            )
+            .unwrap();
+        }
+        client.wl_storage.commit_block().unwrap();
+
+        // The bonds only contribute from the pipeline epoch
+        let pipeline_epoch = Epoch(0)
+            + read_pos_params(&client.wl_storage).unwrap().pipeline_len;
+        let found = RPC
+            .vp()
+            .pos()
+            .validator_delegators(
+                &client,
+                &validator,
+                &MAX_DELEGATORS_LIMIT,
+                &None,
+                &Some(pipeline_epoch),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            found,
+            vec![
+                (delegators[0].clone(), token::Amount::native_whole(10)),
+                (delegators[1].clone(), token::Amount::native_whole(20)),
+            ]
+        );
+
+        // The delegators can be found page by page, each starting after the
+        // last delegator of the previous one
+        for (after, expected) in [
+            (None, vec![(delegators[0].clone(), 10)]),
+            (
+                Some(delegators[0].clone()),
+                vec![(delegators[1].clone(), 20)],
+            ),
+            (Some(delegators[1].clone()), vec![]),
+        ] {
+            let found = RPC
+                .vp()
+                .pos()
+                .validator_delegators(
+                    &client,
+                    &validator,
+                    &1,
+                    &after,
+                    &Some(pipeline_epoch),
+                )
+                .await
+                .unwrap();
+            let expected: Vec<_> = expected
+                .into_iter()
+                .map(|(delegator, amount)| {
+                    (delegator, token::Amount::native_whole(amount))
+                })
+                .collect();
+            assert_eq!(found, expected);
+        }
+
+        let found = RPC
+            .vp()
+            .pos()
+            .validator_delegators(
+                &client,
+                &validator,
+                &MAX_DELEGATORS_LIMIT,
+                &None,
+                &None,
+            )
+            .await
+            .unwrap();
+        assert!(found.is_empty());
+    }
+}
@@ -1,15 +1,22 @@
 //! Token validity predicate queries
 
+use std::collections::BTreeMap;
+
 use namada_core::ledger::storage::{DBIter, StorageHasher, DB};
 use namada_core::ledger::storage_api;
-use namada_core::ledger::storage_api::token::read_denom;
+use namada_core::ledger::storage_api::token::{read_denom, read_total_supply};
+use namada_core::ledger::storage_api::OptionExt;
 use namada_core::types::address::Address;
+use namada_core::types::storage::{DbKeySeg, Epoch, Key};
 use namada_core::types::token;
 
 use crate::queries::RequestCtx;
 
 router! {TOKEN,
     ( "denomination" / [addr: Address] ) -> Option<token::Denomination> = denomination,
+    ( "denominations" ) -> BTreeMap<Address, u8> = denominations,
+    ( "total_supply" / [token: Address] / [epoch: opt Epoch] )
+        -> token::Amount = total_supply,
 }
 
 /// Get the number of decimal places (in base 10) for a
@@ -25,6 +32,91 @@ where
     read_denom(ctx.wl_storage, &addr)
 }
 
+/// Get the number of decimal places (in base 10) of every token that has its
+/// denomination written in storage. The tokens are found in the registry
+/// written together with their denominations. If the registry is missing,
+/// e.g. in a chain whose denominations were written before it was added, the
+/// whole storage is scanned for the denominations instead.
+fn denominations<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+) -> storage_api::Result<BTreeMap<Address, u8>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let iter = storage_api::iter_prefix_bytes(
+        ctx.wl_storage,
+        &token::denominated_tokens_prefix(),
+    )?;
+    let registered = iter
+        .map(|res| {
+            let (key, _) = res?;
+            let token = token::is_denominated_token_key(&key)
+                .ok_or_err_msg("The key must be a denominated token key")?;
+            let denom = read_denom(ctx.wl_storage, token)?
+                .ok_or_err_msg("A registered token must have a denomination")?;
+            Ok((token.clone(), denom.0))
+        })
+        .collect::<storage_api::Result<BTreeMap<_, _>>>()?;
+    if registered.is_empty() {
+        scan_denominations(ctx)
+    } else {
+        Ok(registered)
+    }
+}
+
+/// Find the denominations of all the tokens by scanning the whole storage for
+/// their denomination keys.
+fn scan_denominations<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+) -> storage_api::Result<BTreeMap<Address, u8>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let is_denom_key = |key: &Key| {
+        matches!(&key.segments[..],
+            [DbKeySeg::AddressSeg(_), DbKeySeg::StringSeg(key)]
+                if key == token::DENOM_STORAGE_KEY)
+    };
+    let iter = storage_api::iter_prefix_with_filter::<token::Denomination, _>(
+        ctx.wl_storage,
+        &Key::default(),
+        is_denom_key,
+    )?;
+    iter.map(|res| {
+        let (key, denom) = res?;
+        match key.segments.into_iter().next() {
+            Some(DbKeySeg::AddressSeg(token)) => Ok((token, denom.0)),
+            _ => unreachable!("The key must have passed the filter"),
+        }
+    })
+    .collect()
+}
+
+/// Get the minted total supply of a token at the given epoch or current when
+/// `None`. The supply isn't recorded per epoch, so only the current epoch is
+/// supported.
+fn total_supply<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    token: Address,
+    epoch: Option<Epoch>,
+) -> storage_api::Result<token::Amount>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let current_epoch = ctx.wl_storage.storage.last_epoch;
+    let epoch = epoch.unwrap_or(current_epoch);
+    if epoch != current_epoch {
+        return Err(storage_api::Error::new(format!(
+            "The total supply can only be queried at the current epoch \
+             {current_epoch}, not at epoch {epoch}."
+        )));
+    }
+    read_total_supply(ctx.wl_storage, &token)
+}
+
 #[cfg(any(test, feature = "async-client"))]
 pub mod client_only_methods {
     use borsh::BorshDeserialize;
@@ -61,3 +153,91 @@ pub mod client_only_methods {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use namada_core::ledger::storage_api::token::{credit_tokens, write_denom};
+    use namada_core::ledger::storage_api::StorageWrite;
+    use namada_core::types::address;
+    use namada_core::types::storage::Epoch;
+    use namada_core::types::token;
+
+    use crate::queries::testing::TestClient;
+    use crate::queries::RPC;
+
+    /// Test that the denominations of all the tokens in storage are found.
+    #[tokio::test]
+    async fn test_denominations() {
+        let mut client = TestClient::new(RPC);
+        let nam = address::nam();
+        let btc = address::btc();
+        write_denom(&mut client.wl_storage, &nam, 6u8.into()).unwrap();
+        write_denom(&mut client.wl_storage, &btc, 8u8.into()).unwrap();
+        client.wl_storage.commit_block().unwrap();
+
+        let denoms = RPC.vp().token().denominations(&client).await.unwrap();
+        assert_eq!(denoms, BTreeMap::from([(nam, 6), (btc, 8)]));
+    }
+
+    /// Test that the denominations written without the registry are found by
+    /// scanning the storage.
+    #[tokio::test]
+    async fn test_denominations_without_registry() {
+        let mut client = TestClient::new(RPC);
+        let nam = address::nam();
+        let btc = address::btc();
+        for (token, denom) in [(&nam, 6u8), (&btc, 8u8)] {
+            client
+                .wl_storage
+                .write(&token::denom_key(token), token::Denomination(denom))
+                .unwrap();
+        }
+        client.wl_storage.commit_block().unwrap();
+
+        let denoms = RPC.vp().token().denominations(&client).await.unwrap();
+        assert_eq!(denoms, BTreeMap::from([(nam, 6), (btc, 8)]));
+    }
+
+    /// Test that the total supply of a token includes the minted tokens.
+    #[tokio::test]
+    async fn test_total_supply() {
+        let mut client = TestClient::new(RPC);
+        let nam = address::nam();
+        let owner = address::testing::established_address_1();
+        let supply = RPC
+            .vp()
+            .token()
+            .total_supply(&client, &nam, &None)
+            .await
+            .unwrap();
+        assert!(supply.is_zero());
+
+        credit_tokens(
+            &mut client.wl_storage,
+            &nam,
+            &owner,
+            token::Amount::native_whole(100),
+        )
+        .unwrap();
+        client.wl_storage.commit_block().unwrap();
+        for epoch in [None, Some(Epoch(0))] {
+            let supply = RPC
+                .vp()
+                .token()
+                .total_supply(&client, &nam, &epoch)
+                .await
+                .unwrap();
+            assert_eq!(supply, token::Amount::native_whole(100));
+        }
+
+        // The supply isn't recorded for other epochs
+        let result = RPC
+            .vp()
+            .token()
+            .total_supply(&client, &nam, &Some(Epoch(1)))
+            .await;
+        assert!(result.is_err());
+    }
+}
@@ -1,14 +1,35 @@
 //! Wallet address and key aliases.
 
+use std::collections::BTreeMap;
 use std::convert::Infallible;
 use std::fmt::Display;
 use std::hash::Hash;
 use std::io::Read;
 use std::str::FromStr;
 
-use borsh::{BorshDeserialize, BorshSerialize};
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use namada_core::types::address::{Address, InternalAddress};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// The maximum length of an alias in bytes
+pub const MAX_ALIAS_LEN: usize = 64;
+
+/// Errors from alias validation
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum AliasError {
+    #[error("An alias must not be empty")]
+    Empty,
+    #[error(
+        "The alias \"{0}\" is longer than the maximum of {} bytes",
+        MAX_ALIAS_LEN
+    )]
+    TooLong(String),
+    #[error(
+        "The alias {alias:?} contains an invalid character {invalid_char:?}"
+    )]
+    InvalidChar { alias: String, invalid_char: char },
+}
 
 /// Aliases created from raw strings are kept in-memory as given, but their
 /// `Serialize` and `Display` instance converts them to lowercase. Their
@@ -32,6 +53,29 @@ impl Alias {
         self.0.is_empty()
     }
 
+    /// Check that the alias is not empty, is not longer than
+    /// [`MAX_ALIAS_LEN`] and contains no path separators or control
+    /// characters, which could collide with storage key encoding.
+    pub fn validate(&self) -> Result<(), AliasError> {
+        if self.0.is_empty() {
+            return Err(AliasError::Empty);
+        }
+        if self.0.len() > MAX_ALIAS_LEN {
+            return Err(AliasError::TooLong(self.0.clone()));
+        }
+        match self
+            .0
+            .chars()
+            .find(|c| matches!(c, '/' | '\\') || c.is_control())
+        {
+            Some(invalid_char) => Err(AliasError::InvalidChar {
+                alias: self.0.clone(),
+                invalid_char,
+            }),
+            None => Ok(()),
+        }
+    }
+
     /// If the alias is reserved for an internal address,
     /// return that address
     pub fn is_reserved(alias: impl AsRef<str>) -> Option<Address> {
@@ -60,6 +104,25 @@ impl BorshDeserialize for Alias {
     }
 }
 
+impl BorshSchema for Alias {
+    fn add_definitions_recursively(
+        definitions: &mut BTreeMap<
+            borsh::schema::Declaration,
+            borsh::schema::Definition,
+        >,
+    ) {
+        // Encoded as a normalized `String`
+        let fields =
+            borsh::schema::Fields::UnnamedFields(vec!["string".into()]);
+        let definition = borsh::schema::Definition::Struct { fields };
+        definitions.insert(Self::declaration(), definition);
+    }
+
+    fn declaration() -> borsh::schema::Declaration {
+        "Alias".into()
+    }
+}
+
 impl Serialize for Alias {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -158,3 +221,35 @@ pub fn validator_consensus_key(validator_alias: &Alias) -> Alias {
 pub fn validator_tendermint_node_key(validator_alias: &Alias) -> Alias {
     format!("{validator_alias}-tendermint-node-key").into()
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_alias_validate() {
+        for alias in ["albert", "validator-0", "Faucet_1", "a.b", "ünïcode"] {
+            assert_eq!(Alias::from(alias).validate(), Ok(()), "{alias}");
+        }
+        assert_eq!(Alias::from("").validate(), Err(AliasError::Empty));
+        assert_eq!(Alias::from("a".repeat(MAX_ALIAS_LEN)).validate(), Ok(()));
+        assert!(matches!(
+            Alias::from("a".repeat(MAX_ALIAS_LEN + 1)).validate(),
+            Err(AliasError::TooLong(_))
+        ));
+        for (alias, invalid_char) in [
+            ("albert/balance", '/'),
+            ("albert\\balance", '\\'),
+            ("albert\n", '\n'),
+            ("al\u{0}bert", '\u{0}'),
+        ] {
+            assert_eq!(
+                Alias::from(alias).validate(),
+                Err(AliasError::InvalidChar {
+                    alias: alias.to_string(),
+                    invalid_char,
+                })
+            );
+        }
+    }
+}
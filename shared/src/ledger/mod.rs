@@ -44,6 +44,7 @@ where
     use crate::types::storage::TxIndex;
     use crate::types::transaction::TxType;
 
+    ctx.check_query_data_len(request)?;
     let mut tx = Tx::try_from(&request.data[..]).into_storage_result()?;
     tx.validate_tx().into_storage_result()?;
 
@@ -144,7 +145,7 @@ mod test {
     use tendermint_rpc::{Error as RpcError, Response};
 
     use crate::ledger::events::log::EventLog;
-    use crate::ledger::queries::Client;
+    use crate::ledger::queries::{Client, DEFAULT_MAX_QUERY_DATA_LEN};
     use crate::ledger::{EncodedResponseQuery, RequestCtx, RequestQuery};
     use crate::proto::{Code, Data, Tx};
     use crate::vm::wasm::{TxCache, VpCache};
@@ -244,6 +245,7 @@ mod test {
                 vp_wasm_cache: self.vp_wasm_cache.clone(),
                 tx_wasm_cache: self.tx_wasm_cache.clone(),
                 storage_read_past_height_limit: None,
+                max_query_data_len: DEFAULT_MAX_QUERY_DATA_LEN,
             };
             // TODO: this is a hack to propagate errors to the caller, we should
             // really permit error types other than [`std::io::Error`]
@@ -367,6 +367,7 @@ pub fn network(
         "2023-08-30T00:00:00Z",
         "--archive-dir",
         &archive_dir,
+        "--allow-legacy-signatures",
     ];
     if let Some(consensus_timeout_commit) = consensus_timeout_commit {
         args.push("--consensus-timeout-commit");
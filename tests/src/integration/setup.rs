@@ -10,6 +10,7 @@ use namada_apps::config;
 use namada_apps::config::genesis::chain::Finalized;
 use namada_apps::config::genesis::templates;
 use namada_apps::config::genesis::templates::load_and_validate;
+use namada_apps::config::genesis::transactions::ValidationOptions;
 use namada_apps::config::TendermintMode;
 use namada_apps::facade::tendermint::Timeout;
 use namada_apps::facade::tendermint_proto::google::protobuf::Timestamp;
@@ -54,8 +55,11 @@ pub fn initialize_genesis() -> Result<(MockNode, MockServicesController)> {
         .expect("Could not write genesis files into test chain directory.");
 
     // Finalize the genesis config to derive the chain ID
-    let templates = load_and_validate(&template_dir)
-        .expect("Missing or invalid genesis files");
+    let templates = load_and_validate(
+        &template_dir,
+        &ValidationOptions::with_legacy_signatures(),
+    )
+    .expect("Missing or invalid genesis files");
     let genesis_time = Default::default();
     let chain_id_prefix = ChainIdPrefix::from_str("integration-test").unwrap();
     let genesis = config::genesis::chain::finalize(
@@ -85,6 +89,7 @@ pub fn initialize_genesis() -> Result<(MockNode, MockServicesController)> {
             dont_archive: true,
             archive_dir: None,
             genesis_time,
+            allow_legacy_signatures: true,
         },
     );
 
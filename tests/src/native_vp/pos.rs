@@ -98,7 +98,11 @@
 use namada::proof_of_stake::parameters::{OwnedPosParams, PosParams};
 use namada::proof_of_stake::test_utils::test_init_genesis as init_genesis;
 use namada::proof_of_stake::types::GenesisValidator;
+use namada::types::address::Address;
+use namada::types::key::{self, RefTo};
 use namada::types::storage::Epoch;
+use namada::types::token;
+use namada_core::types::dec::Dec;
 
 use crate::tx::tx_host_env;
 
@@ -144,6 +148,27 @@ pub fn init_pos(
     })
 }
 
+/// A genesis validator with the given stake, a newly generated consensus key
+/// and the default commission rates
+pub fn genesis_validator(
+    address: &Address,
+    tokens: token::Amount,
+) -> GenesisValidator {
+    GenesisValidator {
+        address: address.clone(),
+        tokens,
+        consensus_key: key::common::PublicKey::Ed25519(
+            key::testing::gen_keypair::<key::ed25519::SigScheme>().ref_to(),
+        ),
+        protocol_key: key::testing::keypair_2().ref_to(),
+        eth_cold_key: key::testing::keypair_3().ref_to(),
+        eth_hot_key: key::testing::keypair_4().ref_to(),
+        commission_rate: Dec::new(5, 2).expect("Cannot fail"),
+        max_commission_rate_change: Dec::new(1, 2).expect("Cannot fail"),
+        metadata: Default::default(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
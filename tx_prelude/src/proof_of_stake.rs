@@ -1,21 +1,61 @@
 //! Proof of Stake system integration with functions for transactions
 
+use std::collections::{BTreeMap, BTreeSet};
+
+use namada_core::ledger::storage_api::collections::lazy_map::{
+    NestedSubKey, SubKey,
+};
 use namada_core::types::dec::Dec;
 use namada_core::types::hash::Hash;
 use namada_core::types::transaction::pos::InitValidator;
 use namada_core::types::{key, token};
 pub use namada_proof_of_stake::parameters::PosParams;
-use namada_proof_of_stake::types::ValidatorMetaData;
+use namada_proof_of_stake::storage::{
+    is_unbond_key, unbonds_for_source_prefix,
+};
+use namada_proof_of_stake::types::{ValidatorMetaData, ValidatorState};
 use namada_proof_of_stake::{
-    become_validator, bond_tokens, change_validator_commission_rate,
-    change_validator_metadata, claim_reward_tokens, deactivate_validator,
-    reactivate_validator, read_pos_params, redelegate_tokens, unbond_tokens,
-    unjail_validator, withdraw_tokens, BecomeValidator,
+    become_validator, bond_handle, bond_slashes, bond_tokens,
+    change_consensus_key, change_validator_commission_rate,
+    change_validator_metadata, claim_all_reward_tokens, claim_reward_tokens,
+    claim_reward_tokens_until, deactivate_validator,
+    estimate_unbond_slash_exposure, is_consensus_key_used, is_validator,
+    query_reward_tokens, reactivate_validator, read_pos_params,
+    read_validator_stake, redelegate_tokens, unbond_handle, unbond_tokens,
+    unjail_validator, validator_commission_rate_handle,
+    validator_incoming_redelegations_handle, validator_state_handle,
+    withdraw_tokens, BecomeValidator,
 };
 pub use namada_proof_of_stake::{parameters, types, ResultSlashing};
 
 use super::*;
 
+/// The record of a new bond, e.g. for emitting events referencing the bond
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct BondReceipt {
+    /// The epoch from which the new bond contributes to the validator's stake
+    pub start_epoch: Epoch,
+    /// The total amount bonded by the source to the validator at the start
+    /// epoch, including the new bond
+    pub total_amount: token::Amount,
+}
+
+/// The amount of bonded tokens to redelegate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RedelegationAmount {
+    /// Redelegate the given amount
+    Amount(token::Amount),
+    /// Redelegate the whole bond of the owner to the source validator, as
+    /// read at the time of the redelegation
+    AllAvailable,
+}
+
+impl From<token::Amount> for RedelegationAmount {
+    fn from(amount: token::Amount) -> Self {
+        Self::Amount(amount)
+    }
+}
+
 impl Ctx {
     /// Self-bond tokens to a validator when `source` is `None` or equal to
     /// the `validator` address, or delegate tokens from the `source` to the
@@ -30,6 +70,29 @@ impl Ctx {
         bond_tokens(self, source, validator, amount, current_epoch, None)
     }
 
+    /// Bond tokens like [`Ctx::bond_tokens`] and return a receipt with the
+    /// epoch at which the bond was recorded.
+    pub fn bond_tokens_tracked(
+        &mut self,
+        source: Option<&Address>,
+        validator: &Address,
+        amount: token::Amount,
+    ) -> EnvResult<BondReceipt> {
+        let current_epoch = self.get_block_epoch()?;
+        bond_tokens(self, source, validator, amount, current_epoch, None)?;
+
+        let params = read_pos_params(self)?;
+        let start_epoch = current_epoch + params.pipeline_len;
+        let source = source.unwrap_or(validator);
+        let total_amount = bond_handle(source, validator)
+            .get_sum(self, start_epoch, &params)?
+            .unwrap_or_default();
+        Ok(BondReceipt {
+            start_epoch,
+            total_amount,
+        })
+    }
+
     /// Unbond self-bonded tokens from a validator when `source` is `None`
     /// or equal to the `validator` address, or unbond delegated tokens from
     /// the `source` to the `validator`.
@@ -43,6 +106,37 @@ impl Ctx {
         unbond_tokens(self, source, validator, amount, current_epoch, false)
     }
 
+    /// Estimate the worst-case amount of an unbond of the given `amount` that
+    /// can be slashed by the validator's enqueued slashes. This is only
+    /// advisory, the actual slashes are applied when they're processed.
+    pub fn estimate_unbond_slash_exposure(
+        &self,
+        source: Option<&Address>,
+        validator: &Address,
+        amount: token::Amount,
+    ) -> EnvResult<token::Amount> {
+        let current_epoch = self.get_block_epoch()?;
+        estimate_unbond_slash_exposure(
+            self,
+            source,
+            validator,
+            amount,
+            current_epoch,
+        )
+    }
+
+    /// Find the amounts slashed from a self-bond to a validator when
+    /// `source` is `None` or equal to the `validator` address, or from a
+    /// delegation from the `source` to the `validator`, by the epochs of the
+    /// slashed infractions. Empty when the bond hasn't been slashed.
+    pub fn bond_slashes(
+        &self,
+        source: Option<&Address>,
+        validator: &Address,
+    ) -> EnvResult<Vec<(Epoch, token::Amount)>> {
+        bond_slashes(self, source, validator)
+    }
+
     /// Withdraw unbonded tokens from a self-bond to a validator when
     /// `source` is `None` or equal to the `validator` address, or withdraw
     /// unbonded tokens delegated to the `validator` to the `source`.
@@ -55,6 +149,63 @@ impl Ctx {
         withdraw_tokens(self, source, validator, current_epoch)
     }
 
+    /// Withdraw the matured unbonds of the `source` from all the validators
+    /// that it has unbonded from. Validators with nothing to withdraw yet are
+    /// skipped. Returns the total amount of withdrawn tokens.
+    pub fn withdraw_all(
+        &mut self,
+        source: &Address,
+    ) -> EnvResult<token::Amount> {
+        let current_epoch = self.get_block_epoch()?;
+        // Collected in a sorted set for a deterministic order of withdrawals
+        let mut validators: BTreeSet<Address> = BTreeSet::new();
+        let prefix = unbonds_for_source_prefix(source);
+        for res in storage_api::iter_prefix_bytes(self, &prefix)? {
+            let (key, _val) = res?;
+            if let Some((bond_id, _start, withdraw_epoch)) = is_unbond_key(&key)
+            {
+                if withdraw_epoch <= current_epoch {
+                    validators.insert(bond_id.validator);
+                }
+            }
+        }
+        let mut total = token::Amount::zero();
+        for validator in validators {
+            total +=
+                withdraw_tokens(self, Some(source), &validator, current_epoch)?;
+        }
+        Ok(total)
+    }
+
+    /// Get the schedule of the unbonds of a self-bond to a validator when
+    /// `source` is `None` or equal to the `validator` address, or of the
+    /// unbonds of the `source`'s delegation to the `validator`. The unbonds
+    /// that have not been withdrawn yet are listed by the epoch from which
+    /// they can be withdrawn, in ascending order, with the amounts of the
+    /// unbonds withdrawable from the same epoch summed up.
+    pub fn unbond_schedule(
+        &self,
+        source: Option<&Address>,
+        validator: &Address,
+    ) -> EnvResult<Vec<(Epoch, token::Amount)>> {
+        let source = source.unwrap_or(validator);
+        let mut schedule: BTreeMap<Epoch, token::Amount> = BTreeMap::new();
+        for unbond in unbond_handle(source, validator).iter(self)? {
+            let (
+                NestedSubKey::Data {
+                    key: _start_epoch,
+                    nested_sub_key: SubKey::Data(withdraw_epoch),
+                },
+                amount,
+            ) = unbond?;
+            let total = schedule.entry(withdraw_epoch).or_default();
+            *total = total.checked_add(amount).ok_or_else(|| {
+                Error::new_const("Unbond schedule amount overflow")
+            })?;
+        }
+        Ok(schedule.into_iter().collect())
+    }
+
     /// Change validator commission rate.
     pub fn change_validator_commission_rate(
         &mut self,
@@ -65,21 +216,98 @@ impl Ctx {
         change_validator_commission_rate(self, validator, *rate, current_epoch)
     }
 
+    /// Get the commission rate of a validator in the current epoch. Returns an
+    /// error for a non-validator address.
+    pub fn validator_commission_rate(
+        &self,
+        validator: &Address,
+    ) -> EnvResult<Dec> {
+        let current_epoch = self.get_block_epoch()?;
+        self.validator_commission_rate_at_epoch(validator, current_epoch)
+    }
+
+    /// Get the commission rate of a validator effective at the given epoch,
+    /// which may be a future epoch up to the pipeline offset to preview a
+    /// scheduled rate change. Returns an error for a non-validator address.
+    pub fn validator_commission_rate_at_epoch(
+        &self,
+        validator: &Address,
+        epoch: Epoch,
+    ) -> EnvResult<Dec> {
+        let params = read_pos_params(self)?;
+        validator_commission_rate_handle(validator)
+            .get(self, epoch, &params)?
+            .ok_or_else(|| {
+                storage_api::Error::new(format!(
+                    "The address {validator} is not a validator"
+                ))
+            })
+    }
+
+    /// Get the stake of a validator in the current epoch
+    pub fn read_validator_stake(
+        &self,
+        validator: &Address,
+    ) -> EnvResult<token::Amount> {
+        let current_epoch = self.get_block_epoch()?;
+        self.read_validator_stake_at_epoch(validator, current_epoch)
+    }
+
+    /// Get the stake of a validator at the given epoch, which may be a past
+    /// epoch or a future epoch up to the pipeline offset
+    pub fn read_validator_stake_at_epoch(
+        &self,
+        validator: &Address,
+        epoch: Epoch,
+    ) -> EnvResult<token::Amount> {
+        let params = read_pos_params(self)?;
+        read_validator_stake(self, &params, validator, epoch)
+    }
+
+    /// Change the consensus key of a validator, effective at the pipeline
+    /// offset. The new key must not be already used by any validator.
+    pub fn change_consensus_key(
+        &mut self,
+        validator: &Address,
+        consensus_key: &key::common::PublicKey,
+    ) -> TxResult {
+        let current_epoch = self.get_block_epoch()?;
+        change_consensus_key(self, validator, consensus_key, current_epoch)
+    }
+
     /// Unjail a jailed validator and re-enter the validator sets.
     pub fn unjail_validator(&mut self, validator: &Address) -> TxResult {
         let current_epoch = self.get_block_epoch()?;
         unjail_validator(self, validator, current_epoch)
     }
 
-    /// Redelegate bonded tokens from one validator to another one.
+    /// Redelegate bonded tokens from one validator to another one. With
+    /// [`RedelegationAmount::AllAvailable`], the whole bond of the `owner` to
+    /// the `src_validator` is redelegated.
     pub fn redelegate_tokens(
         &mut self,
         owner: &Address,
         src_validator: &Address,
         dest_validator: &Address,
-        amount: token::Amount,
+        amount: impl Into<RedelegationAmount>,
     ) -> TxResult {
         let current_epoch = self.get_block_epoch()?;
+        let amount = match amount.into() {
+            RedelegationAmount::Amount(amount) => amount,
+            RedelegationAmount::AllAvailable => {
+                let params = read_pos_params(self)?;
+                let pipeline_epoch = current_epoch + params.pipeline_len;
+                let bonded = bond_handle(owner, src_validator)
+                    .get_sum(self, pipeline_epoch, &params)?
+                    .unwrap_or_default();
+                if bonded.is_zero() {
+                    return Err(storage_api::Error::new_const(
+                        "There are no bonded tokens to redelegate",
+                    ));
+                }
+                bonded
+            }
+        };
         redelegate_tokens(
             self,
             owner,
@@ -90,6 +318,34 @@ impl Ctx {
         )
     }
 
+    /// Get the epoch recorded for the last redelegation of the `owner`'s
+    /// tokens to `src_validator`, which is the epoch at which the redelegated
+    /// tokens started contributing to the validator's stake. Because tokens
+    /// can only be redelegated onwards from `src_validator` once this
+    /// redelegation can no longer be slashed, this can be used to implement
+    /// redelegation cooldowns. Returns `None` when there is no recorded
+    /// redelegation.
+    pub fn last_redelegation_epoch(
+        &self,
+        owner: &Address,
+        src_validator: &Address,
+    ) -> EnvResult<Option<Epoch>> {
+        validator_incoming_redelegations_handle(src_validator).get(self, owner)
+    }
+
+    /// Get the first epoch from which the tokens redelegated in the given
+    /// `epoch` can be redelegated again from the destination validator,
+    /// i.e. when the redelegation can no longer be slashed for the source
+    /// validator's infractions. This can be used to preview the cooldown
+    /// before submitting a redelegation.
+    pub fn redelegation_cooldown_end(&self, epoch: Epoch) -> EnvResult<Epoch> {
+        let params = read_pos_params(self)?;
+        // The epoch recorded for the redelegation, as given by
+        // `Ctx::last_redelegation_epoch`
+        let redelegation_epoch = epoch + params.pipeline_len;
+        Ok(redelegation_epoch.prev() + params.slash_processing_epoch_offset())
+    }
+
     /// Claim available reward tokens
     pub fn claim_reward_tokens(
         &mut self,
@@ -100,11 +356,76 @@ impl Ctx {
         claim_reward_tokens(self, source, validator, current_epoch)
     }
 
+    /// Claim the reward tokens accrued before the `until_epoch`, leaving the
+    /// later rewards unclaimed. The `until_epoch` must not be past the
+    /// current epoch. Claiming until the current epoch is the same as
+    /// [`Ctx::claim_reward_tokens`].
+    pub fn claim_reward_tokens_until(
+        &mut self,
+        source: Option<&Address>,
+        validator: &Address,
+        until_epoch: Epoch,
+    ) -> EnvResult<token::Amount> {
+        let current_epoch = self.get_block_epoch()?;
+        claim_reward_tokens_until(
+            self,
+            source,
+            validator,
+            until_epoch,
+            current_epoch,
+        )
+    }
+
+    /// Get the reward tokens that can be claimed in the current epoch,
+    /// without claiming them. Returns zero when there are no rewards.
+    pub fn claimable_rewards(
+        &self,
+        source: Option<&Address>,
+        validator: &Address,
+    ) -> EnvResult<token::Amount> {
+        let current_epoch = self.get_block_epoch()?;
+        self.claimable_rewards_at_epoch(source, validator, current_epoch)
+    }
+
+    /// Get the reward tokens that can be claimed in the given epoch, without
+    /// claiming them. Returns zero when there are no rewards.
+    pub fn claimable_rewards_at_epoch(
+        &self,
+        source: Option<&Address>,
+        validator: &Address,
+        epoch: Epoch,
+    ) -> EnvResult<token::Amount> {
+        query_reward_tokens(self, source, validator, epoch)
+    }
+
+    /// Claim the rewards from all the validators that the `source` is bonded
+    /// to or that it has rewards tallied in the rewards counter from.
+    /// Validators with nothing to claim are skipped. Returns the total amount
+    /// of claimed rewards.
+    pub fn claim_all_rewards(
+        &mut self,
+        source: &Address,
+    ) -> EnvResult<token::Amount> {
+        let current_epoch = self.get_block_epoch()?;
+        claim_all_reward_tokens(self, source, current_epoch)
+    }
+
+    /// Check if the given address is a validator. Returns `false` for an
+    /// address that doesn't exist.
+    pub fn is_validator(&self, address: &Address) -> EnvResult<bool> {
+        is_validator(self, address)
+    }
+
     /// Attempt to initialize a validator account. On success, returns the
     /// initialized validator account's address.
     pub fn init_validator(
         &mut self,
-        InitValidator {
+        init_validator: InitValidator,
+        validator_vp_code_hash: Hash,
+    ) -> EnvResult<Address> {
+        // Reject invalid validators before any storage changes
+        self.validate_become_validator(&init_validator)?;
+        let InitValidator {
             account_keys,
             threshold,
             consensus_key,
@@ -118,9 +439,7 @@ impl Ctx {
             website,
             discord_handle,
             validator_vp_code_hash: _,
-        }: InitValidator,
-        validator_vp_code_hash: Hash,
-    ) -> EnvResult<Address> {
+        } = init_validator;
         let current_epoch = self.get_block_epoch()?;
         // Init validator account
         let validator_address = self.init_account(validator_vp_code_hash)?;
@@ -157,12 +476,67 @@ impl Ctx {
         Ok(validator_address)
     }
 
+    /// Check that a validator can be initialized from the given data without
+    /// making any storage changes. This checks that the keys use the
+    /// supported schemes, that the consensus key is not used by another
+    /// validator and that the commission rates are between 0 and 1. The
+    /// address of a new validator is always available, because it's
+    /// generated for a new account in [`Ctx::init_validator`].
+    pub fn validate_become_validator(
+        &self,
+        init_validator: &InitValidator,
+    ) -> EnvResult<()> {
+        init_validator
+            .validate_key_schemes()
+            .map_err(storage_api::Error::new)?;
+        if is_consensus_key_used(self, &init_validator.consensus_key)? {
+            return Err(storage_api::Error::new_const(
+                "The consensus key is already used by another validator",
+            ));
+        }
+        let is_rate_valid =
+            |rate: Dec| !rate.is_negative() && rate <= Dec::one();
+        if !is_rate_valid(init_validator.commission_rate) {
+            return Err(storage_api::Error::new_const(
+                "The commission rate must be between 0 and 1",
+            ));
+        }
+        if !is_rate_valid(init_validator.max_commission_rate_change) {
+            return Err(storage_api::Error::new_const(
+                "The max commission rate change must be between 0 and 1",
+            ));
+        }
+        Ok(())
+    }
+
     /// Deactivate validator
     pub fn deactivate_validator(&mut self, validator: &Address) -> TxResult {
         let current_epoch = self.get_block_epoch()?;
         deactivate_validator(self, validator, current_epoch)
     }
 
+    /// Deactivate validator, unless it's already inactive at the pipeline
+    /// epoch. Returns `true` if the validator got deactivated or `false` if
+    /// it was already inactive, in which case nothing is changed.
+    pub fn deactivate_validator_if_active(
+        &mut self,
+        validator: &Address,
+    ) -> EnvResult<bool> {
+        let current_epoch = self.get_block_epoch()?;
+        let params = read_pos_params(self)?;
+        let pipeline_epoch = current_epoch + params.pipeline_len;
+        let state = validator_state_handle(validator).get(
+            self,
+            pipeline_epoch,
+            &params,
+        )?;
+        if state == Some(ValidatorState::Inactive) {
+            return Ok(false);
+        }
+        deactivate_validator(self, validator, current_epoch)?;
+        Ok(true)
+    }
+
     /// Reactivate validator
     pub fn reactivate_validator(&mut self, validator: &Address) -> TxResult {
         let current_epoch = self.get_block_epoch()?;
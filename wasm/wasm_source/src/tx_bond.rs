@@ -21,15 +21,14 @@ mod tests {
     use std::collections::BTreeSet;
 
     use namada::ledger::pos::{OwnedPosParams, PosVP};
-    use namada::proof_of_stake::types::{GenesisValidator, WeightedValidator};
+    use namada::proof_of_stake::types::WeightedValidator;
     use namada::proof_of_stake::{
         bond_handle, read_consensus_validator_set_addresses_with_stake,
         read_total_stake, read_validator_stake,
     };
-    use namada::types::dec::Dec;
     use namada::types::storage::Epoch;
     use namada_tests::log::test;
-    use namada_tests::native_vp::pos::init_pos;
+    use namada_tests::native_vp::pos::{genesis_validator, init_pos};
     use namada_tests::native_vp::TestNativeVpEnv;
     use namada_tests::tx::*;
     use namada_tx_prelude::address::testing::{
@@ -39,7 +38,6 @@ mod tests {
     use namada_tx_prelude::borsh_ext::BorshSerializeExt;
     use namada_tx_prelude::chain::ChainId;
     use namada_tx_prelude::key::testing::arb_common_keypair;
-    use namada_tx_prelude::key::RefTo;
     use namada_tx_prelude::proof_of_stake::parameters::testing::arb_pos_params;
     use namada_tx_prelude::token;
     use proptest::prelude::*;
@@ -79,24 +77,8 @@ mod tests {
         dbg!(&initial_stake, &bond);
         let is_delegation =
             matches!(&bond.source, Some(source) if *source != bond.validator);
-        let consensus_key = key::testing::keypair_1().ref_to();
-        let protocol_key = key::testing::keypair_2().ref_to();
-        let commission_rate = Dec::new(5, 2).expect("Cannot fail");
-        let max_commission_rate_change = Dec::new(1, 2).expect("Cannot fail");
-        let eth_cold_key = key::testing::keypair_3().ref_to();
-        let eth_hot_key = key::testing::keypair_4().ref_to();
-
-        let genesis_validators = [GenesisValidator {
-            address: bond.validator.clone(),
-            tokens: initial_stake,
-            consensus_key,
-            protocol_key,
-            eth_cold_key,
-            eth_hot_key,
-            commission_rate,
-            max_commission_rate_change,
-            metadata: Default::default(),
-        }];
+        let genesis_validators =
+            [genesis_validator(&bond.validator, initial_stake)];
 
         let pos_params =
             init_pos(&genesis_validators[..], &pos_params, Epoch(0));
@@ -339,6 +321,129 @@ mod tests {
         Ok(())
     }
 
+    /// Test that the receipt of a tracked bond records the pipeline epoch and
+    /// the total bonded amount.
+    #[test]
+    fn test_bond_tokens_tracked() {
+        let validator = address::testing::established_address_1();
+        let delegator = address::testing::established_address_2();
+        let initial_stake = token::Amount::native_whole(100);
+        let genesis_validators = [genesis_validator(&validator, initial_stake)];
+        let pos_params = init_pos(
+            &genesis_validators[..],
+            &OwnedPosParams::default(),
+            Epoch(0),
+        );
+        let pipeline_epoch = Epoch(pos_params.pipeline_len);
+
+        tx_host_env::with(|tx_env| {
+            tx_env.spawn_accounts([&delegator]);
+            let native_token = tx_env.wl_storage.storage.native_token.clone();
+            tx_env.credit_tokens(
+                &delegator,
+                &native_token,
+                token::Amount::native_whole(20),
+            );
+            tx_env.credit_tokens(
+                &validator,
+                &native_token,
+                token::Amount::native_whole(10),
+            );
+        });
+
+        // Delegations
+        let receipt = ctx()
+            .bond_tokens_tracked(
+                Some(&delegator),
+                &validator,
+                token::Amount::native_whole(15),
+            )
+            .unwrap();
+        assert_eq!(receipt.start_epoch, pipeline_epoch);
+        assert_eq!(receipt.total_amount, token::Amount::native_whole(15));
+        let receipt = ctx()
+            .bond_tokens_tracked(
+                Some(&delegator),
+                &validator,
+                token::Amount::native_whole(5),
+            )
+            .unwrap();
+        assert_eq!(receipt.start_epoch, pipeline_epoch);
+        assert_eq!(receipt.total_amount, token::Amount::native_whole(20));
+
+        // Self-bond
+        let receipt = ctx()
+            .bond_tokens_tracked(
+                None,
+                &validator,
+                token::Amount::native_whole(10),
+            )
+            .unwrap();
+        assert_eq!(receipt.start_epoch, pipeline_epoch);
+        assert_eq!(
+            receipt.total_amount,
+            initial_stake + token::Amount::native_whole(10)
+        );
+    }
+
+    /// Test reading a validator's stake at an explicit epoch, before and
+    /// after a bond is effective, and in the current epoch.
+    #[test]
+    fn test_read_validator_stake_at_epoch() {
+        let validator = address::testing::established_address_1();
+        let delegator = address::testing::established_address_2();
+        let initial_stake = token::Amount::native_whole(100);
+        let genesis_validators = [genesis_validator(&validator, initial_stake)];
+        let pos_params = init_pos(
+            &genesis_validators[..],
+            &OwnedPosParams::default(),
+            Epoch(0),
+        );
+        let pipeline_epoch = Epoch(pos_params.pipeline_len);
+
+        tx_host_env::with(|tx_env| {
+            tx_env.spawn_accounts([&delegator]);
+            let native_token = tx_env.wl_storage.storage.native_token.clone();
+            tx_env.credit_tokens(
+                &delegator,
+                &native_token,
+                token::Amount::native_whole(10),
+            );
+        });
+        let amount = token::Amount::native_whole(10);
+        ctx()
+            .bond_tokens(Some(&delegator), &validator, amount)
+            .unwrap();
+
+        // The bond is only effective at the pipeline epoch
+        assert_eq!(
+            ctx().read_validator_stake(&validator).unwrap(),
+            initial_stake
+        );
+        assert_eq!(
+            ctx()
+                .read_validator_stake_at_epoch(&validator, pipeline_epoch)
+                .unwrap(),
+            initial_stake + amount
+        );
+
+        // Once the pipeline epoch is the current one, the stake at a past
+        // epoch is unchanged
+        tx_host_env::with(|env| {
+            env.wl_storage.storage.block.epoch = pipeline_epoch;
+        });
+        assert_eq!(
+            ctx().read_validator_stake(&validator).unwrap(),
+            initial_stake + amount
+        );
+        assert_eq!(
+            ctx()
+                .read_validator_stake_at_epoch(&validator, Epoch(0))
+                .unwrap(),
+            initial_stake
+        );
+    }
+
     prop_compose! {
         /// Generates an initial validator stake and a bond, while making sure
         /// that the `initial_stake + bond.amount <= u64::MAX` to avoid
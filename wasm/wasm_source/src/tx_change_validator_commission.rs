@@ -28,14 +28,13 @@ mod tests {
     use namada::types::dec::{Dec, POS_DECIMAL_PRECISION};
     use namada::types::storage::Epoch;
     use namada_tests::log::test;
-    use namada_tests::native_vp::pos::init_pos;
+    use namada_tests::native_vp::pos::{genesis_validator, init_pos};
     use namada_tests::native_vp::TestNativeVpEnv;
     use namada_tests::tx::*;
     use namada_tx_prelude::address::testing::arb_established_address;
     use namada_tx_prelude::borsh_ext::BorshSerializeExt;
     use namada_tx_prelude::chain::ChainId;
     use namada_tx_prelude::key::testing::arb_common_keypair;
-    use namada_tx_prelude::key::RefTo;
     use namada_tx_prelude::proof_of_stake::parameters::testing::arb_pos_params;
     use namada_tx_prelude::token;
     use proptest::prelude::*;
@@ -62,6 +61,57 @@ mod tests {
         }
     }
 
+    /// Test that a scheduled commission rate change can be read at the epoch
+    /// from which it's effective, while the current rate is unchanged.
+    #[test]
+    fn test_validator_commission_rate_at_epoch() {
+        let validator = address::testing::established_address_1();
+        let genesis_validators = [genesis_validator(
+            &validator,
+            token::Amount::native_whole(100),
+        )];
+        let initial_rate = genesis_validators[0].commission_rate;
+        let pos_params = init_pos(
+            &genesis_validators[..],
+            &OwnedPosParams::default(),
+            Epoch(0),
+        );
+        let pipeline_epoch = Epoch(pos_params.pipeline_len);
+
+        let new_rate = Dec::new(6, 2).expect("Cannot fail");
+        ctx()
+            .change_validator_commission_rate(&validator, &new_rate)
+            .unwrap();
+
+        assert_eq!(
+            ctx().validator_commission_rate(&validator).unwrap(),
+            initial_rate
+        );
+        for epoch in Epoch(0).iter_range(pos_params.pipeline_len) {
+            assert_eq!(
+                ctx()
+                    .validator_commission_rate_at_epoch(&validator, epoch)
+                    .unwrap(),
+                initial_rate
+            );
+        }
+        assert_eq!(
+            ctx()
+                .validator_commission_rate_at_epoch(&validator, pipeline_epoch)
+                .unwrap(),
+            new_rate
+        );
+
+        // A non-validator address has no commission rate
+        let non_validator = address::testing::established_address_2();
+        assert!(ctx().validator_commission_rate(&non_validator).is_err());
+        assert!(
+            ctx()
+                .validator_commission_rate_at_epoch(&non_validator, Epoch(0))
+                .is_err()
+        );
+    }
+
     fn test_tx_change_validator_commission_aux(
         initial_rate: Dec,
         max_change: Dec,
@@ -69,25 +119,13 @@ mod tests {
         key: key::common::SecretKey,
         pos_params: OwnedPosParams,
     ) -> TxResult {
-        let consensus_key = key::testing::keypair_1().ref_to();
-        let protocol_key = key::testing::keypair_2().ref_to();
-
-        let eth_hot_key = key::common::PublicKey::Secp256k1(
-            key::testing::gen_keypair::<key::secp256k1::SigScheme>().ref_to(),
-        );
-        let eth_cold_key = key::common::PublicKey::Secp256k1(
-            key::testing::gen_keypair::<key::secp256k1::SigScheme>().ref_to(),
-        );
         let genesis_validators = [GenesisValidator {
-            address: commission_change.validator.clone(),
-            tokens: token::Amount::from_uint(1_000_000, 0).unwrap(),
-            consensus_key,
-            protocol_key,
             commission_rate: initial_rate,
             max_commission_rate_change: max_change,
-            eth_hot_key,
-            eth_cold_key,
-            metadata: Default::default(),
+            ..genesis_validator(
+                &commission_change.validator,
+                token::Amount::from_uint(1_000_000, 0).unwrap(),
+            )
         }];
 
         let pos_params =
@@ -27,3 +27,78 @@ fn apply_tx(ctx: &mut Ctx, tx_data: Tx) -> TxResult {
         commission_rate,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use namada::ledger::pos::OwnedPosParams;
+    use namada::proof_of_stake::{
+        read_validator_email, validator_commission_rate_handle,
+    };
+    use namada::types::dec::Dec;
+    use namada::types::storage::Epoch;
+    use namada_tests::log::test;
+    use namada_tests::native_vp::pos::{genesis_validator, init_pos};
+    use namada_tests::tx::*;
+    use namada_tx_prelude::token;
+
+    use super::*;
+
+    /// Test that a commission rate change through the metadata change is
+    /// bounded by the validator's max commission rate change and that no
+    /// metadata is changed when the rate change is rejected.
+    #[test]
+    fn test_change_validator_metadata_commission_rate() {
+        let validator = address::testing::established_address_1();
+        let genesis_validators = [genesis_validator(
+            &validator,
+            token::Amount::native_whole(100),
+        )];
+        let pos_params = init_pos(
+            &genesis_validators[..],
+            &OwnedPosParams::default(),
+            Epoch(0),
+        );
+        let pipeline_epoch = Epoch(pos_params.pipeline_len);
+        let rate_at_pipeline = || {
+            validator_commission_rate_handle(&validator)
+                .get(ctx(), pipeline_epoch, &pos_params)
+                .unwrap()
+        };
+
+        // A change within the limit is applied together with the metadata
+        let new_rate = Dec::new(55, 3).expect("Cannot fail");
+        ctx()
+            .change_validator_metadata(
+                &validator,
+                Some("validator@namada.net".to_string()),
+                None,
+                None,
+                None,
+                Some(new_rate),
+            )
+            .unwrap();
+        assert_eq!(rate_at_pipeline(), Some(new_rate));
+        assert_eq!(
+            read_validator_email(ctx(), &validator).unwrap(),
+            Some("validator@namada.net".to_string())
+        );
+
+        // A change over the limit is rejected without changing the metadata
+        let err = ctx()
+            .change_validator_metadata(
+                &validator,
+                Some("other@namada.net".to_string()),
+                None,
+                None,
+                None,
+                Some(Dec::new(2, 1).expect("Cannot fail")),
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("too large"), "{err}");
+        assert_eq!(rate_at_pipeline(), Some(new_rate));
+        assert_eq!(
+            read_validator_email(ctx(), &validator).unwrap(),
+            Some("validator@namada.net".to_string())
+        );
+    }
+}
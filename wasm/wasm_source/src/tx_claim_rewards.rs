@@ -13,3 +13,235 @@ fn apply_tx(ctx: &mut Ctx, tx_data: Tx) -> TxResult {
     ctx.claim_reward_tokens(withdraw.source.as_ref(), &withdraw.validator)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use namada::ledger::pos::{rewards_counter_key, OwnedPosParams};
+    use namada::proof_of_stake::{
+        get_last_reward_claim_epoch, validator_rewards_products_handle,
+    };
+    use namada::types::dec::Dec;
+    use namada::types::storage::Epoch;
+    use namada_tests::log::test;
+    use namada_tests::native_vp::pos::{genesis_validator, init_pos};
+    use namada_tests::tx::*;
+    use namada_tx_prelude::token;
+
+    use super::*;
+
+    /// Test that the rewards of a delegator bonded to multiple validators are
+    /// all claimed at once and summed up, together with the rewards tallied
+    /// from a validator that it's no longer bonded to, skipping the
+    /// validators with nothing to claim.
+    #[test]
+    fn test_claim_all_rewards() {
+        let validators = [
+            address::testing::established_address_1(),
+            address::testing::established_address_2(),
+            address::testing::established_address_3(),
+            address::testing::address_from_simple_seed(5),
+        ];
+        let delegator = address::testing::established_address_4();
+        let genesis_validators = validators
+            .iter()
+            .map(|address| {
+                genesis_validator(address, token::Amount::native_whole(100))
+            })
+            .collect::<Vec<_>>();
+        init_pos(
+            &genesis_validators[..],
+            &OwnedPosParams::default(),
+            Epoch(0),
+        );
+
+        let native_token = tx_host_env::with(|tx_env| {
+            tx_env.spawn_accounts([&delegator]);
+            let native_token = tx_env.wl_storage.storage.native_token.clone();
+            tx_env.credit_tokens(
+                &delegator,
+                &native_token,
+                token::Amount::native_whole(30),
+            );
+            native_token
+        });
+
+        // Delegate to all the validators but the last one and tally some
+        // rewards for the first two of them and for the last one
+        for validator in &validators[..3] {
+            ctx()
+                .bond_tokens(
+                    Some(&delegator),
+                    validator,
+                    token::Amount::native_whole(10),
+                )
+                .unwrap();
+        }
+        ctx()
+            .write(
+                &rewards_counter_key(&delegator, &validators[0]),
+                token::Amount::native_whole(5),
+            )
+            .unwrap();
+        ctx()
+            .write(
+                &rewards_counter_key(&delegator, &validators[1]),
+                token::Amount::native_whole(7),
+            )
+            .unwrap();
+        ctx()
+            .write(
+                &rewards_counter_key(&delegator, &validators[3]),
+                token::Amount::native_whole(3),
+            )
+            .unwrap();
+        tx_host_env::commit_tx_and_block();
+
+        // Nothing can be claimed in the first epoch
+        tx_host_env::with(|env| {
+            env.wl_storage.storage.block.epoch =
+                env.wl_storage.storage.block.epoch.next();
+        });
+
+        let claimed = ctx().claim_all_rewards(&delegator).unwrap();
+        assert_eq!(claimed, token::Amount::native_whole(15));
+        let balance_key = token::balance_key(&native_token, &delegator);
+        let balance: token::Amount =
+            ctx().read(&balance_key).unwrap().unwrap_or_default();
+        assert_eq!(balance, token::Amount::native_whole(15));
+
+        // The validator with nothing to claim was skipped
+        for (validator, expected) in validators.iter().zip([
+            Some(Epoch(1)),
+            Some(Epoch(1)),
+            None,
+            Some(Epoch(1)),
+        ]) {
+            assert_eq!(
+                get_last_reward_claim_epoch(ctx(), &delegator, validator)
+                    .unwrap(),
+                expected
+            );
+        }
+
+        // Everything has been claimed already
+        let claimed = ctx().claim_all_rewards(&delegator).unwrap();
+        assert!(claimed.is_zero());
+    }
+
+    /// Test that claiming the rewards up to a past epoch leaves the rewards of
+    /// the later epochs to be claimed.
+    #[test]
+    fn test_claim_rewards_until_epoch() {
+        let validator = address::testing::established_address_1();
+        let genesis_validators = [genesis_validator(
+            &validator,
+            token::Amount::native_whole(100),
+        )];
+        init_pos(
+            &genesis_validators[..],
+            &OwnedPosParams::default(),
+            Epoch(0),
+        );
+
+        // The self-bond earns 10, 20 and 30 tokens in the first three epochs
+        let rewards_products = validator_rewards_products_handle(&validator);
+        for (epoch, rate) in [(0, 1), (1, 2), (2, 3)] {
+            rewards_products
+                .insert(ctx(), Epoch(epoch), Dec::new(rate, 1).unwrap())
+                .unwrap();
+        }
+        tx_host_env::commit_tx_and_block();
+        tx_host_env::with(|env| {
+            env.wl_storage.storage.block.epoch = Epoch(3);
+        });
+        let native_token = tx_host_env::with(|env| {
+            env.wl_storage.storage.native_token.clone()
+        });
+        let balance_key = token::balance_key(&native_token, &validator);
+        let read_balance = || -> token::Amount {
+            ctx().read(&balance_key).unwrap().unwrap_or_default()
+        };
+        let initial_balance = read_balance();
+
+        // Rewards cannot be claimed past the current epoch
+        assert!(
+            ctx()
+                .claim_reward_tokens_until(None, &validator, Epoch(4))
+                .is_err()
+        );
+
+        // Only the rewards of the epochs before the cutoff are claimed
+        let claimed = ctx()
+            .claim_reward_tokens_until(None, &validator, Epoch(2))
+            .unwrap();
+        assert_eq!(claimed, token::Amount::native_whole(30));
+        assert_eq!(
+            get_last_reward_claim_epoch(ctx(), &validator, &validator).unwrap(),
+            Some(Epoch(2))
+        );
+
+        // Nothing is left to claim up to an earlier epoch
+        let claimed = ctx()
+            .claim_reward_tokens_until(None, &validator, Epoch(1))
+            .unwrap();
+        assert!(claimed.is_zero());
+
+        // The later rewards are claimed with the current epoch
+        let claimed = ctx().claim_reward_tokens(None, &validator).unwrap();
+        assert_eq!(claimed, token::Amount::native_whole(30));
+        assert_eq!(
+            read_balance(),
+            initial_balance + token::Amount::native_whole(60)
+        );
+    }
+
+    /// Test reading the claimable rewards at a past and at the current epoch,
+    /// without claiming them.
+    #[test]
+    fn test_claimable_rewards_at_epoch() {
+        let validator = address::testing::established_address_1();
+        let genesis_validators = [genesis_validator(
+            &validator,
+            token::Amount::native_whole(100),
+        )];
+        init_pos(
+            &genesis_validators[..],
+            &OwnedPosParams::default(),
+            Epoch(0),
+        );
+
+        // The self-bond earns 10, 20 and 30 tokens in the first three epochs
+        let rewards_products = validator_rewards_products_handle(&validator);
+        for (epoch, rate) in [(0, 1), (1, 2), (2, 3)] {
+            rewards_products
+                .insert(ctx(), Epoch(epoch), Dec::new(rate, 1).unwrap())
+                .unwrap();
+        }
+        tx_host_env::commit_tx_and_block();
+        tx_host_env::with(|env| {
+            env.wl_storage.storage.block.epoch = Epoch(3);
+        });
+
+        // The rewards are computed at the end of an epoch, so a past epoch
+        // only includes the rewards of the epochs before it
+        for (epoch, expected) in [(0, 0), (1, 10), (2, 30), (3, 60)] {
+            assert_eq!(
+                ctx()
+                    .claimable_rewards_at_epoch(None, &validator, Epoch(epoch))
+                    .unwrap(),
+                token::Amount::native_whole(expected)
+            );
+        }
+        // The current epoch is the default
+        assert_eq!(
+            ctx().claimable_rewards(None, &validator).unwrap(),
+            token::Amount::native_whole(60)
+        );
+
+        // Nothing has been claimed
+        assert_eq!(
+            get_last_reward_claim_epoch(ctx(), &validator, &validator).unwrap(),
+            None
+        );
+    }
+}
@@ -10,3 +10,57 @@ fn apply_tx(ctx: &mut Ctx, tx_data: Tx) -> TxResult {
         .wrap_err("failed to decode an Address")?;
     ctx.deactivate_validator(&validator)
 }
+
+#[cfg(test)]
+mod tests {
+    use namada::ledger::pos::OwnedPosParams;
+    use namada::proof_of_stake::types::ValidatorState;
+    use namada::proof_of_stake::validator_state_handle;
+    use namada::types::storage::Epoch;
+    use namada_tests::log::test;
+    use namada_tests::native_vp::pos::{genesis_validator, init_pos};
+    use namada_tests::tx::*;
+    use namada_tx_prelude::token;
+
+    use super::*;
+
+    /// Test that an active validator gets deactivated and that deactivating
+    /// an already inactive validator is rejected, or is a no-op with
+    /// `deactivate_validator_if_active`.
+    #[test]
+    fn test_deactivate_validator_if_active() {
+        let validator = address::testing::established_address_1();
+        let genesis_validators = [genesis_validator(
+            &validator,
+            token::Amount::native_whole(100),
+        )];
+        let pos_params = init_pos(
+            &genesis_validators[..],
+            &OwnedPosParams::default(),
+            Epoch(0),
+        );
+        let pipeline_epoch = Epoch(pos_params.pipeline_len);
+        let state_at_pipeline = || {
+            validator_state_handle(&validator)
+                .get(ctx(), pipeline_epoch, &pos_params)
+                .unwrap()
+        };
+        assert_eq!(state_at_pipeline(), Some(ValidatorState::Consensus));
+
+        // An active validator gets deactivated
+        let deactivated =
+            ctx().deactivate_validator_if_active(&validator).unwrap();
+        assert!(deactivated);
+        assert_eq!(state_at_pipeline(), Some(ValidatorState::Inactive));
+
+        // An already inactive validator is left as is
+        let deactivated =
+            ctx().deactivate_validator_if_active(&validator).unwrap();
+        assert!(!deactivated);
+        assert_eq!(state_at_pipeline(), Some(ValidatorState::Inactive));
+
+        // The plain deactivation fails for an already inactive validator
+        let err = ctx().deactivate_validator(&validator).unwrap_err();
+        assert!(err.to_string().contains("already inactive"), "{err}");
+    }
+}
@@ -44,3 +44,103 @@ fn apply_tx(ctx: &mut Ctx, tx_data: Tx) -> TxResult {
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use namada::ledger::pos::OwnedPosParams;
+    use namada::proof_of_stake::types::GenesisValidator;
+    use namada::types::dec::Dec;
+    use namada::types::hash::Hash;
+    use namada::types::storage::Epoch;
+    use namada_tests::log::test;
+    use namada_tests::native_vp::pos::{genesis_validator, init_pos};
+    use namada_tests::tx::*;
+    use namada_tx_prelude::key::RefTo;
+    use namada_tx_prelude::token;
+
+    use super::*;
+
+    /// Test that the validation of a new validator rejects a duplicate
+    /// consensus key and out-of-bounds commission rates.
+    #[test]
+    fn test_validate_become_validator() {
+        let genesis_consensus_key = key::testing::keypair_1().ref_to();
+        let genesis_validators = [GenesisValidator {
+            consensus_key: genesis_consensus_key.clone(),
+            ..genesis_validator(
+                &address::testing::established_address_1(),
+                token::Amount::native_whole(1_000),
+            )
+        }];
+        init_pos(&genesis_validators, &OwnedPosParams::default(), Epoch(0));
+
+        let eth_key = || {
+            key::testing::gen_keypair::<key::secp256k1::SigScheme>().ref_to()
+        };
+        let init_validator = InitValidator {
+            account_keys: vec![key::testing::keypair_2().ref_to()],
+            threshold: 1,
+            consensus_key: key::common::PublicKey::Ed25519(
+                key::testing::gen_keypair::<key::ed25519::SigScheme>().ref_to(),
+            ),
+            eth_cold_key: eth_key(),
+            eth_hot_key: eth_key(),
+            protocol_key: key::testing::keypair_2().ref_to(),
+            commission_rate: Dec::new(5, 2).expect("Cannot fail"),
+            max_commission_rate_change: Dec::new(1, 2).expect("Cannot fail"),
+            email: "null@null.net".to_string(),
+            description: None,
+            website: None,
+            discord_handle: None,
+            validator_vp_code_hash: Hash::zero(),
+        };
+        assert!(ctx().validate_become_validator(&init_validator).is_ok());
+
+        // A consensus key of the genesis validator is a duplicate
+        let duplicate_key = InitValidator {
+            consensus_key: genesis_consensus_key,
+            ..init_validator.clone()
+        };
+        assert!(ctx().validate_become_validator(&duplicate_key).is_err());
+        assert!(ctx().init_validator(duplicate_key, Hash::zero()).is_err());
+
+        // Commission rates must be between 0 and 1
+        for rate in [Dec::new(-1, 2).unwrap(), Dec::new(101, 2).unwrap()] {
+            let invalid_rate = InitValidator {
+                commission_rate: rate,
+                ..init_validator.clone()
+            };
+            assert!(ctx().validate_become_validator(&invalid_rate).is_err());
+            let invalid_max_change = InitValidator {
+                max_commission_rate_change: rate,
+                ..init_validator.clone()
+            };
+            assert!(
+                ctx()
+                    .validate_become_validator(&invalid_max_change)
+                    .is_err()
+            );
+        }
+    }
+
+    /// Test that a genesis validator is found to be a validator, unlike a
+    /// plain account.
+    #[test]
+    fn test_is_validator() {
+        let validator = address::testing::established_address_1();
+        let genesis_validators = [genesis_validator(
+            &validator,
+            token::Amount::native_whole(1_000),
+        )];
+        init_pos(&genesis_validators, &OwnedPosParams::default(), Epoch(0));
+
+        let account = address::testing::established_address_2();
+        tx_host_env::with(|tx_env| tx_env.spawn_accounts([&account]));
+
+        assert!(ctx().is_validator(&validator).unwrap());
+        assert!(!ctx().is_validator(&account).unwrap());
+        // A non-existent address
+        let unknown = address::testing::established_address_3();
+        assert!(!ctx().is_validator(&unknown).unwrap());
+    }
+}
@@ -25,22 +25,20 @@ mod tests {
     use std::collections::BTreeSet;
 
     use namada::ledger::pos::{OwnedPosParams, PosVP};
-    use namada::proof_of_stake::types::{GenesisValidator, WeightedValidator};
+    use namada::proof_of_stake::types::WeightedValidator;
     use namada::proof_of_stake::{
         bond_handle, read_consensus_validator_set_addresses_with_stake,
         read_total_stake, read_validator_stake, unbond_handle,
     };
-    use namada::types::dec::Dec;
     use namada::types::storage::Epoch;
     use namada_tests::log::test;
-    use namada_tests::native_vp::pos::init_pos;
+    use namada_tests::native_vp::pos::{genesis_validator, init_pos};
     use namada_tests::native_vp::TestNativeVpEnv;
     use namada_tests::tx::*;
     use namada_tx_prelude::address::InternalAddress;
     use namada_tx_prelude::borsh_ext::BorshSerializeExt;
     use namada_tx_prelude::chain::ChainId;
     use namada_tx_prelude::key::testing::arb_common_keypair;
-    use namada_tx_prelude::key::RefTo;
     use namada_tx_prelude::proof_of_stake::parameters::testing::arb_pos_params;
     use namada_tx_prelude::token;
     use proptest::prelude::*;
@@ -66,6 +64,146 @@ mod tests {
         }
     }
 
+    /// Test that the redelegation cooldown ends once the redelegation can no
+    /// longer be slashed and that chained redelegations are rejected before.
+    #[test]
+    fn test_redelegation_cooldown_end() {
+        let owner = address::testing::established_address_1();
+        let validators = [
+            address::testing::established_address_2(),
+            address::testing::established_address_3(),
+            address::testing::established_address_4(),
+        ];
+        let genesis_validators: Vec<_> = validators
+            .iter()
+            .map(|address| {
+                genesis_validator(address, token::Amount::native_whole(1_000))
+            })
+            .collect();
+        let pos_params = init_pos(
+            &genesis_validators[..],
+            &OwnedPosParams::default(),
+            Epoch(0),
+        );
+
+        let cooldown_end = ctx().redelegation_cooldown_end(Epoch(0)).unwrap();
+        assert_eq!(
+            cooldown_end,
+            Epoch(
+                pos_params.pipeline_len - 1
+                    + pos_params.slash_processing_epoch_offset()
+            )
+        );
+        assert_eq!(
+            ctx().redelegation_cooldown_end(Epoch(10)).unwrap(),
+            cooldown_end + 10
+        );
+
+        let amount = token::Amount::native_whole(10);
+        tx_host_env::with(|tx_env| {
+            let native_token = tx_env.wl_storage.storage.native_token.clone();
+            tx_env.spawn_accounts([&owner]);
+            tx_env.credit_tokens(&owner, &native_token, amount);
+        });
+        ctx()
+            .bond_tokens(Some(&owner), &validators[0], amount)
+            .unwrap();
+        ctx()
+            .redelegate_tokens(&owner, &validators[0], &validators[1], amount)
+            .unwrap();
+        tx_host_env::commit_tx_and_block();
+
+        // The cooldown matches the recorded redelegation epoch
+        let redelegation_epoch = ctx()
+            .last_redelegation_epoch(&owner, &validators[1])
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            redelegation_epoch.prev()
+                + pos_params.slash_processing_epoch_offset(),
+            cooldown_end
+        );
+
+        // Redelegating again before the end of the cooldown is chained
+        assert!(
+            ctx()
+                .redelegate_tokens(
+                    &owner,
+                    &validators[1],
+                    &validators[2],
+                    amount
+                )
+                .is_err()
+        );
+    }
+
+    /// Test that the whole bond of an owner is redelegated with
+    /// `RedelegationAmount::AllAvailable` and that it's rejected when the
+    /// owner has no bond.
+    #[test]
+    fn test_redelegate_all_available() {
+        let owner = address::testing::established_address_1();
+        let validators = [
+            address::testing::established_address_2(),
+            address::testing::established_address_3(),
+        ];
+        let genesis_validators: Vec<_> = validators
+            .iter()
+            .map(|address| {
+                genesis_validator(address, token::Amount::native_whole(1_000))
+            })
+            .collect();
+        let pos_params = init_pos(
+            &genesis_validators[..],
+            &OwnedPosParams::default(),
+            Epoch(0),
+        );
+
+        // An owner without a bond has nothing to redelegate
+        assert!(
+            ctx()
+                .redelegate_tokens(
+                    &owner,
+                    &validators[0],
+                    &validators[1],
+                    proof_of_stake::RedelegationAmount::AllAvailable,
+                )
+                .is_err()
+        );
+
+        let amount = token::Amount::native_whole(10);
+        tx_host_env::with(|tx_env| {
+            let native_token = tx_env.wl_storage.storage.native_token.clone();
+            tx_env.spawn_accounts([&owner]);
+            tx_env.credit_tokens(&owner, &native_token, amount);
+        });
+        ctx()
+            .bond_tokens(Some(&owner), &validators[0], amount)
+            .unwrap();
+        tx_host_env::commit_tx_and_block();
+
+        ctx()
+            .redelegate_tokens(
+                &owner,
+                &validators[0],
+                &validators[1],
+                proof_of_stake::RedelegationAmount::AllAvailable,
+            )
+            .unwrap();
+
+        let pipeline_epoch = Epoch(pos_params.pipeline_len);
+        let src_bond = bond_handle(&owner, &validators[0])
+            .get_sum(ctx(), pipeline_epoch, &pos_params)
+            .unwrap()
+            .unwrap_or_default();
+        let dest_bond = bond_handle(&owner, &validators[1])
+            .get_sum(ctx(), pipeline_epoch, &pos_params)
+            .unwrap()
+            .unwrap_or_default();
+        assert_eq!(src_bond, token::Amount::zero());
+        assert_eq!(dest_bond, amount);
+    }
+
     // TODO: more assertions needed!!
     fn test_tx_redelegate_aux(
         initial_stake: token::Amount,
@@ -80,37 +218,15 @@ mod tests {
         };
         dbg!(&initial_stake, &redelegation);
 
-        let consensus_key_1 = key::testing::keypair_1().ref_to();
-        let consensus_key_2 = key::testing::keypair_2().ref_to();
-        let protocol_key = key::testing::keypair_2().ref_to();
-        let eth_cold_key = key::testing::keypair_3().ref_to();
-        let eth_hot_key = key::testing::keypair_4().ref_to();
-        let commission_rate = Dec::new(5, 2).expect("Cannot fail");
-        let max_commission_rate_change = Dec::new(1, 2).expect("Cannot fail");
-
         let genesis_validators = [
-            GenesisValidator {
-                address: redelegation.src_validator.clone(),
-                tokens: token::Amount::zero(),
-                consensus_key: consensus_key_1,
-                protocol_key: protocol_key.clone(),
-                eth_cold_key: eth_cold_key.clone(),
-                eth_hot_key: eth_hot_key.clone(),
-                commission_rate,
-                max_commission_rate_change,
-                metadata: Default::default(),
-            },
-            GenesisValidator {
-                address: redelegation.dest_validator.clone(),
-                tokens: token::Amount::zero(),
-                consensus_key: consensus_key_2,
-                protocol_key,
-                eth_cold_key,
-                eth_hot_key,
-                commission_rate,
-                max_commission_rate_change,
-                metadata: Default::default(),
-            },
+            genesis_validator(
+                &redelegation.src_validator,
+                token::Amount::zero(),
+            ),
+            genesis_validator(
+                &redelegation.dest_validator,
+                token::Amount::zero(),
+            ),
         ];
 
         let pos_params =
@@ -315,6 +431,25 @@ mod tests {
             "The validator set at pipeline offset should have changed"
         );
 
+        // Check that the redelegation epoch has been recorded for the
+        // destination validator only
+        assert_eq!(
+            ctx().last_redelegation_epoch(
+                &redelegation.owner,
+                &redelegation.dest_validator
+            )?,
+            Some(Epoch(pos_params.pipeline_len)),
+            "The redelegation must be recorded at the pipeline epoch"
+        );
+        assert_eq!(
+            ctx().last_redelegation_epoch(
+                &redelegation.owner,
+                &redelegation.src_validator
+            )?,
+            None,
+            "No redelegation to the source validator must be recorded"
+        );
+
         // Check that PoS account balance is unchanged by the redelegation
         let pos_balance_post: token::Amount =
             ctx().read(&pos_balance_key)?.unwrap();
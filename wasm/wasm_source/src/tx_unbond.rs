@@ -28,22 +28,24 @@ mod tests {
     use std::collections::BTreeSet;
 
     use namada::ledger::pos::{OwnedPosParams, PosVP};
-    use namada::proof_of_stake::types::{GenesisValidator, WeightedValidator};
+    use namada::proof_of_stake::types::{SlashType, WeightedValidator};
     use namada::proof_of_stake::{
-        bond_handle, read_consensus_validator_set_addresses_with_stake,
-        read_total_stake, read_validator_stake, unbond_handle,
+        bond_handle, compute_cubic_slash_rate,
+        copy_validator_sets_and_positions, process_slashes,
+        read_consensus_validator_set_addresses_with_stake, read_total_stake,
+        read_validator_stake, slash, store_total_consensus_stake,
+        unbond_handle,
     };
     use namada::types::dec::Dec;
     use namada::types::storage::Epoch;
     use namada_tests::log::test;
-    use namada_tests::native_vp::pos::init_pos;
+    use namada_tests::native_vp::pos::{genesis_validator, init_pos};
     use namada_tests::native_vp::TestNativeVpEnv;
     use namada_tests::tx::*;
     use namada_tx_prelude::address::InternalAddress;
     use namada_tx_prelude::borsh_ext::BorshSerializeExt;
     use namada_tx_prelude::chain::ChainId;
     use namada_tx_prelude::key::testing::arb_common_keypair;
-    use namada_tx_prelude::key::RefTo;
     use namada_tx_prelude::proof_of_stake::parameters::testing::arb_pos_params;
     use namada_tx_prelude::token;
     use proptest::prelude::*;
@@ -69,6 +71,239 @@ mod tests {
         }
     }
 
+    /// Test the estimate of the slashable amount of an unbond from a
+    /// validator with an enqueued slash.
+    #[test]
+    fn test_estimate_unbond_slash_exposure() {
+        let slashed = address::testing::established_address_1();
+        let other = address::testing::established_address_2();
+        let genesis_validators = [
+            genesis_validator(&slashed, token::Amount::native_whole(10)),
+            genesis_validator(&other, token::Amount::native_whole(990)),
+        ];
+        let pos_params = init_pos(
+            &genesis_validators[..],
+            &OwnedPosParams::default(),
+            Epoch(0),
+        );
+        let amount = token::Amount::native_whole(5);
+
+        // Nothing is exposed without slashes
+        assert!(
+            ctx()
+                .estimate_unbond_slash_exposure(None, &slashed, amount)
+                .unwrap()
+                .is_zero()
+        );
+
+        slash(
+            ctx(),
+            &pos_params,
+            Epoch(0),
+            Epoch(0),
+            0_u64,
+            SlashType::DuplicateVote,
+            &slashed,
+            Epoch(1),
+        )
+        .unwrap();
+
+        let rate = std::cmp::max(
+            SlashType::DuplicateVote.get_slash_rate(&pos_params),
+            compute_cubic_slash_rate(ctx(), &pos_params, Epoch(0)).unwrap(),
+        );
+        assert!(rate < Dec::one());
+        let exposure = ctx()
+            .estimate_unbond_slash_exposure(None, &slashed, amount)
+            .unwrap();
+        assert_eq!(exposure, rate * amount);
+        assert!(!exposure.is_zero());
+
+        // The amount is capped at the bonded amount
+        let exposure = ctx()
+            .estimate_unbond_slash_exposure(
+                None,
+                &slashed,
+                token::Amount::native_whole(50),
+            )
+            .unwrap();
+        assert_eq!(exposure, rate * token::Amount::native_whole(10));
+
+        // The validator without slashes is not exposed
+        assert!(
+            ctx()
+                .estimate_unbond_slash_exposure(None, &other, amount)
+                .unwrap()
+                .is_zero()
+        );
+    }
+
+    /// Test reading the slashes applied to a self-bond of a slashed
+    /// validator, including its unbonded part, and to a delegation
+    /// redelegated away from it before the infraction.
+    #[test]
+    fn test_bond_slashes() {
+        let validator = address::testing::established_address_1();
+        let other = address::testing::established_address_2();
+        let delegator = address::testing::established_address_3();
+        let genesis_validators = [
+            genesis_validator(&validator, token::Amount::native_whole(10)),
+            genesis_validator(&other, token::Amount::native_whole(990)),
+        ];
+        let pos_params = init_pos(
+            &genesis_validators[..],
+            &OwnedPosParams::default(),
+            Epoch(0),
+        );
+        let advance_epoch = || {
+            tx_host_env::commit_tx_and_block();
+            let epoch = tx_host_env::with(|env| {
+                env.wl_storage.storage.block.epoch =
+                    env.wl_storage.storage.block.epoch.next();
+                env.wl_storage.storage.block.epoch
+            });
+            copy_validator_sets_and_positions(
+                ctx(),
+                &pos_params,
+                epoch,
+                epoch + pos_params.pipeline_len,
+            )
+            .unwrap();
+            store_total_consensus_stake(ctx(), epoch).unwrap();
+            epoch
+        };
+
+        // There are no slashes before any are applied
+        assert!(ctx().bond_slashes(None, &validator).unwrap().is_empty());
+
+        // A delegation to the validator that starts contributing to its stake
+        // at the pipeline epoch
+        let delegation = token::Amount::native_whole(20);
+        tx_host_env::with(|tx_env| {
+            let native_token = tx_env.wl_storage.storage.native_token.clone();
+            tx_env.spawn_accounts([&delegator]);
+            tx_env.credit_tokens(&delegator, &native_token, delegation);
+        });
+        ctx()
+            .bond_tokens(Some(&delegator), &validator, delegation)
+            .unwrap();
+        let mut epoch = Epoch(0);
+        while epoch < Epoch(pos_params.pipeline_len) {
+            epoch = advance_epoch();
+        }
+
+        // Unbond a part of the self-bond and redelegate the delegation to the
+        // other validator before the infraction, as the slashed validator
+        // can't be unbonded from until its slash is processed
+        ctx()
+            .unbond_tokens(None, &validator, token::Amount::native_whole(4))
+            .unwrap();
+        ctx()
+            .redelegate_tokens(&delegator, &validator, &other, delegation)
+            .unwrap();
+
+        // Enqueue a slash for an infraction in the current epoch. The slashed
+        // amounts are rounded up, as in the slash processing.
+        let infraction_epoch = epoch;
+        slash(
+            ctx(),
+            &pos_params,
+            epoch,
+            infraction_epoch,
+            0_u64,
+            SlashType::DuplicateVote,
+            &validator,
+            epoch.next(),
+        )
+        .unwrap();
+        let rate = std::cmp::max(
+            SlashType::DuplicateVote.get_slash_rate(&pos_params),
+            compute_cubic_slash_rate(ctx(), &pos_params, infraction_epoch)
+                .unwrap(),
+        );
+
+        // The slash is not included until it's processed
+        assert!(ctx().bond_slashes(None, &validator).unwrap().is_empty());
+        let processing_epoch =
+            infraction_epoch + pos_params.slash_processing_epoch_offset();
+        while epoch < processing_epoch {
+            epoch = advance_epoch();
+        }
+        process_slashes(ctx(), epoch).unwrap();
+
+        // The unbonded tokens that haven't been withdrawn yet are still
+        // included
+        let slashes = ctx().bond_slashes(None, &validator).unwrap();
+        assert_eq!(
+            slashes,
+            vec![(
+                infraction_epoch,
+                token::Amount::native_whole(6).mul_ceil(rate)
+                    + token::Amount::native_whole(4).mul_ceil(rate)
+            )]
+        );
+
+        // The delegation redelegated to the other validator is slashed by the
+        // source validator's slash
+        let slashes = ctx().bond_slashes(Some(&delegator), &other).unwrap();
+        assert_eq!(
+            slashes,
+            vec![(infraction_epoch, delegation.mul_ceil(rate))]
+        );
+
+        // The self-bond to the other validator is not slashed
+        assert!(ctx().bond_slashes(None, &other).unwrap().is_empty());
+    }
+
+    /// Test the schedule of the unbonds made in different epochs.
+    #[test]
+    fn test_unbond_schedule() {
+        let validator = address::testing::established_address_1();
+        let genesis_validators = [genesis_validator(
+            &validator,
+            token::Amount::native_whole(10),
+        )];
+        let pos_params = init_pos(
+            &genesis_validators[..],
+            &OwnedPosParams::default(),
+            Epoch(0),
+        );
+
+        // There's nothing scheduled without unbonds
+        assert!(ctx().unbond_schedule(None, &validator).unwrap().is_empty());
+
+        ctx()
+            .unbond_tokens(None, &validator, token::Amount::native_whole(2))
+            .unwrap();
+        tx_host_env::commit_tx_and_block();
+
+        let epoch = tx_host_env::with(|env| {
+            env.wl_storage.storage.block.epoch =
+                env.wl_storage.storage.block.epoch.next();
+            env.wl_storage.storage.block.epoch
+        });
+        copy_validator_sets_and_positions(
+            ctx(),
+            &pos_params,
+            epoch,
+            epoch + pos_params.pipeline_len,
+        )
+        .unwrap();
+        ctx()
+            .unbond_tokens(None, &validator, token::Amount::native_whole(3))
+            .unwrap();
+        tx_host_env::commit_tx_and_block();
+
+        let offset = pos_params.withdrawable_epoch_offset();
+        assert_eq!(
+            ctx().unbond_schedule(None, &validator).unwrap(),
+            vec![
+                (Epoch(offset), token::Amount::native_whole(2)),
+                (Epoch(1 + offset), token::Amount::native_whole(3)),
+            ]
+        );
+    }
+
     fn test_tx_unbond_aux(
         initial_stake: token::Amount,
         unbond: transaction::pos::Unbond,
@@ -85,31 +320,15 @@ mod tests {
         let is_delegation = matches!(
             &unbond.source, Some(source) if *source != unbond.validator);
 
-        let consensus_key = key::testing::keypair_1().ref_to();
-        let protocol_key = key::testing::keypair_2().ref_to();
-
-        let eth_cold_key = key::testing::keypair_3().ref_to();
-        let eth_hot_key = key::testing::keypair_4().ref_to();
-        let commission_rate = Dec::new(5, 2).expect("Cannot fail");
-        let max_commission_rate_change = Dec::new(1, 2).expect("Cannot fail");
-
-        let genesis_validators = [GenesisValidator {
-            address: unbond.validator.clone(),
-            tokens: if is_delegation {
-                // If we're unbonding a delegation, we'll give the initial stake
-                // to the delegation instead of the validator
-                token::Amount::zero()
-            } else {
-                initial_stake
-            },
-            consensus_key,
-            protocol_key,
-            eth_cold_key,
-            eth_hot_key,
-            commission_rate,
-            max_commission_rate_change,
-            metadata: Default::default(),
-        }];
+        // If we're unbonding a delegation, we'll give the initial stake to the
+        // delegation instead of the validator
+        let validator_stake = if is_delegation {
+            token::Amount::zero()
+        } else {
+            initial_stake
+        };
+        let genesis_validators =
+            [genesis_validator(&unbond.validator, validator_stake)];
 
         let pos_params =
             init_pos(&genesis_validators[..], &pos_params, Epoch(0));
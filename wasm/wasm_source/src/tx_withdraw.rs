@@ -24,12 +24,10 @@ fn apply_tx(ctx: &mut Ctx, tx_data: Tx) -> TxResult {
 #[cfg(test)]
 mod tests {
     use namada::ledger::pos::{OwnedPosParams, PosVP};
-    use namada::proof_of_stake::types::GenesisValidator;
     use namada::proof_of_stake::unbond_handle;
-    use namada::types::dec::Dec;
     use namada::types::storage::Epoch;
     use namada_tests::log::test;
-    use namada_tests::native_vp::pos::init_pos;
+    use namada_tests::native_vp::pos::{genesis_validator, init_pos};
     use namada_tests::native_vp::TestNativeVpEnv;
     use namada_tests::tx::*;
     use namada_tx_prelude::address::testing::{
@@ -39,7 +37,6 @@ mod tests {
     use namada_tx_prelude::borsh_ext::BorshSerializeExt;
     use namada_tx_prelude::chain::ChainId;
     use namada_tx_prelude::key::testing::arb_common_keypair;
-    use namada_tx_prelude::key::RefTo;
     use namada_tx_prelude::proof_of_stake::parameters::testing::arb_pos_params;
     use proptest::prelude::*;
 
@@ -67,6 +64,110 @@ mod tests {
         }
     }
 
+    /// Test that the matured unbonds of a delegator are withdrawn from all
+    /// the validators at once and summed up, skipping the validators with
+    /// nothing to withdraw yet.
+    #[test]
+    fn test_withdraw_all() {
+        let validators = [
+            address::testing::established_address_1(),
+            address::testing::established_address_2(),
+            address::testing::established_address_3(),
+        ];
+        let delegator = address::testing::established_address_4();
+        let genesis_validators = validators
+            .iter()
+            .map(|address| {
+                genesis_validator(address, token::Amount::native_whole(100))
+            })
+            .collect::<Vec<_>>();
+        let pos_params = init_pos(
+            &genesis_validators[..],
+            &OwnedPosParams::default(),
+            Epoch(0),
+        );
+
+        let native_token = tx_host_env::with(|tx_env| {
+            tx_env.spawn_accounts([&delegator]);
+            let native_token = tx_env.wl_storage.storage.native_token.clone();
+            tx_env.credit_tokens(
+                &delegator,
+                &native_token,
+                token::Amount::native_whole(30),
+            );
+            native_token
+        });
+
+        // Delegate to all the validators and unbond from the first two
+        for validator in &validators {
+            ctx()
+                .bond_tokens(
+                    Some(&delegator),
+                    validator,
+                    token::Amount::native_whole(10),
+                )
+                .unwrap();
+        }
+        ctx()
+            .unbond_tokens(
+                Some(&delegator),
+                &validators[0],
+                token::Amount::native_whole(4),
+            )
+            .unwrap();
+        ctx()
+            .unbond_tokens(
+                Some(&delegator),
+                &validators[1],
+                token::Amount::native_whole(6),
+            )
+            .unwrap();
+        tx_host_env::commit_tx_and_block();
+
+        // Fast forward until the unbonds are withdrawable
+        tx_host_env::with(|env| {
+            for _ in 0..pos_params.withdrawable_epoch_offset() {
+                env.wl_storage.storage.block.epoch =
+                    env.wl_storage.storage.block.epoch.next();
+            }
+        });
+
+        // The unbond from the third validator is not matured yet
+        ctx()
+            .unbond_tokens(
+                Some(&delegator),
+                &validators[2],
+                token::Amount::native_whole(5),
+            )
+            .unwrap();
+
+        let withdrawn = ctx().withdraw_all(&delegator).unwrap();
+        assert_eq!(withdrawn, token::Amount::native_whole(10));
+        let balance_key = token::balance_key(&native_token, &delegator);
+        let balance: token::Amount =
+            ctx().read(&balance_key).unwrap().unwrap_or_default();
+        assert_eq!(balance, token::Amount::native_whole(10));
+        assert!(
+            unbond_handle(&delegator, &validators[0])
+                .is_empty(ctx())
+                .unwrap()
+        );
+        assert!(
+            unbond_handle(&delegator, &validators[1])
+                .is_empty(ctx())
+                .unwrap()
+        );
+        assert!(
+            !unbond_handle(&delegator, &validators[2])
+                .is_empty(ctx())
+                .unwrap()
+        );
+
+        // Everything matured has been withdrawn already
+        let withdrawn = ctx().withdraw_all(&delegator).unwrap();
+        assert!(withdrawn.is_zero());
+    }
+
     fn test_tx_withdraw_aux(
         initial_stake: token::Amount,
         unbonded_amount: token::Amount,
@@ -82,32 +183,15 @@ mod tests {
 
         let is_delegation = matches!(
             &withdraw.source, Some(source) if *source != withdraw.validator);
-        let consensus_key = key::testing::keypair_1().ref_to();
-        let protocol_key = key::testing::keypair_2().ref_to();
-
-        let eth_cold_key = key::testing::keypair_3().ref_to();
-        let eth_hot_key = key::testing::keypair_4().ref_to();
-        let commission_rate = Dec::new(5, 2).expect("Cannot fail");
-        let max_commission_rate_change = Dec::new(1, 2).expect("Cannot fail");
-
-        let genesis_validators = [GenesisValidator {
-            address: withdraw.validator.clone(),
-            tokens: if is_delegation {
-                // If we're withdrawing a delegation, we'll give the initial
-                // stake to the delegation instead of the
-                // validator
-                token::Amount::zero()
-            } else {
-                initial_stake
-            },
-            consensus_key,
-            protocol_key,
-            eth_cold_key,
-            eth_hot_key,
-            commission_rate,
-            max_commission_rate_change,
-            metadata: Default::default(),
-        }];
+        // If we're withdrawing a delegation, we'll give the initial stake to
+        // the delegation instead of the validator
+        let validator_stake = if is_delegation {
+            token::Amount::zero()
+        } else {
+            initial_stake
+        };
+        let genesis_validators =
+            [genesis_validator(&withdraw.validator, validator_stake)];
 
         let pos_params =
             init_pos(&genesis_validators[..], &pos_params, Epoch(0));